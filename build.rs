@@ -1,17 +1,21 @@
 use std::process::Command;
 use std::io::{self, Write};
 
-fn main() {
+fn run_script(script: &str, error: &str) {
     let output = Command::new("python3")
-        .arg("scripts/bundle_levels.py")
+        .arg(script)
         .output()
-        .expect("Failed to bundle level json files");
+        .expect(error);
 
     io::stdout().write_all(&output.stdout).unwrap();
     io::stderr().write_all(&output.stderr).unwrap();
 
     if !output.status.success() {
-        panic!("Failed to bundle level json files");
+        panic!("{}", error);
     }
+}
 
-}
\ No newline at end of file
+fn main() {
+    run_script("scripts/bundle_levels.py", "Failed to bundle level json files");
+    run_script("scripts/generate_font.py", "Failed to generate the proportional font");
+}
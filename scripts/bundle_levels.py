@@ -3,11 +3,197 @@ import json
 
 levels_dir = './levels'
 output_file = './src/levels/levelstore.rs'
+mesh_library_file = './meshes/library.json'
+movement_file = './config/movement.json'
+chapters_file = './config/chapters.json'
+#the save keeps the collected gems of a level in the bits of its byte next to the completed bit
+max_gems = 7
+#room for a mesh, MESH_VERTICES and MESH_TRIANGLES in src/entities/mesh.rs
+max_mesh_vertices = 16
+max_mesh_triangles = 24
+max_shade = 3
+
+with open(mesh_library_file, 'r') as f:
+	mesh_library = json.load(f)
+
+#the keys each group of level settings can have and the type of their values, like the structs
+#in src/player.rs and src/camera/controller.rs, the game fails to read settings that don't match
+number = (int, float)
+frames = 'frames'
+accel_curves = ['linear', 'ease_out', 'ease_in']
+setting_groups = {
+	'abilities': {
+		'double_jump': bool,
+		'wall_jump': bool,
+		'dash': bool,
+		'ground_pound': bool,
+	},
+	'movement': {
+		'move_cap': number,
+		'ground_accel': number,
+		'air_accel': number,
+		'float_gravity': number,
+		'base_gravity': number,
+		'jump_power': number,
+		'coyote_frames': frames,
+		'jump_buffer_frames': frames,
+		'turn_rate': number,
+		'accel_curve': accel_curves,
+	},
+	'camera': {
+		'follow_speed': number,
+		'turn_speed': number,
+		'dead_zone': number,
+		'look_ahead': number,
+	},
+}
+#the keys of a camera keyframe in the intro, like Keyframe in src/camera/path.rs
+keyframe_keys = {
+	'x': number,
+	'y': number,
+	'z': number,
+	'yaw': number,
+	'pitch': number,
+	'duration': frames,
+}
+
+def check_value(name, value, kind):
+	#bool is an int in python, but not in json
+	if kind is frames:
+		if isinstance(value, bool) or not isinstance(value, int) or not 0 <= value <= 65535:
+			raise ValueError(f"{name} is {value!r}, not a number of frames")
+	elif isinstance(kind, list):
+		if value not in kind:
+			raise ValueError(f"{name} is {value!r}, not one of {', '.join(kind)}")
+	elif kind is bool:
+		if not isinstance(value, bool):
+			raise ValueError(f"{name} is {value!r}, not true or false")
+	elif isinstance(value, bool) or not isinstance(value, kind):
+		raise ValueError(f"{name} is {value!r}, not a number")
+
+def check_group(group, values):
+	if not isinstance(values, dict):
+		raise ValueError(f"{group} is not an object")
+	for key, value in values.items():
+		if key not in setting_groups[group]:
+			raise ValueError(f"unknown {group} setting {key}")
+		check_value(f"{group}.{key}", value, setting_groups[group][key])
+
+def check_intro(intro):
+	if not isinstance(intro, list):
+		raise ValueError("intro is not an array")
+	for i, keyframe in enumerate(intro):
+		if not isinstance(keyframe, dict):
+			raise ValueError(f"intro keyframe {i} is not an object")
+		for key, value in keyframe.items():
+			if key not in keyframe_keys:
+				raise ValueError(f"unknown key {key} in intro keyframe {i}")
+			check_value(f"intro keyframe {i} {key}", value, keyframe_keys[key])
+
+def check_settings(settings):
+	if not isinstance(settings, dict):
+		raise ValueError("settings is not an object")
+	for key, value in settings.items():
+		if key in setting_groups:
+			check_group(key, value)
+		elif key == 'intro':
+			check_intro(value)
+		else:
+			raise ValueError(f"unknown setting {key}")
+
+#global movement profile, values in a level's own settings take priority
+with open(movement_file, 'r') as f:
+	movement = json.load(f)
+try:
+	check_group('movement', movement)
+except ValueError as e:
+	print(f"Error in {movement_file}: {e}")
+	exit(1)
+
+#names of the chapters and what unlocks them, the levels say which chapter they belong to
+with open(chapters_file, 'r') as f:
+	chapters = json.load(f)
+
+#copy the vertices, triangles and shades of library meshes into the mesh entities using them
+def resolve_meshes(data):
+	resolved = False
+	for entity in data:
+		if entity.get('type') != 'mesh' or 'library' not in entity.get('data', {}):
+			continue
+		name = entity['data'].pop('library')
+		if name not in mesh_library:
+			raise ValueError(f"unknown library mesh {name}")
+		for key, value in mesh_library[name].items():
+			entity['data'].setdefault(key, value)
+		resolved = True
+	return resolved
+
+#meshes that don't fit in the lists of the mesh entity would fail to load when the level starts
+def check_meshes(data):
+	for i, entity in enumerate(data):
+		if entity.get('type') != 'mesh':
+			continue
+		mesh = entity.get('data', {})
+		vertices = mesh.get('vertices', [])
+		triangles = mesh.get('triangles', [])
+		shades = mesh.get('shades', [])
+		if len(vertices) > max_mesh_vertices:
+			raise ValueError(f"mesh {i} has {len(vertices)} vertices, at most {max_mesh_vertices} fit")
+		if len(triangles) > max_mesh_triangles:
+			raise ValueError(f"mesh {i} has {len(triangles)} triangles, at most {max_mesh_triangles} fit")
+		if len(shades) > len(triangles):
+			raise ValueError(f"mesh {i} has {len(shades)} shades for {len(triangles)} triangles")
+		for vertex in vertices:
+			if not isinstance(vertex, list) or len(vertex) != 3:
+				raise ValueError(f"mesh {i} vertex {vertex} is not [x, y, z]")
+		for triangle in triangles:
+			if not isinstance(triangle, list) or len(triangle) != 3:
+				raise ValueError(f"mesh {i} triangle {triangle} is not [a, b, c]")
+			for index in triangle:
+				if isinstance(index, bool) or not isinstance(index, int) or not 0 <= index < len(vertices):
+					raise ValueError(f"mesh {i} triangle {triangle} points past its {len(vertices)} vertices")
+		for shade in shades:
+			if isinstance(shade, bool) or not isinstance(shade, int) or not 0 <= shade <= max_shade:
+				raise ValueError(f"mesh {i} shade {shade} is not between 0 and {max_shade}")
+
+#move the text of signs into a table of their own, the entities only keep its index
+#so that they can be copied around like the other entities
+def extract_messages(data, messages):
+	extracted = False
+	for entity in data:
+		if entity.get('type') != 'sign':
+			continue
+		text = entity.get('data', {}).pop('text', None)
+		if text is None:
+			raise ValueError("sign has no text")
+		if '"#' in text:
+			raise ValueError(f"sign text {text!r} contains \"#")
+		entity['data']['message'] = len(messages)
+		messages.append(text)
+		extracted = True
+	return extracted
+
+#number the gems of a level, the numbers are their bits in the save
+def number_gems(data):
+	count = 0
+	for entity in data:
+		if entity.get('type') != 'gem':
+			continue
+		entity.setdefault('data', {})['gem'] = count
+		count += 1
+	if count > max_gems:
+		raise ValueError(f"{count} gems, a level can have at most {max_gems}")
+	return count
 
 level_files = [f for f in os.listdir(levels_dir) if f.endswith('.json')]
 level_files.sort()
 
 rust_strings = []
+settings_strings = []
+message_strings = []
+level_chapters = []
+level_gems = []
+bonus_levels = []
 
 max_length = 0
 
@@ -18,28 +204,86 @@ for i, level_file in enumerate(level_files):
 		level_data = f.read()
 		try:
 			data = json.loads(level_data)
-			if not isinstance(data, list):
-				raise ValueError("JSON is not an array")
+			#a level is either the entity array alone, or an object with the array under "entities"
+			#and level wide options under "settings"
+			settings = {}
+			if isinstance(data, dict):
+				if not isinstance(data.get('entities'), list):
+					raise ValueError("JSON object has no entities array")
+				settings = data.get('settings', {})
+				data = data['entities']
+				level_data = json.dumps(data, indent=4)
+			elif not isinstance(data, list):
+				raise ValueError("JSON is not an array or an object")
 
 			max_length = max(max_length, len(data))
 
+			if resolve_meshes(data):
+				level_data = json.dumps(data, indent=4)
+			check_meshes(data)
+
+			messages = []
+			if extract_messages(data, messages):
+				level_data = json.dumps(data, indent=4)
+
+			gem_count = number_gems(data)
+			if gem_count > 0:
+				level_data = json.dumps(data, indent=4)
+
+			#where the level is on the world map, the game reads these from tables of their own
+			chapter = settings.pop('chapter', 0)
+			if not isinstance(chapter, int) or not 0 <= chapter < len(chapters):
+				raise ValueError(f"chapter {chapter} is not in {chapters_file}")
+			bonus = settings.pop('bonus', False)
+			check_settings(settings)
+
 		except (json.JSONDecodeError, ValueError) as e:
 			print(f"Error in file {level_file}: {e}")
 			exit(1)
 		rust_string = f'const LEVEL{i+1}: &str = r#"{level_data}"#;'
 		rust_strings.append(rust_string)
+		settings['movement'] = {**movement, **settings.get('movement', {})}
+		settings_strings.append(f'const LEVEL{i+1}_SETTINGS: &str = r#"{json.dumps(settings)}"#;')
+		message_list = ", ".join([f'r#"{message}"#' for message in messages])
+		message_strings.append(f'const LEVEL{i+1}_MESSAGES: [&str; {len(messages)}] = [{message_list}];')
+		level_chapters.append(str(chapter))
+		level_gems.append(str(gem_count))
+		bonus_levels.append('true' if bonus else 'false')
 level_count = len(level_files)
+for chapter_index, chapter in enumerate(chapters):
+	if str(chapter_index) not in level_chapters:
+		print(f"Error in {chapters_file}: chapter {chapter['name']} has no levels")
+		exit(1)
 levels_array = f'pub const LEVELS: [&\'static str; {level_count}] = [{", ".join([f"LEVEL{i+1}" for i in range(level_count)])}];'
+settings_array = f'pub const LEVEL_SETTINGS: [&\'static str; {level_count}] = [{", ".join([f"LEVEL{i+1}_SETTINGS" for i in range(level_count)])}];'
+messages_array = f'pub const LEVEL_MESSAGES: [&[&str]; {level_count}] = [{", ".join([f"&LEVEL{i+1}_MESSAGES" for i in range(level_count)])}];'
 level_size_var = f'pub const LEVELSIZE: usize = {max_length};'
+chapters_list = ", ".join([
+	f'Chapter {{ name: r#"{chapter["name"]}"#, unlock_levels: {chapter.get("unlock", {}).get("levels", 0)}, unlock_gems: {chapter.get("unlock", {}).get("gems", 0)} }}'
+	for chapter in chapters
+])
+chapters_array = f'pub const CHAPTERS: [Chapter; {len(chapters)}] = [{chapters_list}];'
+level_chapters_array = f'pub const LEVEL_CHAPTERS: [usize; {level_count}] = [{", ".join(level_chapters)}];'
+level_gems_array = f'pub const LEVEL_GEMS: [u8; {level_count}] = [{", ".join(level_gems)}];'
+bonus_levels_array = f'pub const BONUS_LEVELS: [bool; {level_count}] = [{", ".join(bonus_levels)}];'
 
 #completed_levels_array = f'pub static mut COMPLETED_LEVELS: [bool; {level_count}] = [false; {level_count}];'
 
 with open(output_file, 'w') as f:
 	f.write('// DO NOT EDIT: This file was automatically generated by bundle_levels.py.\n')
 	f.write('// Any modifications will be overwritten during the next build.\n\n')
+	f.write('use super::chapters::Chapter;\n')
 	f.write('\n\n' + level_size_var)
 	f.write('\n\n' + '\n\n'.join(rust_strings))
 	f.write('\n\n' + levels_array)
+	f.write('\n\n' + '\n\n'.join(settings_strings))
+	f.write('\n\n' + settings_array)
+	f.write('\n\n' + '\n\n'.join(message_strings))
+	f.write('\n\n' + messages_array)
+	f.write('\n\n' + chapters_array)
+	f.write('\n\n' + level_chapters_array)
+	f.write('\n\n' + level_gems_array)
+	f.write('\n\n' + bonus_levels_array)
 	#f.write('\n\n' + completed_levels_array)
 
 #with open(output_file, 'a') as f:
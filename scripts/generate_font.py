@@ -0,0 +1,157 @@
+import json
+import os
+import struct
+import zlib
+
+font_file = './fonts/proportional.json'
+output_file = './src/textengine/fontstore.rs'
+
+#reads a non interlaced png with 8 bits per channel, or a paletted one with up to 8 bits per pixel
+#returns rows of (luminance, alpha) pairs
+def read_png(path):
+	with open(path, 'rb') as f:
+		data = f.read()
+	if data[:8] != b'\x89PNG\r\n\x1a\n':
+		raise ValueError(f"{path} is not a png")
+
+	pos = 8
+	idat = b''
+	palette = []
+	transparency = b''
+	while pos < len(data):
+		length, kind = struct.unpack('>I4s', data[pos:pos + 8])
+		body = data[pos + 8:pos + 8 + length]
+		pos += 12 + length
+		if kind == b'IHDR':
+			width, height, depth, color_type, _, _, interlace = struct.unpack('>IIBBBBB', body)
+		elif kind == b'PLTE':
+			palette = [tuple(body[i:i + 3]) for i in range(0, len(body), 3)]
+		elif kind == b'tRNS':
+			transparency = body
+		elif kind == b'IDAT':
+			idat += body
+		elif kind == b'IEND':
+			break
+
+	if interlace != 0:
+		raise ValueError(f"{path} is interlaced")
+	channels = {0: 1, 2: 3, 3: 1, 4: 2, 6: 4}[color_type]
+	if depth != 8 and not (color_type == 3 and depth in (1, 2, 4)):
+		raise ValueError(f"{path} has an unsupported bit depth of {depth}")
+
+	bits = channels * depth
+	stride = (width * bits + 7) // 8
+	step = max(1, bits // 8)
+	raw = zlib.decompress(idat)
+	previous = bytearray(stride)
+	pixels = []
+	for y in range(height):
+		start = y * (stride + 1)
+		filter_type = raw[start]
+		line = bytearray(raw[start + 1:start + 1 + stride])
+		for i in range(stride):
+			left = line[i - step] if i >= step else 0
+			up = previous[i]
+			up_left = previous[i - step] if i >= step else 0
+			if filter_type == 1:
+				line[i] = (line[i] + left) & 0xff
+			elif filter_type == 2:
+				line[i] = (line[i] + up) & 0xff
+			elif filter_type == 3:
+				line[i] = (line[i] + (left + up) // 2) & 0xff
+			elif filter_type == 4:
+				p = left + up - up_left
+				pa, pb, pc = abs(p - left), abs(p - up), abs(p - up_left)
+				predictor = left if pa <= pb and pa <= pc else (up if pb <= pc else up_left)
+				line[i] = (line[i] + predictor) & 0xff
+		previous = line
+
+		row = []
+		for x in range(width):
+			if color_type == 3:
+				per_byte = 8 // depth
+				index = (line[x // per_byte] >> ((per_byte - 1 - x % per_byte) * depth)) & ((1 << depth) - 1)
+				r, g, b = palette[index]
+				alpha = transparency[index] if index < len(transparency) else 255
+			else:
+				p = line[x * channels:(x + 1) * channels]
+				r, g, b = (p[0], p[0], p[0]) if color_type in (0, 4) else (p[0], p[1], p[2])
+				alpha = p[-1] if color_type in (4, 6) else 255
+			row.append(((r * 3 + g * 6 + b) // 10, alpha))
+		pixels.append(row)
+	return width, height, pixels
+
+with open(font_file, 'r') as f:
+	font = json.load(f)
+
+cell_width = font['cell_width']
+cell_height = font['cell_height']
+characters = font['characters']
+if cell_width > 8:
+	print(f"Error in {font_file}: glyphs can be at most 8 pixels wide")
+	exit(1)
+
+width, height, pixels = read_png(os.path.join(os.path.dirname(font_file), font['image']))
+columns = width // cell_width
+if len(characters) > columns * (height // cell_height):
+	print(f"Error in {font_file}: the image has fewer cells than there are characters")
+	exit(1)
+
+#glyphs are light pixels on a dark or transparent background, blank columns on both sides are cut off
+glyphs = []
+for i, c in enumerate(characters):
+	cell_x = (i % columns) * cell_width
+	cell_y = (i // columns) * cell_height
+	rows = []
+	for y in range(cell_height):
+		bits = 0
+		for x in range(cell_width):
+			luminance, alpha = pixels[cell_y + y][cell_x + x]
+			if luminance >= 128 and alpha >= 128:
+				bits |= 1 << (cell_width - 1 - x)
+		rows.append(bits)
+	used = 0
+	for bits in rows:
+		used |= bits
+	if used == 0:
+		print(f"Error in {font_file}: the cell for {c!r} is empty")
+		exit(1)
+	right = (used & -used).bit_length() - 1
+	left = used.bit_length() - 1
+	rows = [(bits >> right) << (8 - (left - right + 1)) for bits in rows]
+	glyphs.append((c, left - right + 1, rows))
+
+kerning = []
+for pair, offset in font.get('kerning', {}).items():
+	if len(pair) != 2 or pair[0] not in characters or pair[1] not in characters:
+		print(f"Error in {font_file}: kerning pair {pair!r} is not two characters of the font")
+		exit(1)
+	kerning.append((pair[0], pair[1], offset))
+#sorted so the renderer can binary search it
+kerning.sort(key=lambda k: (ord(k[0]), ord(k[1])))
+
+def rust_char(c):
+	return "'\\''" if c == "'" else ("'\\\\'" if c == '\\' else f"'{c}'")
+
+with open(output_file, 'w') as f:
+	f.write('// DO NOT EDIT: This file was automatically generated by generate_font.py.\n')
+	f.write('// Any modifications will be overwritten during the next build.\n\n')
+	f.write('use super::font::Glyph;\n\n')
+	f.write(f'pub const GLYPH_HEIGHT: usize = {cell_height};\n')
+	f.write(f'pub const SPACE_WIDTH: u16 = {font["space_width"]};\n')
+	f.write(f'pub const SPACING: u16 = {font["spacing"]};\n\n')
+	f.write(f'pub const GLYPHS: [Glyph; {len(glyphs)}] = [\n')
+	for c, glyph_width, rows in glyphs:
+		row_string = ', '.join(f'0b{bits:08b}' for bits in rows)
+		f.write(f'\tGlyph {{ width: {glyph_width}, rows: [{row_string}] }}, // {c}\n')
+	f.write('];\n\n')
+	f.write('pub fn glyph_index(c: char) -> Option<usize> {\n\tmatch c {\n')
+	for i, (c, _, _) in enumerate(glyphs):
+		f.write(f'\t\t{rust_char(c)} => Some({i}),\n')
+	f.write('\t\t_ => None,\n\t}\n}\n\n')
+	f.write(f'pub const KERNING: [(char, char, i8); {len(kerning)}] = [\n')
+	for a, b, offset in kerning:
+		f.write(f'\t({rust_char(a)}, {rust_char(b)}, {offset}),\n')
+	f.write('];\n')
+
+print(f"Generated {len(glyphs)} glyphs and {len(kerning)} kerning pairs in {output_file}")
@@ -1,6 +1,20 @@
 
 use agb::sound::dmg::{DutyCycle, EnvelopeSettings, SoundDirection, SweepSettings};
 
+//SOUNDCNT_L, the master volume of the left and right speaker and which channels play on them
+const REG_SOUNDCNT_L: *mut u16 = 0x0400_0080 as *mut u16;
+const ALL_CHANNELS: u16 = 0xFF00;
+
+//volume goes from 0 to 7, with sound off every channel is muted
+//must be called after Sound::enable, which sets the register to full volume
+pub fn set_volume(sound_on: bool, volume: u8) {
+    let volume = (volume & 7) as u16;
+    let channels = if sound_on { ALL_CHANNELS } else { 0 };
+    unsafe {
+        REG_SOUNDCNT_L.write_volatile(channels | (volume << 4) | volume);
+    }
+}
+
 pub fn play_sound(track: u16, vblank: &agb::interrupt::VBlank, sound: &agb::sound::dmg::Sound) {
     //must wait, as on real hardware channel 1 wont work consistently otherwise
     //menu move
@@ -0,0 +1,170 @@
+//moves the camera after the player smoothly instead of snapping to them every frame
+//position and yaw follow with critically damped springs, so they catch up as fast as possible
+//without overshooting, small hops don't move the camera up and down and it looks a bit ahead
+//in the direction the player is moving
+
+use serde::Deserialize;
+
+use super::Camera;
+use crate::fixed;
+use fixed::*;
+
+//how fast the camera catches up with the player, 1 or more snaps to them
+const FOLLOWSPEED: Fixed = Fixed::from_raw(38);
+const TURNSPEED: Fixed = Fixed::from_raw(51);
+//how far the player can get above the ground they jumped from before the camera moves up
+const DEADZONE: Fixed = Fixed::from_raw(384);
+//frames of movement the camera looks ahead of the player
+const LOOKAHEAD: Fixed = Fixed::const_new(4);
+
+fn default_follow_speed() -> Fixed {
+    FOLLOWSPEED
+}
+
+fn default_turn_speed() -> Fixed {
+    TURNSPEED
+}
+
+fn default_dead_zone() -> Fixed {
+    DEADZONE
+}
+
+fn default_look_ahead() -> Fixed {
+    LOOKAHEAD
+}
+
+//set per level in the "camera" object of the level settings
+#[derive(Copy, Clone, Deserialize, Debug)]
+pub struct CameraSettings {
+    #[serde(default = "default_follow_speed")]
+    pub follow_speed: Fixed,
+    #[serde(default = "default_turn_speed")]
+    pub turn_speed: Fixed,
+    #[serde(default = "default_dead_zone")]
+    pub dead_zone: Fixed,
+    #[serde(default = "default_look_ahead")]
+    pub look_ahead: Fixed,
+}
+
+impl Default for CameraSettings {
+    fn default() -> Self {
+        Self {
+            follow_speed: FOLLOWSPEED,
+            turn_speed: TURNSPEED,
+            dead_zone: DEADZONE,
+            look_ahead: LOOKAHEAD,
+        }
+    }
+}
+
+pub struct CameraController {
+    settings: CameraSettings,
+    //the point the camera orbits around, trails behind the player
+    focus: [Fixed; 3],
+    velocity: [Fixed; 3],
+    //in the same 256 steps per turn as the player's camera angle
+    yaw: Fixed,
+    yaw_velocity: Fixed,
+    //height the camera stays at while the player is in the air within the dead zone
+    anchor_y: Fixed,
+}
+
+impl CameraController {
+    pub fn default() -> Self {
+        Self {
+            settings: CameraSettings::default(),
+            focus: [Fixed::const_new(0); 3],
+            velocity: [Fixed::const_new(0); 3],
+            yaw: Fixed::const_new(0),
+            yaw_velocity: Fixed::const_new(0),
+            anchor_y: Fixed::const_new(0),
+        }
+    }
+
+    pub fn set_settings(&mut self, settings: CameraSettings) {
+        self.settings = settings;
+    }
+
+    //jumps straight to the player, for the level start and teleports
+    pub fn snap(&mut self, camera: &mut Camera, target: [Fixed; 3], yaw: usize) {
+        self.focus = target;
+        self.velocity = [Fixed::const_new(0); 3];
+        self.anchor_y = target[1];
+        self.yaw = Fixed::const_new(yaw as i32);
+        self.yaw_velocity = Fixed::const_new(0);
+        self.place(camera);
+    }
+
+    //target is the player's position, movement what they moved on this frame
+    pub fn follow(
+        &mut self,
+        camera: &mut Camera,
+        target: [Fixed; 3],
+        movement: [Fixed; 2],
+        grounded: bool,
+        yaw: usize,
+    ) {
+        if grounded || target[1] < self.anchor_y {
+            self.anchor_y = target[1];
+        } else if target[1] > self.anchor_y + self.settings.dead_zone {
+            self.anchor_y = target[1] - self.settings.dead_zone;
+        }
+
+        let goal = [
+            target[0] + movement[0] * self.settings.look_ahead,
+            self.anchor_y,
+            target[2] + movement[1] * self.settings.look_ahead,
+        ];
+        for i in 0..3 {
+            damp(&mut self.focus[i], &mut self.velocity[i], goal[i], self.settings.follow_speed);
+        }
+
+        //turn the short way around
+        let mut diff = Fixed::const_new(yaw as i32) - self.yaw;
+        if diff >= Fixed::const_new(128) {
+            diff -= Fixed::const_new(256);
+        } else if diff < Fixed::const_new(-128) {
+            diff += Fixed::const_new(256);
+        }
+        let goal = self.yaw + diff;
+        damp(&mut self.yaw, &mut self.yaw_velocity, goal, self.settings.turn_speed);
+        if self.yaw < Fixed::const_new(0) {
+            self.yaw += Fixed::const_new(256);
+        } else if self.yaw >= Fixed::const_new(256) {
+            self.yaw -= Fixed::const_new(256);
+        }
+
+        self.place(camera);
+    }
+
+    fn place(&self, camera: &mut Camera) {
+        let yaw = (self.yaw + Fixed::from_raw(128)).trunc().rem_euclid(256);
+        camera.orbit(yaw as usize);
+        camera.x = camera.local_x + self.focus[0];
+        camera.y = camera.local_y + self.focus[1];
+        camera.z = camera.local_z + self.focus[2];
+    }
+}
+
+//one frame of a critically damped spring pulling value towards goal
+//done on the raw values, the small speeds would round to nothing in Fixed and leave the camera drifting
+fn damp(value: &mut Fixed, velocity: &mut Fixed, goal: Fixed, speed: Fixed) {
+    if speed >= Fixed::const_new(1) {
+        *value = goal;
+        *velocity = Fixed::const_new(0);
+        return;
+    }
+    let error = (goal.to_raw() - value.to_raw()) as i64;
+    let speed = speed.to_raw() as i64;
+    let mut v = velocity.to_raw() as i64;
+    //error * speed^2 - 2 * speed * velocity, with speed^2 scaled by 256 * 256
+    let accel = error * speed * speed - 2 * speed * v * 256;
+    v += (accel + 32768).div_euclid(65536);
+    if error.abs() <= 1 && v.abs() <= 1 {
+        *value = goal;
+        *velocity = Fixed::const_new(0);
+        return;
+    }
+    *velocity = Fixed::from_raw(v as i32);
+    *value += *velocity;
+}
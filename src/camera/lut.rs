@@ -1,263 +0,0 @@
-use crate::fixed;
-use fixed::*;
-    //x_off, z_off, y_angle, x_angle, y_angle
-
-pub const CAMERALOCATIONS: [[Fixed; 5]; 256] = [
-	[Fixed::from_raw(0), Fixed::from_raw(-1280), Fixed::from_raw(0), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(31), Fixed::from_raw(-1279), Fixed::from_raw(1), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(62), Fixed::from_raw(-1278), Fixed::from_raw(2), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(94), Fixed::from_raw(-1276), Fixed::from_raw(3), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(125), Fixed::from_raw(-1273), Fixed::from_raw(4), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(156), Fixed::from_raw(-1270), Fixed::from_raw(5), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(187), Fixed::from_raw(-1266), Fixed::from_raw(6), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(218), Fixed::from_raw(-1261), Fixed::from_raw(7), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(249), Fixed::from_raw(-1255), Fixed::from_raw(8), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(280), Fixed::from_raw(-1248), Fixed::from_raw(9), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(311), Fixed::from_raw(-1241), Fixed::from_raw(10), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(341), Fixed::from_raw(-1233), Fixed::from_raw(11), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(371), Fixed::from_raw(-1224), Fixed::from_raw(12), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(401), Fixed::from_raw(-1215), Fixed::from_raw(13), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(431), Fixed::from_raw(-1205), Fixed::from_raw(14), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(460), Fixed::from_raw(-1194), Fixed::from_raw(15), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(489), Fixed::from_raw(-1182), Fixed::from_raw(16), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(518), Fixed::from_raw(-1170), Fixed::from_raw(17), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(547), Fixed::from_raw(-1157), Fixed::from_raw(18), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(575), Fixed::from_raw(-1143), Fixed::from_raw(19), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(603), Fixed::from_raw(-1128), Fixed::from_raw(20), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(630), Fixed::from_raw(-1113), Fixed::from_raw(21), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(658), Fixed::from_raw(-1097), Fixed::from_raw(22), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(684), Fixed::from_raw(-1081), Fixed::from_raw(23), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(711), Fixed::from_raw(-1064), Fixed::from_raw(24), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(737), Fixed::from_raw(-1046), Fixed::from_raw(25), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(762), Fixed::from_raw(-1028), Fixed::from_raw(26), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(787), Fixed::from_raw(-1009), Fixed::from_raw(27), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(812), Fixed::from_raw(-989), Fixed::from_raw(28), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(836), Fixed::from_raw(-969), Fixed::from_raw(29), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(859), Fixed::from_raw(-948), Fixed::from_raw(30), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(882), Fixed::from_raw(-927), Fixed::from_raw(31), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(905), Fixed::from_raw(-905), Fixed::from_raw(32), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(927), Fixed::from_raw(-882), Fixed::from_raw(33), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(948), Fixed::from_raw(-859), Fixed::from_raw(34), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(969), Fixed::from_raw(-836), Fixed::from_raw(35), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(989), Fixed::from_raw(-812), Fixed::from_raw(36), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1009), Fixed::from_raw(-787), Fixed::from_raw(37), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1028), Fixed::from_raw(-762), Fixed::from_raw(38), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1046), Fixed::from_raw(-737), Fixed::from_raw(39), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1064), Fixed::from_raw(-711), Fixed::from_raw(40), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1081), Fixed::from_raw(-684), Fixed::from_raw(41), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1097), Fixed::from_raw(-658), Fixed::from_raw(42), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1113), Fixed::from_raw(-630), Fixed::from_raw(43), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1128), Fixed::from_raw(-603), Fixed::from_raw(44), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1143), Fixed::from_raw(-575), Fixed::from_raw(45), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1157), Fixed::from_raw(-547), Fixed::from_raw(46), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1170), Fixed::from_raw(-518), Fixed::from_raw(47), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1182), Fixed::from_raw(-489), Fixed::from_raw(48), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1194), Fixed::from_raw(-460), Fixed::from_raw(49), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1205), Fixed::from_raw(-431), Fixed::from_raw(50), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1215), Fixed::from_raw(-401), Fixed::from_raw(51), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1224), Fixed::from_raw(-371), Fixed::from_raw(52), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1233), Fixed::from_raw(-341), Fixed::from_raw(53), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1241), Fixed::from_raw(-311), Fixed::from_raw(54), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1248), Fixed::from_raw(-280), Fixed::from_raw(55), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1255), Fixed::from_raw(-249), Fixed::from_raw(56), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1261), Fixed::from_raw(-218), Fixed::from_raw(57), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1266), Fixed::from_raw(-187), Fixed::from_raw(58), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1270), Fixed::from_raw(-156), Fixed::from_raw(59), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1273), Fixed::from_raw(-125), Fixed::from_raw(60), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1276), Fixed::from_raw(-94), Fixed::from_raw(61), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1278), Fixed::from_raw(-62), Fixed::from_raw(62), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1279), Fixed::from_raw(-31), Fixed::from_raw(63), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1280), Fixed::from_raw(0), Fixed::from_raw(64), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1279), Fixed::from_raw(31), Fixed::from_raw(65), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1278), Fixed::from_raw(62), Fixed::from_raw(66), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1276), Fixed::from_raw(94), Fixed::from_raw(67), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1273), Fixed::from_raw(125), Fixed::from_raw(68), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1270), Fixed::from_raw(156), Fixed::from_raw(69), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1266), Fixed::from_raw(187), Fixed::from_raw(70), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1261), Fixed::from_raw(218), Fixed::from_raw(71), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1255), Fixed::from_raw(249), Fixed::from_raw(72), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1248), Fixed::from_raw(280), Fixed::from_raw(73), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1241), Fixed::from_raw(311), Fixed::from_raw(74), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1233), Fixed::from_raw(341), Fixed::from_raw(75), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1224), Fixed::from_raw(371), Fixed::from_raw(76), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1215), Fixed::from_raw(401), Fixed::from_raw(77), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1205), Fixed::from_raw(431), Fixed::from_raw(78), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1194), Fixed::from_raw(460), Fixed::from_raw(79), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1182), Fixed::from_raw(489), Fixed::from_raw(80), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1170), Fixed::from_raw(518), Fixed::from_raw(81), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1157), Fixed::from_raw(547), Fixed::from_raw(82), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1143), Fixed::from_raw(575), Fixed::from_raw(83), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1128), Fixed::from_raw(603), Fixed::from_raw(84), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1113), Fixed::from_raw(630), Fixed::from_raw(85), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1097), Fixed::from_raw(658), Fixed::from_raw(86), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1081), Fixed::from_raw(684), Fixed::from_raw(87), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1064), Fixed::from_raw(711), Fixed::from_raw(88), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1046), Fixed::from_raw(737), Fixed::from_raw(89), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1028), Fixed::from_raw(762), Fixed::from_raw(90), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(1009), Fixed::from_raw(787), Fixed::from_raw(91), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(989), Fixed::from_raw(812), Fixed::from_raw(92), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(969), Fixed::from_raw(836), Fixed::from_raw(93), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(948), Fixed::from_raw(859), Fixed::from_raw(94), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(927), Fixed::from_raw(882), Fixed::from_raw(95), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(905), Fixed::from_raw(905), Fixed::from_raw(96), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(882), Fixed::from_raw(927), Fixed::from_raw(97), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(859), Fixed::from_raw(948), Fixed::from_raw(98), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(836), Fixed::from_raw(969), Fixed::from_raw(99), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(812), Fixed::from_raw(989), Fixed::from_raw(100), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(787), Fixed::from_raw(1009), Fixed::from_raw(101), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(762), Fixed::from_raw(1028), Fixed::from_raw(102), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(737), Fixed::from_raw(1046), Fixed::from_raw(103), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(711), Fixed::from_raw(1064), Fixed::from_raw(104), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(684), Fixed::from_raw(1081), Fixed::from_raw(105), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(658), Fixed::from_raw(1097), Fixed::from_raw(106), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(630), Fixed::from_raw(1113), Fixed::from_raw(107), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(603), Fixed::from_raw(1128), Fixed::from_raw(108), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(575), Fixed::from_raw(1143), Fixed::from_raw(109), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(547), Fixed::from_raw(1157), Fixed::from_raw(110), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(518), Fixed::from_raw(1170), Fixed::from_raw(111), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(489), Fixed::from_raw(1182), Fixed::from_raw(112), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(460), Fixed::from_raw(1194), Fixed::from_raw(113), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(431), Fixed::from_raw(1205), Fixed::from_raw(114), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(401), Fixed::from_raw(1215), Fixed::from_raw(115), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(371), Fixed::from_raw(1224), Fixed::from_raw(116), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(341), Fixed::from_raw(1233), Fixed::from_raw(117), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(311), Fixed::from_raw(1241), Fixed::from_raw(118), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(280), Fixed::from_raw(1248), Fixed::from_raw(119), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(249), Fixed::from_raw(1255), Fixed::from_raw(120), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(218), Fixed::from_raw(1261), Fixed::from_raw(121), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(187), Fixed::from_raw(1266), Fixed::from_raw(122), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(156), Fixed::from_raw(1270), Fixed::from_raw(123), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(125), Fixed::from_raw(1273), Fixed::from_raw(124), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(94), Fixed::from_raw(1276), Fixed::from_raw(125), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(62), Fixed::from_raw(1278), Fixed::from_raw(126), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(31), Fixed::from_raw(1279), Fixed::from_raw(127), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(0), Fixed::from_raw(1280), Fixed::from_raw(128), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-31), Fixed::from_raw(1279), Fixed::from_raw(129), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-62), Fixed::from_raw(1278), Fixed::from_raw(130), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-94), Fixed::from_raw(1276), Fixed::from_raw(131), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-125), Fixed::from_raw(1273), Fixed::from_raw(132), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-156), Fixed::from_raw(1270), Fixed::from_raw(133), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-187), Fixed::from_raw(1266), Fixed::from_raw(134), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-218), Fixed::from_raw(1261), Fixed::from_raw(135), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-249), Fixed::from_raw(1255), Fixed::from_raw(136), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-280), Fixed::from_raw(1248), Fixed::from_raw(137), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-311), Fixed::from_raw(1241), Fixed::from_raw(138), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-341), Fixed::from_raw(1233), Fixed::from_raw(139), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-371), Fixed::from_raw(1224), Fixed::from_raw(140), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-401), Fixed::from_raw(1215), Fixed::from_raw(141), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-431), Fixed::from_raw(1205), Fixed::from_raw(142), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-460), Fixed::from_raw(1194), Fixed::from_raw(143), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-489), Fixed::from_raw(1182), Fixed::from_raw(144), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-518), Fixed::from_raw(1170), Fixed::from_raw(145), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-547), Fixed::from_raw(1157), Fixed::from_raw(146), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-575), Fixed::from_raw(1143), Fixed::from_raw(147), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-603), Fixed::from_raw(1128), Fixed::from_raw(148), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-630), Fixed::from_raw(1113), Fixed::from_raw(149), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-658), Fixed::from_raw(1097), Fixed::from_raw(150), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-684), Fixed::from_raw(1081), Fixed::from_raw(151), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-711), Fixed::from_raw(1064), Fixed::from_raw(152), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-737), Fixed::from_raw(1046), Fixed::from_raw(153), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-762), Fixed::from_raw(1028), Fixed::from_raw(154), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-787), Fixed::from_raw(1009), Fixed::from_raw(155), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-812), Fixed::from_raw(989), Fixed::from_raw(156), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-836), Fixed::from_raw(969), Fixed::from_raw(157), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-859), Fixed::from_raw(948), Fixed::from_raw(158), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-882), Fixed::from_raw(927), Fixed::from_raw(159), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-905), Fixed::from_raw(905), Fixed::from_raw(160), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-927), Fixed::from_raw(882), Fixed::from_raw(161), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-948), Fixed::from_raw(859), Fixed::from_raw(162), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-969), Fixed::from_raw(836), Fixed::from_raw(163), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-989), Fixed::from_raw(812), Fixed::from_raw(164), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1009), Fixed::from_raw(787), Fixed::from_raw(165), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1028), Fixed::from_raw(762), Fixed::from_raw(166), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1046), Fixed::from_raw(737), Fixed::from_raw(167), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1064), Fixed::from_raw(711), Fixed::from_raw(168), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1081), Fixed::from_raw(684), Fixed::from_raw(169), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1097), Fixed::from_raw(658), Fixed::from_raw(170), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1113), Fixed::from_raw(630), Fixed::from_raw(171), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1128), Fixed::from_raw(603), Fixed::from_raw(172), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1143), Fixed::from_raw(575), Fixed::from_raw(173), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1157), Fixed::from_raw(547), Fixed::from_raw(174), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1170), Fixed::from_raw(518), Fixed::from_raw(175), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1182), Fixed::from_raw(489), Fixed::from_raw(176), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1194), Fixed::from_raw(460), Fixed::from_raw(177), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1205), Fixed::from_raw(431), Fixed::from_raw(178), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1215), Fixed::from_raw(401), Fixed::from_raw(179), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1224), Fixed::from_raw(371), Fixed::from_raw(180), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1233), Fixed::from_raw(341), Fixed::from_raw(181), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1241), Fixed::from_raw(311), Fixed::from_raw(182), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1248), Fixed::from_raw(280), Fixed::from_raw(183), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1255), Fixed::from_raw(249), Fixed::from_raw(184), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1261), Fixed::from_raw(218), Fixed::from_raw(185), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1266), Fixed::from_raw(187), Fixed::from_raw(186), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1270), Fixed::from_raw(156), Fixed::from_raw(187), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1273), Fixed::from_raw(125), Fixed::from_raw(188), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1276), Fixed::from_raw(94), Fixed::from_raw(189), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1278), Fixed::from_raw(62), Fixed::from_raw(190), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1279), Fixed::from_raw(31), Fixed::from_raw(191), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1280), Fixed::from_raw(0), Fixed::from_raw(192), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1279), Fixed::from_raw(-31), Fixed::from_raw(193), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1278), Fixed::from_raw(-62), Fixed::from_raw(194), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1276), Fixed::from_raw(-94), Fixed::from_raw(195), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1273), Fixed::from_raw(-125), Fixed::from_raw(196), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1270), Fixed::from_raw(-156), Fixed::from_raw(197), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1266), Fixed::from_raw(-187), Fixed::from_raw(198), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1261), Fixed::from_raw(-218), Fixed::from_raw(199), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1255), Fixed::from_raw(-249), Fixed::from_raw(200), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1248), Fixed::from_raw(-280), Fixed::from_raw(201), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1241), Fixed::from_raw(-311), Fixed::from_raw(202), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1233), Fixed::from_raw(-341), Fixed::from_raw(203), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1224), Fixed::from_raw(-371), Fixed::from_raw(204), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1215), Fixed::from_raw(-401), Fixed::from_raw(205), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1205), Fixed::from_raw(-431), Fixed::from_raw(206), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1194), Fixed::from_raw(-460), Fixed::from_raw(207), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1182), Fixed::from_raw(-489), Fixed::from_raw(208), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1170), Fixed::from_raw(-518), Fixed::from_raw(209), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1157), Fixed::from_raw(-547), Fixed::from_raw(210), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1143), Fixed::from_raw(-575), Fixed::from_raw(211), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1128), Fixed::from_raw(-603), Fixed::from_raw(212), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1113), Fixed::from_raw(-630), Fixed::from_raw(213), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1097), Fixed::from_raw(-658), Fixed::from_raw(214), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1081), Fixed::from_raw(-684), Fixed::from_raw(215), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1064), Fixed::from_raw(-711), Fixed::from_raw(216), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1046), Fixed::from_raw(-737), Fixed::from_raw(217), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1028), Fixed::from_raw(-762), Fixed::from_raw(218), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-1009), Fixed::from_raw(-787), Fixed::from_raw(219), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-989), Fixed::from_raw(-812), Fixed::from_raw(220), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-969), Fixed::from_raw(-836), Fixed::from_raw(221), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-948), Fixed::from_raw(-859), Fixed::from_raw(222), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-927), Fixed::from_raw(-882), Fixed::from_raw(223), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-905), Fixed::from_raw(-905), Fixed::from_raw(224), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-882), Fixed::from_raw(-927), Fixed::from_raw(225), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-859), Fixed::from_raw(-948), Fixed::from_raw(226), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-836), Fixed::from_raw(-969), Fixed::from_raw(227), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-812), Fixed::from_raw(-989), Fixed::from_raw(228), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-787), Fixed::from_raw(-1009), Fixed::from_raw(229), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-762), Fixed::from_raw(-1028), Fixed::from_raw(230), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-737), Fixed::from_raw(-1046), Fixed::from_raw(231), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-711), Fixed::from_raw(-1064), Fixed::from_raw(232), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-684), Fixed::from_raw(-1081), Fixed::from_raw(233), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-658), Fixed::from_raw(-1097), Fixed::from_raw(234), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-630), Fixed::from_raw(-1113), Fixed::from_raw(235), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-603), Fixed::from_raw(-1128), Fixed::from_raw(236), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-575), Fixed::from_raw(-1143), Fixed::from_raw(237), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-547), Fixed::from_raw(-1157), Fixed::from_raw(238), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-518), Fixed::from_raw(-1170), Fixed::from_raw(239), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-489), Fixed::from_raw(-1182), Fixed::from_raw(240), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-460), Fixed::from_raw(-1194), Fixed::from_raw(241), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-431), Fixed::from_raw(-1205), Fixed::from_raw(242), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-401), Fixed::from_raw(-1215), Fixed::from_raw(243), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-371), Fixed::from_raw(-1224), Fixed::from_raw(244), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-341), Fixed::from_raw(-1233), Fixed::from_raw(245), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-311), Fixed::from_raw(-1241), Fixed::from_raw(246), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-280), Fixed::from_raw(-1248), Fixed::from_raw(247), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-249), Fixed::from_raw(-1255), Fixed::from_raw(248), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-218), Fixed::from_raw(-1261), Fixed::from_raw(249), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-187), Fixed::from_raw(-1266), Fixed::from_raw(250), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-156), Fixed::from_raw(-1270), Fixed::from_raw(251), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-125), Fixed::from_raw(-1273), Fixed::from_raw(252), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-94), Fixed::from_raw(-1276), Fixed::from_raw(253), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-62), Fixed::from_raw(-1278), Fixed::from_raw(254), Fixed::from_raw(-12), Fixed::from_raw(0)],
-	[Fixed::from_raw(-31), Fixed::from_raw(-1279), Fixed::from_raw(255), Fixed::from_raw(-12), Fixed::from_raw(0)],
-			
-];
\ No newline at end of file
@@ -1,8 +1,27 @@
 
-pub mod lut;
+pub mod controller;
+pub mod path;
+
+use crate::entities::{boundingshapes::BoundingCylinder, utils::horizontal_collision, EntityEnum};
 use crate::fixed;
+use crate::math::{cos_degrees, sin_degrees};
 use fixed::*;
 
+//orbit angle above the horizon in degrees, and the distance from the player
+pub const DEFAULT_PITCH: i32 = 31;
+pub const MIN_PITCH: i32 = 5;
+pub const MAX_PITCH: i32 = 75;
+//sqrt(5*5 + 3*3), 5 units behind and 3 above the player at the default pitch
+pub const DEFAULT_DISTANCE: Fixed = Fixed::from_raw(1493);
+pub const MIN_DISTANCE: Fixed = Fixed::const_new(2);
+pub const MAX_DISTANCE: Fixed = Fixed::const_new(10);
+//the camera looks this many degrees less down than its orbit angle, keeping the player below the center
+const LOOK_ABOVE: i32 = 14;
+//camera collision checks this many points between the player and the camera
+const COLLISION_STEPS: i32 = 12;
+//the camera is treated as a small cylinder so it stops a bit before the wall
+const COLLISION_RADIUS: Fixed = Fixed::from_raw(64);
+
 pub struct Camera {
     pub local_x: Fixed,
     pub local_y: Fixed,
@@ -15,6 +34,10 @@ pub struct Camera {
     pub y_angle: Fixed,
     pub z_angle: Fixed,
 
+    //degrees above the horizon the camera orbits at
+    pub pitch: i32,
+    pub distance: Fixed,
+
     pub x_rotation_matrix: [[Fixed; 4]; 4],
     pub y_rotation_matrix: [[Fixed; 4]; 4],
     pub z_rotation_matrix: [[Fixed; 4]; 4],
@@ -34,6 +57,9 @@ impl Camera {
             x_angle: Fixed::const_new(0),
             y_angle: Fixed::const_new(0),
             z_angle: Fixed::const_new(0),
+
+            pitch: DEFAULT_PITCH,
+            distance: DEFAULT_DISTANCE,
             
             x_rotation_matrix: [[Fixed::const_new(0); 4]; 4],
             y_rotation_matrix: [[Fixed::const_new(0); 4]; 4],
@@ -66,4 +92,61 @@ impl Camera {
             [Fixed::const_new(0), Fixed::const_new(0), Fixed::const_new(1), Fixed::const_new(0)],
             [Fixed::const_new(0), Fixed::const_new(0), Fixed::const_new(0), Fixed::const_new(1)],
         ];    }
+
+    //places the camera on its orbit around the player, yaw is in 256 steps per turn
+    pub fn orbit(&mut self, yaw: usize) {
+        let yaw_degrees = (yaw * 360 / 256) as i32;
+        let horizontal = self.distance * cos_degrees(self.pitch);
+        self.local_x = horizontal * sin_degrees(yaw_degrees);
+        self.local_y = self.distance * sin_degrees(self.pitch);
+        self.local_z = -horizontal * cos_degrees(yaw_degrees);
+
+        self.set_y_rotation(Fixed::from_raw(yaw as i32));
+        self.set_x_rotation(-Fixed::const_new(self.pitch - LOOK_ABOVE) / 360);
+        self.set_z_rotation(Fixed::const_new(0));
+    }
+
+    pub fn change_pitch(&mut self, amount: i32, yaw: usize) {
+        self.pitch = (self.pitch + amount).clamp(MIN_PITCH, MAX_PITCH);
+        self.orbit(yaw);
+    }
+
+    pub fn zoom(&mut self, amount: Fixed, yaw: usize) {
+        self.distance += amount;
+        if self.distance < MIN_DISTANCE {
+            self.distance = MIN_DISTANCE;
+        } else if self.distance > MAX_DISTANCE {
+            self.distance = MAX_DISTANCE;
+        }
+        self.orbit(yaw);
+    }
+
+    //moves the camera towards target if something solid is between them
+    //so the camera doesn't end up inside a wall or under the floor
+    pub fn pull_in(&mut self, target: [Fixed; 3], entities: &[EntityEnum]) {
+        let offset = [self.x - target[0], self.y - target[1], self.z - target[2]];
+        let mut free_steps = COLLISION_STEPS;
+        for step in 1..=COLLISION_STEPS {
+            let x = target[0] + offset[0] * step / COLLISION_STEPS;
+            let y = target[1] + offset[1] * step / COLLISION_STEPS;
+            let z = target[2] + offset[2] * step / COLLISION_STEPS;
+            let point = BoundingCylinder {
+                x,
+                z,
+                radius: COLLISION_RADIUS,
+                y_top: y + COLLISION_RADIUS,
+                y_bottom: y - COLLISION_RADIUS,
+            };
+            if horizontal_collision(entities, point).is_some() {
+                free_steps = step - 1;
+                break;
+            }
+        }
+        if free_steps == COLLISION_STEPS {
+            return;
+        }
+        self.x = target[0] + offset[0] * free_steps / COLLISION_STEPS;
+        self.y = target[1] + offset[1] * free_steps / COLLISION_STEPS;
+        self.z = target[2] + offset[2] * free_steps / COLLISION_STEPS;
+    }
 }
@@ -0,0 +1,139 @@
+//scripted camera movement for the level intro flyover
+//the camera goes through the keyframes along a catmull-rom spline, so it passes each one without
+//stopping, and ends where the normal camera starts
+
+extern crate alloc;
+use alloc::vec::Vec;
+use serde::Deserialize;
+
+use super::Camera;
+use crate::fixed;
+use fixed::*;
+
+//frames from a keyframe to the next one when the level doesn't say
+const DURATION: u16 = 60;
+
+fn default_duration() -> u16 {
+    DURATION
+}
+
+#[derive(Copy, Clone, Deserialize, Debug)]
+pub struct Keyframe {
+    #[serde(default = "default_fixed")]
+    pub x: Fixed,
+    #[serde(default = "default_fixed")]
+    pub y: Fixed,
+    #[serde(default = "default_fixed")]
+    pub z: Fixed,
+    //turns around the y axis, same as Camera::set_y_rotation
+    #[serde(default = "default_fixed")]
+    pub yaw: Fixed,
+    //turns around the x axis, negative looks down
+    #[serde(default = "default_fixed")]
+    pub pitch: Fixed,
+    //frames it takes to get from this keyframe to the next one
+    #[serde(default = "default_duration")]
+    pub duration: u16,
+}
+
+impl Keyframe {
+    //the pose the camera currently has
+    pub fn from_camera(camera: &Camera) -> Self {
+        Self {
+            x: camera.x,
+            y: camera.y,
+            z: camera.z,
+            yaw: camera.y_angle,
+            pitch: camera.x_angle,
+            duration: DURATION,
+        }
+    }
+}
+
+pub struct CameraPath {
+    keyframes: Vec<Keyframe>,
+    //keyframe the camera last passed and frames since then
+    index: usize,
+    frame: u16,
+}
+
+impl CameraPath {
+    //end is appended as the last keyframe, usually the pose the camera has when play starts
+    pub fn new(mut keyframes: Vec<Keyframe>, end: Keyframe) -> Self {
+        keyframes.push(end);
+        Self {
+            keyframes,
+            index: 0,
+            frame: 0,
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        return self.index + 1 >= self.keyframes.len();
+    }
+
+    //moves the camera one frame along the path
+    pub fn step(&mut self, camera: &mut Camera) {
+        if self.is_finished() {
+            let last = self.keyframes[self.keyframes.len() - 1];
+            apply(camera, [last.x, last.y, last.z], last.yaw, last.pitch);
+            return;
+        }
+
+        let count = self.keyframes.len();
+        let p0 = self.keyframes[self.index.saturating_sub(1)];
+        let p1 = self.keyframes[self.index];
+        let p2 = self.keyframes[self.index + 1];
+        let p3 = self.keyframes[(self.index + 2).min(count - 1)];
+
+        let duration = p1.duration.max(1);
+        let t = Fixed::const_new(self.frame as i32) / duration as i32;
+        let position = [
+            catmull_rom(p0.x, p1.x, p2.x, p3.x, t),
+            catmull_rom(p0.y, p1.y, p2.y, p3.y, t),
+            catmull_rom(p0.z, p1.z, p2.z, p3.z, t),
+        ];
+        //eased so the turning doesn't start and stop abruptly at each keyframe
+        let eased = t * t * (Fixed::const_new(3) - t * 2);
+        let yaw = p1.yaw + turn_diff(p1.yaw, p2.yaw) * eased;
+        let pitch = p1.pitch + (p2.pitch - p1.pitch) * eased;
+        apply(camera, position, yaw, pitch);
+
+        self.frame += 1;
+        if self.frame >= duration {
+            self.frame = 0;
+            self.index += 1;
+        }
+    }
+}
+
+fn apply(camera: &mut Camera, position: [Fixed; 3], yaw: Fixed, pitch: Fixed) {
+    camera.x = position[0];
+    camera.y = position[1];
+    camera.z = position[2];
+    camera.set_x_rotation(pitch);
+    camera.set_y_rotation(yaw);
+    camera.set_z_rotation(Fixed::const_new(0));
+}
+
+//difference between two angles in turns, going the short way around
+fn turn_diff(from: Fixed, to: Fixed) -> Fixed {
+    let mut diff = (to - from).modulo(Fixed::const_new(1));
+    if diff > Fixed::from_raw(128) {
+        diff -= Fixed::const_new(1);
+    } else if diff < Fixed::from_raw(-128) {
+        diff += Fixed::const_new(1);
+    }
+    return diff;
+}
+
+//point between p1 and p2 at t, p0 and p3 shape the curve
+fn catmull_rom(p0: Fixed, p1: Fixed, p2: Fixed, p3: Fixed, t: Fixed) -> Fixed {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let a = p1 * 2;
+    let b = p2 - p0;
+    let c = p0 * 2 - p1 * 5 + p2 * 4 - p3;
+    let d = p1 * 3 - p0 - p2 * 3 + p3;
+    return (a + b * t + c * t2 + d * t3) / 2;
+}
@@ -1,4 +1,4 @@
-use crate::{boundingshapes::{BoundingBox, BoundingCylinder}, Fixed};
+use crate::{boundingshapes::{BoundingBox, BoundingCylinder}, pool::Dynamic, Fixed};
 
 pub struct InputGameState<'a> {
 	pub support_below_id: i16,
@@ -6,22 +6,34 @@ pub struct InputGameState<'a> {
 	pub bounding_cylinder: &'a BoundingCylinder,
 	pub action_requested: bool,
 	pub yspeed: Fixed,
+	pub ground_pound: bool,
 }
 
 
 
 pub enum OutputEvents {
-	PlayerEvent(MoveXYZ),
+	Carry(Carry),
 	GameFinish(Finished),
 	SwitchAction(SwitchFlip),
 	BounceEvent(Bounce),
 	Sliding(Sliding),
+	Force(Force),
+	Teleport(Teleport),
+	EnemyHit(EnemyHit),
+	Spawn(Spawn),
+	Message(Message),
+	Collect(Collect),
 }
 
-pub struct MoveXYZ {
+//how the platform under the player moved this frame, see moveutils::carry
+//turn is a rotation around pivot_x and pivot_z, the platform center before it moved
+pub struct Carry {
 	pub move_x: Fixed,
 	pub move_y: Fixed,
 	pub move_z: Fixed,
+	pub turn: Fixed,
+	pub pivot_x: Fixed,
+	pub pivot_z: Fixed,
 }
 
 pub struct Finished {
@@ -38,4 +50,39 @@ pub struct Bounce {
 
 pub struct Sliding {
 	pub acceleration: Fixed,
-}
\ No newline at end of file
+}
+
+//horizontal parts are added to the player movement, y counters gravity
+pub struct Force {
+	pub force_x: Fixed,
+	pub force_y: Fixed,
+	pub force_z: Fixed,
+}
+
+pub struct Teleport {
+	pub target: i16,
+}
+
+//push_x and push_z point away from what hit the player
+pub struct EnemyHit {
+	pub deadly: bool,
+	pub push_x: Fixed,
+	pub push_z: Fixed,
+}
+
+//count copies of dynamic go into the pool, see DynamicPool::spawn_burst
+pub struct Spawn {
+	pub dynamic: Dynamic,
+	pub count: u8,
+	pub spread: Fixed,
+}
+
+//the player is reading a sign, index is in the level's messages
+pub struct Message {
+	pub index: u16,
+}
+
+//the player picked up a gem, index is its bit in the level's collected gems
+pub struct Collect {
+	pub index: u16,
+}
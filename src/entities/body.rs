@@ -240,6 +240,9 @@ impl Entity for Body {
     fn set_color(&mut self, color: u16) {
         self.color = color;
     }
+    fn get_color(&self) -> u16 {
+        return self.color;
+    }
     fn tick(&mut self, effects: &effects::InputGameState) -> Option<effects::OutputEvents> {
         if effects.yspeed != 0 {
             self.y_offset = effects.yspeed / 3;
@@ -57,6 +57,9 @@ pub struct Bounce {
 
     #[serde(default = "default_fixed")]
     power: Fixed,
+
+    #[serde(default = "default_true")]
+    collidable: bool,
 }
 
 impl Bounce {
@@ -79,8 +82,13 @@ impl Bounce {
             z_rotation_matrix: [[Fixed::const_new(0); 3]; 3],
             color: 0,
             power: Fixed::const_new(256),
+            collidable: true,
         }
     }
+
+    pub fn is_collidable(&self) -> bool {
+        self.collidable
+    }
 }
 
 impl Entity for Bounce {
@@ -380,6 +388,9 @@ impl Entity for Bounce {
     fn set_color(&mut self, color: u16) {
         self.color = color;
     }
+    fn get_color(&self) -> u16 {
+        return self.color;
+    }
     fn tick(&mut self, effects: &effects::InputGameState) -> Option<effects::OutputEvents> {
         if effects.support_below_id == self.id {
             return Some(effects::OutputEvents::BounceEvent(effects::Bounce {
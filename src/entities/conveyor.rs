@@ -0,0 +1,368 @@
+//pushes the player along the z-axis of the block while they stand on it, the block itself stays put
+//use rotation to point the belt in other directions
+
+use agb::InternalAllocator;
+use alloc::vec::Vec;
+use serde::Deserialize;
+
+use super::boundingshapes::BoundingShape;
+use super::math;
+use super::BoundingBox;
+use super::BoundingCylinder;
+use super::Camera;
+use super::Entity;
+use crate::effects;
+use crate::rectangle_model_points;
+use crate::renderer;
+use crate::renderer::polygon::Polygon;
+use math::*;
+
+use crate::fixed;
+use crate::utils;
+use fixed::*;
+
+const STRIPE_COUNT: i32 = 3;
+//lift the stripes a bit above the top face and sort them in front of it
+const STRIPE_LIFT: Fixed = Fixed::from_raw(8);
+const STRIPE_DEPTH_BIAS: Fixed = Fixed::from_raw(64);
+
+#[derive(Copy, Clone, Deserialize, Debug)]
+pub struct Conveyor {
+    #[serde(default = "default_i16")]
+    id: i16,
+    #[serde(default = "default_fixed")]
+    x: Fixed,
+    #[serde(default = "default_fixed")]
+    y: Fixed,
+    #[serde(default = "default_fixed")]
+    z: Fixed,
+
+    #[serde(default = "default_fixed")]
+    xsize: Fixed,
+    #[serde(default = "default_fixed")]
+    ysize: Fixed,
+    #[serde(default = "default_fixed")]
+    zsize: Fixed,
+
+    #[serde(default = "default_fixed")]
+    x_rotation: Fixed,
+    #[serde(rename = "rotation", default = "default_fixed")]
+    y_rotation: Fixed,
+    #[serde(default = "default_fixed")]
+    z_rotation: Fixed,
+
+    #[serde(default = "default_fixed_3_8")]
+    points: [[Fixed; 3]; 8],
+    #[serde(default = "default_fixed_3_8")]
+    model_rotated_points: [[Fixed; 3]; 8],
+
+    #[serde(default = "default_fixed_3_3")]
+    x_rotation_matrix: [[Fixed; 3]; 3],
+    #[serde(default = "default_fixed_3_3")]
+    y_rotation_matrix: [[Fixed; 3]; 3],
+    #[serde(default = "default_fixed_3_3")]
+    z_rotation_matrix: [[Fixed; 3]; 3],
+
+    #[serde(default = "default_u16")]
+    color: u16,
+
+    #[serde(default = "default_fixed")]
+    speed: Fixed,
+    #[serde(default = "positive_i16")]
+    direction: i16,
+
+    //how far the stripes have scrolled, between 0 and the stripe spacing
+    #[serde(default = "default_fixed")]
+    scroll: Fixed,
+
+    #[serde(default = "default_true")]
+    collidable: bool,
+}
+
+impl Conveyor {
+    #[allow(dead_code)]
+    pub fn default() -> Self {
+        Self {
+            id: 0,
+            x: Fixed::const_new(0),
+            y: Fixed::const_new(0),
+            z: Fixed::const_new(0),
+            xsize: Fixed::const_new(0),
+            ysize: Fixed::const_new(0),
+            zsize: Fixed::const_new(0),
+            x_rotation: Fixed::const_new(0),
+            y_rotation: Fixed::const_new(0),
+            z_rotation: Fixed::const_new(0),
+            points: [[Fixed::const_new(0); 3]; 8],
+            model_rotated_points: [[Fixed::const_new(0); 3]; 8],
+            x_rotation_matrix: [[Fixed::const_new(0); 3]; 3],
+            y_rotation_matrix: [[Fixed::const_new(0); 3]; 3],
+            z_rotation_matrix: [[Fixed::const_new(0); 3]; 3],
+            color: 0,
+            speed: Fixed::const_new(0),
+            direction: 1,
+            scroll: Fixed::const_new(0),
+            collidable: true,
+        }
+    }
+
+    pub fn is_collidable(&self) -> bool {
+        self.collidable
+    }
+
+    pub fn reverse(&mut self) {
+        self.direction = -self.direction;
+    }
+
+    fn stripe_spacing(&self) -> Fixed {
+        self.zsize / STRIPE_COUNT
+    }
+
+    //unit vector of the belt in world space, taken from the rotated model so it matches what is drawn
+    fn belt_direction(&self) -> [Fixed; 3] {
+        if self.zsize == Fixed::const_new(0) {
+            return [Fixed::const_new(0); 3];
+        }
+        let axis = vector_sub(self.model_rotated_points[0], self.model_rotated_points[4]);
+        [
+            axis[0] / self.zsize,
+            axis[1] / self.zsize,
+            axis[2] / self.zsize,
+        ]
+    }
+
+    fn rotate_point(&self, point: [Fixed; 3]) -> [Fixed; 3] {
+        let mut rotated_point: [Fixed; 3] = matmul(self.x_rotation_matrix, point);
+        rotated_point = matmul(self.y_rotation_matrix, rotated_point);
+        matmul(self.z_rotation_matrix, rotated_point)
+    }
+
+    fn render_stripes(&self, camera: &Camera, polygons: &mut Vec<Polygon, InternalAllocator>) {
+        let spacing = self.stripe_spacing();
+        let stripe_width = spacing / 4;
+        let half_x = self.xsize / 2 - stripe_width;
+        let half_z = self.zsize / 2;
+        let top = self.ysize / 2 + STRIPE_LIFT;
+        let color = renderer::utils::get_color(self.color, 3);
+
+        for i in 0..STRIPE_COUNT {
+            let start = -half_z + self.scroll + spacing * i;
+            if start >= half_z {
+                continue;
+            }
+            let mut end = start + stripe_width;
+            if end > half_z {
+                end = half_z;
+            }
+
+            let corners: [[Fixed; 3]; 4] = [
+                self.rotate_point([half_x, top, start]),
+                self.rotate_point([-half_x, top, start]),
+                self.rotate_point([-half_x, top, end]),
+                self.rotate_point([half_x, top, end]),
+            ];
+
+            let mut screen_points: [[Fixed; 2]; 4] = [[Fixed::const_new(0); 2]; 4];
+            let mut translated_points: [[Fixed; 3]; 4] = [[Fixed::const_new(0); 3]; 4];
+            for j in 0..corners.len() {
+                (translated_points[j], screen_points[j]) = renderer::render::translate_point(
+                    &corners[j],
+                    camera,
+                    self.x,
+                    self.y,
+                    self.z,
+                );
+            }
+
+            let first = polygons.len();
+            renderer::render::render_face_outline(
+                &screen_points,
+                &translated_points,
+                0,
+                1,
+                2,
+                3,
+                color,
+                polygons,
+                false,
+            );
+            for polygon in polygons[first..].iter_mut() {
+                polygon.distance_from_camera -= STRIPE_DEPTH_BIAS;
+            }
+        }
+    }
+}
+
+impl Entity for Conveyor {
+    fn set_x_offset(&mut self, x_offset: Fixed) {
+        self.x = x_offset;
+    }
+
+    fn set_y_offset(&mut self, y_offset: Fixed) {
+        self.y = y_offset;
+    }
+
+    fn set_z_offset(&mut self, z_offset: Fixed) {
+        self.z = z_offset;
+    }
+
+    fn set_size(&mut self, size: Fixed) {
+        self.xsize = size;
+        self.ysize = size;
+        self.zsize = size;
+    }
+
+    fn recalculate_points(&mut self) {
+        self.points = rectangle_model_points(self.xsize, self.ysize, self.zsize);
+    }
+
+    fn set_x_rotation(&mut self, x_rotation: Fixed) {
+        self.x_rotation = x_rotation;
+        self.x_rotation_matrix = utils::x_rotation_matrix(x_rotation);
+    }
+
+    fn set_y_rotation(&mut self, y_rotation: Fixed) {
+        self.y_rotation = y_rotation;
+        self.y_rotation_matrix = utils::y_rotation_matrix(y_rotation);
+    }
+
+    fn set_z_rotation(&mut self, z_rotation: Fixed) {
+        self.z_rotation = z_rotation;
+        self.z_rotation_matrix = utils::z_rotation_matrix(z_rotation);
+    }
+
+    fn reload_rotation_matrices(&mut self) {
+        self.set_x_rotation(self.x_rotation);
+        self.set_y_rotation(self.y_rotation);
+        self.set_z_rotation(self.z_rotation);
+    }
+    fn refresh_model_matrix(&mut self) {
+        for i in 0..self.points.len() {
+            self.model_rotated_points[i] = self.rotate_point(self.points[i]);
+        }
+    }
+
+    fn set_vertex(&mut self, _point: [Fixed; 3], _index: i32) {
+        //not implemented
+    }
+
+    fn render(
+        &mut self,
+        camera: &Camera,
+        polygons: &mut Vec<Polygon, InternalAllocator>,
+        render_distance: Fixed,
+    ) {
+        if self.distance_from_camera(camera) > render_distance {
+            return;
+        }
+
+        renderer::render::render_rect(
+            &self.model_rotated_points,
+            self.x,
+            self.y,
+            self.z,
+            self.y_rotation,
+            camera,
+            self.color,
+            polygons,
+            false,
+        );
+        self.render_stripes(camera, polygons);
+    }
+
+    fn distance_from_camera(&self, camera: &Camera) -> Fixed {
+        return (self.x - camera.x).abs() + (self.y - camera.y).abs() + (self.z - camera.z).abs();
+    }
+
+    fn bounding_shape(&self) -> Option<BoundingShape> {
+        let points: [[Fixed; 2]; 4] = [
+            [
+                self.model_rotated_points[0][0] + self.x,
+                self.model_rotated_points[0][2] + self.z,
+            ],
+            [
+                self.model_rotated_points[1][0] + self.x,
+                self.model_rotated_points[1][2] + self.z,
+            ],
+            [
+                self.model_rotated_points[5][0] + self.x,
+                self.model_rotated_points[5][2] + self.z,
+            ],
+            [
+                self.model_rotated_points[4][0] + self.x,
+                self.model_rotated_points[4][2] + self.z,
+            ],
+        ];
+
+        Some(BoundingShape::BoundingBox(BoundingBox {
+            data: points,
+            center: utils::calculate_center(&points),
+            width: (self.model_rotated_points[0][0] + self.x
+                - (self.model_rotated_points[1][0] + self.x))
+                .abs(),
+            height: (self.model_rotated_points[1][2] + self.z
+                - (self.model_rotated_points[5][2] + self.z))
+                .abs(),
+            y_top: self.model_rotated_points[0][1] + self.y,
+            y_bottom: self.model_rotated_points[2][1] + self.y,
+            rotation: -self.y_rotation,
+        }))
+    }
+
+    fn bounding_cylinder(&self) -> BoundingCylinder {
+        BoundingCylinder {
+            x: self.x,
+            z: self.z,
+            radius: self.xsize / 2,
+            y_top: self.model_rotated_points[0][1] + self.y,
+            y_bottom: self.model_rotated_points[2][1] + self.y,
+        }
+    }
+    fn get_y(&self) -> Fixed {
+        return self.y;
+    }
+    fn get_height(&self) -> Fixed {
+        return self.ysize;
+    }
+    fn set_color(&mut self, color: u16) {
+        self.color = color;
+    }
+    fn get_color(&self) -> u16 {
+        return self.color;
+    }
+    fn tick(&mut self, effects: &effects::InputGameState) -> Option<effects::OutputEvents> {
+        let spacing = self.stripe_spacing();
+        let step = self.speed * self.direction as i32;
+        if spacing > Fixed::const_new(0) {
+            self.scroll += step;
+            while self.scroll >= spacing {
+                self.scroll -= spacing;
+            }
+            while self.scroll < Fixed::const_new(0) {
+                self.scroll += spacing;
+            }
+        }
+
+        if effects.support_below_id == self.id {
+            let push = vector_mul(self.belt_direction(), step);
+            return Some(effects::OutputEvents::Carry(effects::Carry {
+                move_x: push[0],
+                move_y: Fixed::const_new(0),
+                move_z: push[2],
+                turn: Fixed::const_new(0),
+                pivot_x: self.x,
+                pivot_z: self.z,
+            }));
+        } else {
+            return None;
+        }
+    }
+
+    fn get_id(&self) -> i16 {
+        return self.id;
+    }
+
+    fn set_id(&mut self, id: i16) {
+        self.id = id
+    }
+}
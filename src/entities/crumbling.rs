@@ -9,6 +9,7 @@ use super::BoundingCylinder;
 use super::Camera;
 use super::Entity;
 use crate::effects;
+use crate::pool::{Dynamic, DynamicKind};
 use crate::rectangle_model_points;
 use crate::renderer;
 use crate::renderer::polygon::Polygon;
@@ -18,6 +19,11 @@ use crate::fixed;
 use crate::utils;
 use fixed::*;
 
+const DEBRIS_PIECES: u8 = 4;
+const DEBRIS_SPREAD: Fixed = Fixed::from_raw(16);
+const DEBRIS_LIFT: Fixed = Fixed::from_raw(32);
+const DEBRIS_LIFETIME: u16 = 45;
+
 #[derive(Copy, Clone, Deserialize, Debug)]
 pub struct Crumbling {
     #[serde(default = "default_i16")]
@@ -66,6 +72,9 @@ pub struct Crumbling {
 
     #[serde(default = "positive_i16")]
     shake_direction: i16,
+
+    #[serde(default = "default_true")]
+    collidable: bool,
 }
 
 impl Crumbling {
@@ -91,8 +100,13 @@ impl Crumbling {
             lifetime: 0,
             player_standing_on_rect: 0,
             shake_direction: 1,
+            collidable: true,
         }
     }
+
+    pub fn is_collidable(&self) -> bool {
+        self.collidable
+    }
 }
 
 impl Entity for Crumbling {
@@ -256,10 +270,35 @@ impl Entity for Crumbling {
     fn set_color(&mut self, color: u16) {
         self.color = color;
     }
+    fn get_color(&self) -> u16 {
+        return self.color;
+    }
     fn tick(&mut self, effects: &effects::InputGameState) -> Option<effects::OutputEvents> {
         if self.lifetime > 0 && effects.support_below_id == self.id {
+            //a ground pound breaks the block at once
+            if effects.ground_pound {
+                self.lifetime = 1;
+            }
             self.lifetime -= 1;
             self.player_standing_on_rect = 1;
+            if self.lifetime == 0 {
+                //breaks into pieces that fall away
+                return Some(effects::OutputEvents::Spawn(effects::Spawn {
+                    dynamic: Dynamic {
+                        kind: DynamicKind::Debris,
+                        x: self.x,
+                        y: self.y,
+                        z: self.z,
+                        velocity: [Fixed::const_new(0), DEBRIS_LIFT, Fixed::const_new(0)],
+                        size: self.ysize.min(self.xsize / 2),
+                        color: self.color,
+                        lifetime: DEBRIS_LIFETIME,
+                        deadly: false,
+                    },
+                    count: DEBRIS_PIECES,
+                    spread: DEBRIS_SPREAD,
+                }));
+            }
         } else {
             self.player_standing_on_rect = 0;
         }
@@ -55,6 +55,9 @@ pub struct Cube {
 
     #[serde(default = "default_u16")]
     color: u16,
+
+    #[serde(default = "default_true")]
+    collidable: bool,
 }
 
 impl Cube {
@@ -74,8 +77,13 @@ impl Cube {
             z_rotation_matrix: [[Fixed::const_new(0); 3]; 3],
             color: 0,
             id: 0,
+            collidable: true,
         }
     }
+
+    pub fn is_collidable(&self) -> bool {
+        self.collidable
+    }
     
 
 }
@@ -216,6 +224,9 @@ impl Entity for Cube {
     fn set_color(&mut self, color: u16) {
         self.color = color;
     }
+    fn get_color(&self) -> u16 {
+        return self.color;
+    }
     
     fn tick(&mut self, _effects: &effects::InputGameState) -> Option<effects::OutputEvents> {
         None
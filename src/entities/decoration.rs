@@ -0,0 +1,376 @@
+//scenery that is only rendered, the player passes through it
+//xsize is the width of the shape and ysize the height, the shape is centered on y like the other entities
+
+use agb::InternalAllocator;
+use alloc::vec::Vec;
+use serde::Deserialize;
+
+use super::boundingshapes::BoundingShape;
+use super::math;
+use super::BoundingCylinder;
+use super::Camera;
+use super::Entity;
+use crate::effects;
+use crate::renderer;
+use crate::renderer::polygon::Polygon;
+use crate::renderer::render::back_face_culling;
+use math::*;
+
+use crate::fixed;
+use crate::utils;
+use fixed::*;
+
+const TRUNK_COLOR: u16 = 3;
+//a sixth of a full turn
+const HEX_STEP: Fixed = Fixed::from_raw(43);
+//a quarter turn, with an eighth turn offset so the sides line up with the axes
+const SQUARE_STEP: Fixed = Fixed::from_raw(64);
+const SQUARE_OFFSET: Fixed = Fixed::from_raw(32);
+
+#[derive(Copy, Clone, Deserialize, Debug, PartialEq)]
+pub enum DecorationShape {
+    #[serde(rename = "pillar")]
+    Pillar,
+    #[serde(rename = "tree")]
+    Tree,
+    #[serde(rename = "pyramid")]
+    Pyramid,
+    #[serde(rename = "ring")]
+    Ring,
+}
+
+fn default_shape() -> DecorationShape {
+    DecorationShape::Pillar
+}
+
+#[derive(Copy, Clone, Deserialize, Debug)]
+pub struct Decoration {
+    #[serde(default = "default_i16")]
+    id: i16,
+    #[serde(default = "default_fixed")]
+    x: Fixed,
+    #[serde(default = "default_fixed")]
+    y: Fixed,
+    #[serde(default = "default_fixed")]
+    z: Fixed,
+
+    #[serde(default = "default_fixed")]
+    xsize: Fixed,
+    #[serde(default = "default_fixed")]
+    ysize: Fixed,
+
+    #[serde(default = "default_fixed")]
+    x_rotation: Fixed,
+    #[serde(rename = "rotation", default = "default_fixed")]
+    y_rotation: Fixed,
+    #[serde(default = "default_fixed")]
+    z_rotation: Fixed,
+
+    #[serde(default = "default_fixed_3_16")]
+    points: [[Fixed; 3]; 16],
+    #[serde(default = "default_fixed_3_16")]
+    model_rotated_points: [[Fixed; 3]; 16],
+
+    #[serde(default = "default_fixed_3_3")]
+    x_rotation_matrix: [[Fixed; 3]; 3],
+    #[serde(default = "default_fixed_3_3")]
+    y_rotation_matrix: [[Fixed; 3]; 3],
+    #[serde(default = "default_fixed_3_3")]
+    z_rotation_matrix: [[Fixed; 3]; 3],
+
+    #[serde(default = "default_u16")]
+    color: u16,
+
+    #[serde(default = "default_shape")]
+    shape: DecorationShape,
+    //rotation added on every frame, mostly for rings
+    #[serde(default = "default_fixed")]
+    spin: Fixed,
+}
+
+impl Decoration {
+    #[allow(dead_code)]
+    pub fn default() -> Self {
+        Self {
+            id: 0,
+            x: Fixed::const_new(0),
+            y: Fixed::const_new(0),
+            z: Fixed::const_new(0),
+            xsize: Fixed::const_new(0),
+            ysize: Fixed::const_new(0),
+            x_rotation: Fixed::const_new(0),
+            y_rotation: Fixed::const_new(0),
+            z_rotation: Fixed::const_new(0),
+            points: [[Fixed::const_new(0); 3]; 16],
+            model_rotated_points: [[Fixed::const_new(0); 3]; 16],
+            x_rotation_matrix: [[Fixed::const_new(0); 3]; 3],
+            y_rotation_matrix: [[Fixed::const_new(0); 3]; 3],
+            z_rotation_matrix: [[Fixed::const_new(0); 3]; 3],
+            color: 0,
+            shape: DecorationShape::Pillar,
+            spin: Fixed::const_new(0),
+        }
+    }
+
+    //points around the y-axis, in the order of increasing angle
+    fn set_ring_xz(&mut self, start: usize, count: usize, step: Fixed, offset: Fixed, radius: Fixed, y: Fixed) {
+        for i in 0..count {
+            let angle = offset + step * i as i32;
+            self.points[start + i] = [radius * angle.cos(), y, radius * angle.sin()];
+        }
+    }
+
+    //points around the z-axis, for shapes that stand upright like the finish portal
+    fn set_ring_xy(&mut self, start: usize, count: usize, radius: Fixed) {
+        for i in 0..count {
+            let angle = HEX_STEP * i as i32;
+            self.points[start + i] = [radius * angle.cos(), radius * angle.sin(), Fixed::const_new(0)];
+        }
+    }
+}
+
+//sides of a prism between two rings of points, faces outwards
+fn push_prism_sides(
+    translated_points: &[[Fixed; 3]],
+    screen_points: &[[Fixed; 2]],
+    bottom: usize,
+    top: usize,
+    count: usize,
+    color: u16,
+    polygons: &mut Vec<Polygon, InternalAllocator>,
+) {
+    for i in 0..count {
+        let j = (i + 1) % count;
+        let shade = (i % 2 + 1) as i16;
+        push_triangle(translated_points, screen_points, bottom + i, top + i, top + j, color, shade, polygons);
+        push_triangle(translated_points, screen_points, bottom + i, top + j, bottom + j, color, shade, polygons);
+    }
+}
+
+//sides of a cone from a ring of points to a tip
+fn push_cone_sides(
+    translated_points: &[[Fixed; 3]],
+    screen_points: &[[Fixed; 2]],
+    base: usize,
+    tip: usize,
+    count: usize,
+    color: u16,
+    polygons: &mut Vec<Polygon, InternalAllocator>,
+) {
+    for i in 0..count {
+        let j = (i + 1) % count;
+        let shade = (i % 2 + 1) as i16;
+        push_triangle(translated_points, screen_points, base + i, tip, base + j, color, shade, polygons);
+    }
+}
+
+fn push_triangle(
+    translated_points: &[[Fixed; 3]],
+    screen_points: &[[Fixed; 2]],
+    a: usize,
+    b: usize,
+    c: usize,
+    color: u16,
+    shade: i16,
+    polygons: &mut Vec<Polygon, InternalAllocator>,
+) {
+    if back_face_culling(translated_points, a, b, c) {
+        polygons.push(Polygon {
+            distance_from_camera: renderer::utils::polygon_avg_z(translated_points, a, b, c),
+            shape: renderer::polygon::Shape::Triangle([
+                screen_points[a],
+                screen_points[b],
+                screen_points[c],
+            ]),
+            color: renderer::utils::get_color(color, shade),
+            draw_always: false,
+        });
+    }
+}
+
+impl Entity for Decoration {
+    fn set_x_offset(&mut self, x_offset: Fixed) {
+        self.x = x_offset;
+    }
+
+    fn set_y_offset(&mut self, y_offset: Fixed) {
+        self.y = y_offset;
+    }
+
+    fn set_z_offset(&mut self, z_offset: Fixed) {
+        self.z = z_offset;
+    }
+
+    fn set_size(&mut self, size: Fixed) {
+        self.xsize = size;
+        self.ysize = size;
+    }
+
+    fn recalculate_points(&mut self) {
+        let radius = self.xsize / 2;
+        let half_height = self.ysize / 2;
+        match self.shape {
+            DecorationShape::Pillar => {
+                //0..6 bottom, 6..12 top
+                self.set_ring_xz(0, 6, HEX_STEP, Fixed::const_new(0), radius, -half_height);
+                self.set_ring_xz(6, 6, HEX_STEP, Fixed::const_new(0), radius, half_height);
+            }
+            DecorationShape::Pyramid => {
+                //0..4 base, 4 tip
+                self.set_ring_xz(0, 4, SQUARE_STEP, SQUARE_OFFSET, radius, -half_height);
+                self.points[4] = [Fixed::const_new(0), half_height, Fixed::const_new(0)];
+            }
+            DecorationShape::Tree => {
+                //0..4 trunk bottom, 4..8 trunk top, 8..14 crown base, 14 tip
+                let trunk_top = -half_height + self.ysize / 3;
+                let crown_bottom = -half_height + self.ysize / 4;
+                self.set_ring_xz(0, 4, SQUARE_STEP, SQUARE_OFFSET, radius / 4, -half_height);
+                self.set_ring_xz(4, 4, SQUARE_STEP, SQUARE_OFFSET, radius / 4, trunk_top);
+                self.set_ring_xz(8, 6, HEX_STEP, Fixed::const_new(0), radius, crown_bottom);
+                self.points[14] = [Fixed::const_new(0), half_height, Fixed::const_new(0)];
+            }
+            DecorationShape::Ring => {
+                //0..6 outer edge, 6..12 inner edge
+                self.set_ring_xy(0, 6, radius);
+                self.set_ring_xy(6, 6, radius * 3 / 4);
+            }
+        }
+    }
+
+    fn set_x_rotation(&mut self, x_rotation: Fixed) {
+        self.x_rotation = x_rotation;
+        self.x_rotation_matrix = utils::x_rotation_matrix(x_rotation);
+    }
+
+    fn set_y_rotation(&mut self, y_rotation: Fixed) {
+        self.y_rotation = y_rotation;
+        self.y_rotation_matrix = utils::y_rotation_matrix(y_rotation);
+    }
+
+    fn set_z_rotation(&mut self, z_rotation: Fixed) {
+        self.z_rotation = z_rotation;
+        self.z_rotation_matrix = utils::z_rotation_matrix(z_rotation);
+    }
+
+    fn reload_rotation_matrices(&mut self) {
+        self.set_x_rotation(self.x_rotation);
+        self.set_y_rotation(self.y_rotation);
+        self.set_z_rotation(self.z_rotation);
+    }
+
+    fn refresh_model_matrix(&mut self) {
+        for i in 0..self.points.len() {
+            let point: &[Fixed; 3] = &self.points[i];
+
+            let mut rotated_point: [Fixed; 3] = matmul(self.x_rotation_matrix, *point);
+            rotated_point = matmul(self.y_rotation_matrix, rotated_point);
+            rotated_point = matmul(self.z_rotation_matrix, rotated_point);
+
+            self.model_rotated_points[i] = rotated_point;
+        }
+    }
+
+    fn set_vertex(&mut self, _point: [Fixed; 3], _index: i32) {
+        //not implemented
+    }
+
+    fn render(
+        &mut self,
+        camera: &Camera,
+        polygons: &mut Vec<Polygon, InternalAllocator>,
+        render_distance: Fixed,
+    ) {
+        if self.distance_from_camera(camera) > render_distance {
+            return;
+        }
+
+        let mut screen_points: [[Fixed; 2]; 16] = [[Fixed::const_new(0), Fixed::const_new(0)]; 16];
+        let mut translated_points: [[Fixed; 3]; 16] = [[Fixed::const_new(0); 3]; 16];
+        for i in 0..self.model_rotated_points.len() {
+            (translated_points[i], screen_points[i]) = renderer::render::translate_point(
+                &self.model_rotated_points[i],
+                camera,
+                self.x,
+                self.y,
+                self.z,
+            );
+        }
+        let t = &translated_points;
+        let s = &screen_points;
+
+        match self.shape {
+            DecorationShape::Pillar => {
+                push_prism_sides(t, s, 0, 6, 6, self.color, polygons);
+                //top cap as a fan from the first corner
+                for i in 1..5 {
+                    push_triangle(t, s, 6, 6 + i + 1, 6 + i, self.color, 0, polygons);
+                }
+            }
+            DecorationShape::Pyramid => {
+                push_cone_sides(t, s, 0, 4, 4, self.color, polygons);
+            }
+            DecorationShape::Tree => {
+                push_prism_sides(t, s, 0, 4, 4, TRUNK_COLOR, polygons);
+                push_cone_sides(t, s, 8, 14, 6, self.color, polygons);
+                //underside of the crown, visible when the player is below it
+                for i in 1..5 {
+                    push_triangle(t, s, 8, 8 + i, 8 + i + 1, self.color, 3, polygons);
+                }
+            }
+            DecorationShape::Ring => {
+                //flat, so both windings are added and culling picks the side facing the camera
+                for i in 0..6 {
+                    let j = (i + 1) % 6;
+                    let shade = (i % 2) as i16;
+                    push_triangle(t, s, i, j, 6 + j, self.color, shade, polygons);
+                    push_triangle(t, s, i, 6 + j, 6 + i, self.color, shade, polygons);
+                    push_triangle(t, s, i, 6 + j, j, self.color, shade + 1, polygons);
+                    push_triangle(t, s, i, 6 + i, 6 + j, self.color, shade + 1, polygons);
+                }
+            }
+        }
+    }
+
+    fn distance_from_camera(&self, camera: &Camera) -> Fixed {
+        return (self.x - camera.x).abs() + (self.y - camera.y).abs() + (self.z - camera.z).abs();
+    }
+
+    fn bounding_shape(&self) -> Option<BoundingShape> {
+        //only for looks, no collision
+        None
+    }
+
+    fn bounding_cylinder(&self) -> BoundingCylinder {
+        BoundingCylinder::empty()
+    }
+
+    fn get_y(&self) -> Fixed {
+        return self.y;
+    }
+    fn get_height(&self) -> Fixed {
+        return self.ysize;
+    }
+
+    fn set_color(&mut self, color: u16) {
+        self.color = color;
+    }
+    fn get_color(&self) -> u16 {
+        return self.color;
+    }
+
+    fn tick(&mut self, _effects: &effects::InputGameState) -> Option<effects::OutputEvents> {
+        if self.spin != Fixed::const_new(0) {
+            self.set_y_rotation(self.y_rotation + self.spin);
+            self.refresh_model_matrix();
+        }
+        None
+    }
+
+    fn get_id(&self) -> i16 {
+        return self.id;
+    }
+
+    fn set_id(&mut self, id: i16) {
+        self.id = id
+    }
+}
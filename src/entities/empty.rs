@@ -48,6 +48,9 @@ impl Entity for Empty {
     }
 
     fn set_color(&mut self, _color: u16) {}
+    fn get_color(&self) -> u16 {
+        return 0;
+    }
 
     fn tick(&mut self, _effects: &effects::InputGameState) -> Option<effects::OutputEvents> {
         None
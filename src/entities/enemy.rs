@@ -0,0 +1,471 @@
+//enemies hurt the player on contact and are defeated by landing on them
+//patrollers walk between waypoints, chasers follow the player near their starting point
+//turrets shoot at the player when they are in range and cannons keep firing straight ahead
+//shots go into the dynamic pool, so they keep flying after the enemy is gone
+
+use agb::InternalAllocator;
+use alloc::vec::Vec;
+use serde::Deserialize;
+
+use super::boundingshapes::BoundingShape;
+use super::math;
+use super::BoundingBox;
+use super::BoundingCylinder;
+use super::Camera;
+use super::Entity;
+use crate::effects;
+use crate::pool::{Dynamic, DynamicKind};
+use crate::rectangle_model_points;
+use crate::renderer;
+use crate::renderer::polygon::Polygon;
+use crate::utils::FixedList;
+use math::*;
+
+use crate::fixed;
+use crate::utils;
+use fixed::*;
+
+const STOMP_POWER: Fixed = Fixed::from_raw(192);
+//extra reach for contact, as the collision checks keep the player from ever overlapping the enemy
+const CONTACT_MARGIN: Fixed = Fixed::from_raw(32);
+const PROJECTILE_SIZE: Fixed = Fixed::from_raw(96);
+const PROJECTILE_LIFETIME: u16 = 90;
+
+#[derive(Copy, Clone, Deserialize, Debug, PartialEq)]
+pub enum EnemyKind {
+    #[serde(rename = "patroller")]
+    Patroller,
+    #[serde(rename = "chaser")]
+    Chaser,
+    #[serde(rename = "turret")]
+    Turret,
+    //fires along its local x axis whether the player is near or not
+    #[serde(rename = "cannon")]
+    Cannon,
+}
+
+fn default_kind() -> EnemyKind {
+    EnemyKind::Patroller
+}
+
+fn default_size() -> Fixed {
+    Fixed::const_new(1)
+}
+
+fn default_fire_interval() -> u16 {
+    60
+}
+
+#[derive(Copy, Clone, Deserialize, Debug)]
+pub struct Enemy {
+    #[serde(default = "default_i16")]
+    id: i16,
+    #[serde(default = "default_fixed")]
+    x: Fixed,
+    #[serde(default = "default_fixed")]
+    y: Fixed,
+    #[serde(default = "default_fixed")]
+    z: Fixed,
+
+    #[serde(default = "default_size")]
+    size: Fixed,
+
+    #[serde(default = "default_fixed")]
+    x_rotation: Fixed,
+    #[serde(rename = "rotation", default = "default_fixed")]
+    y_rotation: Fixed,
+    #[serde(default = "default_fixed")]
+    z_rotation: Fixed,
+
+    #[serde(default = "default_fixed_3_8")]
+    points: [[Fixed; 3]; 8],
+    #[serde(default = "default_fixed_3_8")]
+    model_rotated_points: [[Fixed; 3]; 8],
+
+    #[serde(default = "default_fixed_3_3")]
+    x_rotation_matrix: [[Fixed; 3]; 3],
+    #[serde(default = "default_fixed_3_3")]
+    y_rotation_matrix: [[Fixed; 3]; 3],
+    #[serde(default = "default_fixed_3_3")]
+    z_rotation_matrix: [[Fixed; 3]; 3],
+
+    #[serde(default = "default_u16")]
+    color: u16,
+
+    #[serde(default = "default_kind")]
+    kind: EnemyKind,
+    #[serde(default = "default_fixed")]
+    speed: Fixed,
+    //true to fail the level on contact, otherwise the player gets knocked back
+    #[serde(default = "default_bool")]
+    deadly: bool,
+
+    //[x, z] points a patroller walks through in order
+    #[serde(default)]
+    waypoints: FixedList<[Fixed; 2], 4>,
+    #[serde(default = "default_u16")]
+    waypoint: u16,
+
+    //how far from its starting point a chaser notices the player, and how far a turret shoots
+    #[serde(default = "default_fixed")]
+    radius: Fixed,
+    #[serde(default = "default_fixed")]
+    home_x: Fixed,
+    #[serde(default = "default_fixed")]
+    home_z: Fixed,
+
+    #[serde(default = "default_fire_interval")]
+    fire_interval: u16,
+    #[serde(default = "default_fixed")]
+    projectile_speed: Fixed,
+    #[serde(default = "default_u16")]
+    cooldown: u16,
+
+    #[serde(default = "default_bool")]
+    defeated: bool,
+
+    #[serde(default = "default_true")]
+    collidable: bool,
+}
+
+impl Enemy {
+    #[allow(dead_code)]
+    pub fn default() -> Self {
+        Self {
+            id: 0,
+            x: Fixed::const_new(0),
+            y: Fixed::const_new(0),
+            z: Fixed::const_new(0),
+            size: Fixed::const_new(1),
+            x_rotation: Fixed::const_new(0),
+            y_rotation: Fixed::const_new(0),
+            z_rotation: Fixed::const_new(0),
+            points: [[Fixed::const_new(0); 3]; 8],
+            model_rotated_points: [[Fixed::const_new(0); 3]; 8],
+            x_rotation_matrix: [[Fixed::const_new(0); 3]; 3],
+            y_rotation_matrix: [[Fixed::const_new(0); 3]; 3],
+            z_rotation_matrix: [[Fixed::const_new(0); 3]; 3],
+            color: 0,
+            kind: EnemyKind::Patroller,
+            speed: Fixed::const_new(0),
+            deadly: false,
+            waypoints: FixedList::default(),
+            waypoint: 0,
+            radius: Fixed::const_new(0),
+            home_x: Fixed::const_new(0),
+            home_z: Fixed::const_new(0),
+            fire_interval: 60,
+            projectile_speed: Fixed::const_new(0),
+            cooldown: 0,
+            defeated: false,
+            collidable: true,
+        }
+    }
+
+    pub fn is_collidable(&self) -> bool {
+        self.collidable
+    }
+
+    //moves towards the target on the xz plane, returns true once it is reached
+    fn step_toward(&mut self, target: [Fixed; 2]) -> bool {
+        let diff = vector_sub_2d(target, [self.x, self.z]);
+        let distance = vector_len_2d(diff);
+        if distance <= self.speed {
+            self.x = target[0];
+            self.z = target[1];
+            return true;
+        }
+        self.x += diff[0] * self.speed / distance;
+        self.z += diff[1] * self.speed / distance;
+        false
+    }
+
+    fn patrol(&mut self) {
+        if self.waypoints.len == 0 {
+            return;
+        }
+        let target = self.waypoints.items[self.waypoint as usize];
+        if self.step_toward(target) {
+            self.waypoint = (self.waypoint + 1) % self.waypoints.len as u16;
+        }
+    }
+
+    fn chase(&mut self, player: [Fixed; 2]) {
+        let home = [self.home_x, self.home_z];
+        if vector_len_2d(vector_sub_2d(player, home)) < self.radius {
+            self.step_toward(player);
+        } else {
+            self.step_toward(home);
+        }
+    }
+
+    //unit direction towards the player when they are within range
+    fn aim(&self, player: [Fixed; 2]) -> Option<[Fixed; 2]> {
+        let diff = vector_sub_2d(player, [self.x, self.z]);
+        let distance = vector_len_2d(diff);
+        if distance < self.radius && distance > Fixed::const_new(0) {
+            return Some([diff[0] / distance, diff[1] / distance]);
+        }
+        None
+    }
+
+    fn facing(&self) -> [Fixed; 2] {
+        let direction = matmul(
+            self.y_rotation_matrix,
+            [Fixed::const_new(1), Fixed::const_new(0), Fixed::const_new(0)],
+        );
+        [direction[0], direction[2]]
+    }
+
+    //counts down the cooldown and returns the direction to fire in once it runs out
+    fn fire(&mut self, direction: Option<[Fixed; 2]>) -> Option<[Fixed; 2]> {
+        if self.cooldown > 0 {
+            self.cooldown -= 1;
+        }
+        if self.cooldown > 0 {
+            return None;
+        }
+        if direction.is_some() {
+            self.cooldown = self.fire_interval;
+        }
+        direction
+    }
+
+    fn shot_event(&self, direction: [Fixed; 2]) -> effects::OutputEvents {
+        effects::OutputEvents::Spawn(effects::Spawn {
+            dynamic: Dynamic {
+                kind: DynamicKind::Projectile,
+                x: self.x,
+                y: self.y,
+                z: self.z,
+                velocity: [
+                    direction[0] * self.projectile_speed,
+                    Fixed::const_new(0),
+                    direction[1] * self.projectile_speed,
+                ],
+                size: PROJECTILE_SIZE,
+                color: self.color,
+                lifetime: PROJECTILE_LIFETIME,
+                deadly: self.deadly,
+            },
+            count: 1,
+            spread: Fixed::const_new(0),
+        })
+    }
+
+    fn touches_player(&self, player_box: &BoundingBox, player_cylinder: &BoundingCylinder) -> bool {
+        let half = self.size / 2;
+        if player_box.y_bottom >= self.y + half || player_box.y_top <= self.y - half {
+            return false;
+        }
+        let distance = vector_len_2d(vector_sub_2d(
+            [player_cylinder.x, player_cylinder.z],
+            [self.x, self.z],
+        ));
+        distance < half + player_cylinder.radius + CONTACT_MARGIN
+    }
+
+    fn hit_event(&self, from: [Fixed; 2], player: [Fixed; 2]) -> effects::OutputEvents {
+        let diff = vector_sub_2d(player, from);
+        let distance = vector_len_2d(diff);
+        let (push_x, push_z) = if distance > Fixed::const_new(0) {
+            (diff[0] / distance, diff[1] / distance)
+        } else {
+            (Fixed::const_new(0), Fixed::const_new(0))
+        };
+        effects::OutputEvents::EnemyHit(effects::EnemyHit {
+            deadly: self.deadly,
+            push_x,
+            push_z,
+        })
+    }
+}
+
+impl Entity for Enemy {
+    fn set_x_offset(&mut self, x_offset: Fixed) {
+        self.x = x_offset;
+    }
+
+    fn set_y_offset(&mut self, y_offset: Fixed) {
+        self.y = y_offset;
+    }
+
+    fn set_z_offset(&mut self, z_offset: Fixed) {
+        self.z = z_offset;
+    }
+
+    fn set_size(&mut self, size: Fixed) {
+        self.size = size;
+    }
+
+    fn recalculate_points(&mut self) {
+        self.points = rectangle_model_points(self.size, self.size, self.size);
+        self.home_x = self.x;
+        self.home_z = self.z;
+    }
+
+    fn set_x_rotation(&mut self, x_rotation: Fixed) {
+        self.x_rotation = x_rotation;
+        self.x_rotation_matrix = utils::x_rotation_matrix(x_rotation);
+    }
+
+    fn set_y_rotation(&mut self, y_rotation: Fixed) {
+        self.y_rotation = y_rotation;
+        self.y_rotation_matrix = utils::y_rotation_matrix(y_rotation);
+    }
+
+    fn set_z_rotation(&mut self, z_rotation: Fixed) {
+        self.z_rotation = z_rotation;
+        self.z_rotation_matrix = utils::z_rotation_matrix(z_rotation);
+    }
+
+    fn reload_rotation_matrices(&mut self) {
+        self.set_x_rotation(self.x_rotation);
+        self.set_y_rotation(self.y_rotation);
+        self.set_z_rotation(self.z_rotation);
+    }
+
+    fn refresh_model_matrix(&mut self) {
+        for i in 0..self.points.len() {
+            let point: &[Fixed; 3] = &self.points[i];
+
+            let mut rotated_point: [Fixed; 3] = matmul(self.x_rotation_matrix, *point);
+            rotated_point = matmul(self.y_rotation_matrix, rotated_point);
+            rotated_point = matmul(self.z_rotation_matrix, rotated_point);
+
+            self.model_rotated_points[i] = rotated_point;
+        }
+    }
+
+    fn set_vertex(&mut self, _point: [Fixed; 3], _index: i32) {
+        //not implemented
+    }
+
+    fn render(
+        &mut self,
+        camera: &Camera,
+        polygons: &mut Vec<Polygon, InternalAllocator>,
+        render_distance: Fixed,
+    ) {
+        if self.distance_from_camera(camera) > render_distance {
+            return;
+        }
+
+        if self.defeated {
+            return;
+        }
+
+        renderer::render::render_rect(
+            &self.model_rotated_points,
+            self.x,
+            self.y,
+            self.z,
+            self.y_rotation,
+            camera,
+            self.color,
+            polygons,
+            false,
+        );
+    }
+
+    fn distance_from_camera(&self, camera: &Camera) -> Fixed {
+        return (self.x - camera.x).abs() + (self.y - camera.y).abs() + (self.z - camera.z).abs();
+    }
+
+    fn bounding_shape(&self) -> Option<BoundingShape> {
+        if self.defeated {
+            return None;
+        }
+        let points: [[Fixed; 2]; 4] = [
+            [
+                self.model_rotated_points[0][0] + self.x,
+                self.model_rotated_points[0][2] + self.z,
+            ],
+            [
+                self.model_rotated_points[1][0] + self.x,
+                self.model_rotated_points[1][2] + self.z,
+            ],
+            [
+                self.model_rotated_points[5][0] + self.x,
+                self.model_rotated_points[5][2] + self.z,
+            ],
+            [
+                self.model_rotated_points[4][0] + self.x,
+                self.model_rotated_points[4][2] + self.z,
+            ],
+        ];
+
+        Some(BoundingShape::BoundingBox(BoundingBox {
+            data: points,
+            center: utils::calculate_center(&points),
+            width: self.size,
+            height: self.size,
+            y_top: self.model_rotated_points[0][1] + self.y,
+            y_bottom: self.model_rotated_points[2][1] + self.y,
+            rotation: -self.y_rotation,
+        }))
+    }
+
+    fn bounding_cylinder(&self) -> BoundingCylinder {
+        BoundingCylinder {
+            x: self.x,
+            z: self.z,
+            radius: self.size / 2,
+            y_top: self.model_rotated_points[0][1] + self.y,
+            y_bottom: self.model_rotated_points[2][1] + self.y,
+        }
+    }
+
+    fn get_y(&self) -> Fixed {
+        return self.y;
+    }
+    fn get_height(&self) -> Fixed {
+        return self.size;
+    }
+
+    fn set_color(&mut self, color: u16) {
+        self.color = color;
+    }
+    fn get_color(&self) -> u16 {
+        return self.color;
+    }
+
+    fn tick(&mut self, effects: &effects::InputGameState) -> Option<effects::OutputEvents> {
+        if self.defeated {
+            return None;
+        }
+        let player = [effects.bounding_cylinder.x, effects.bounding_cylinder.z];
+
+        //landing on top, the support is found just before touching down while the player is still falling
+        if effects.support_below_id == self.id && effects.yspeed < 0 {
+            self.defeated = true;
+            return Some(effects::OutputEvents::BounceEvent(effects::Bounce {
+                power: STOMP_POWER,
+            }));
+        }
+
+        let mut shot: Option<[Fixed; 2]> = None;
+        match self.kind {
+            EnemyKind::Patroller => self.patrol(),
+            EnemyKind::Chaser => self.chase(player),
+            EnemyKind::Turret => shot = self.fire(self.aim(player)),
+            EnemyKind::Cannon => shot = self.fire(Some(self.facing())),
+        }
+
+        if self.touches_player(effects.bounding_box, effects.bounding_cylinder) {
+            return Some(self.hit_event([self.x, self.z], player));
+        }
+        if let Some(direction) = shot {
+            return Some(self.shot_event(direction));
+        }
+        None
+    }
+
+    fn get_id(&self) -> i16 {
+        return self.id;
+    }
+
+    fn set_id(&mut self, id: i16) {
+        self.id = id
+    }
+}
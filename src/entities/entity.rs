@@ -28,6 +28,7 @@ pub trait Entity {
     fn get_y(&self) -> Fixed;
     fn get_height(&self) -> Fixed;
     fn set_color(&mut self, color: u16);
+    fn get_color(&self) -> u16;
     fn tick(&mut self, _effects: &effects::InputGameState) -> Option<effects::OutputEvents>;
     fn get_id(&self) -> i16;
     fn set_id(&mut self, id: i16);
@@ -4,9 +4,7 @@ use serde::Deserialize;
 
 use super::boundingshapes::BoundingShape;
 use super::math;
-use super::utils::cylinder_and_rotated_rect_collision;
-use super::utils::rect_overlap;
-use super::utils::rect_simple_overlap_check;
+use super::utils::volume_overlap_check;
 use super::BoundingBox;
 use super::BoundingCylinder;
 use super::Camera;
@@ -62,6 +60,302 @@ pub struct Finish {
     radius: Fixed,
     #[serde(default = "default_fixed")]
     depth: Fixed,
+
+    #[serde(default = "default_true")]
+    collidable: bool,
+}
+
+pub fn portal_points(radius: Fixed, depth: Fixed) -> [[Fixed; 3]; 14] {
+    let mut points: [[Fixed; 3]; 14] = [[Fixed::const_new(0); 3]; 14];
+    //front face
+    points[0] = [Fixed::const_new(0), Fixed::const_new(0), depth / 2];
+
+    for i in 1..7 {
+        let angle = Fixed::from_raw(43) * i; // Angle in radians (i * 60 degrees)
+        points[i] = [
+            radius * angle.cos(),
+            radius * angle.sin(),
+            depth / 2,
+        ];
+    }
+    //back face
+    points[7] = [Fixed::const_new(0), Fixed::const_new(0), -depth / 2];
+
+    for i in 8..14 {
+        let angle = Fixed::from_raw(43) * (i - 7); // Angle in radians (i * 60 degrees)
+        points[i] = [
+            radius * angle.cos(),
+            radius * angle.sin(),
+            -depth / 2,
+        ];
+    }
+    points
+}
+
+pub fn portal_bounding_box(
+    model_rotated_points: &[[Fixed; 3]; 14],
+    x: Fixed,
+    y: Fixed,
+    z: Fixed,
+    radius: Fixed,
+    y_rotation: Fixed,
+) -> BoundingBox {
+    let points: [[Fixed; 2]; 4] = [
+        [
+            model_rotated_points[1][0] + x,
+            model_rotated_points[1][2] + z,
+        ],
+        [
+            model_rotated_points[4][0] + x,
+            model_rotated_points[4][2] + z,
+        ],
+        [
+            model_rotated_points[11][0] + x,
+            model_rotated_points[11][2] + z,
+        ],
+        [
+            model_rotated_points[8][0] + x,
+            model_rotated_points[8][2] + z,
+        ],
+    ];
+    BoundingBox {
+        data: points,
+        center: utils::calculate_center(&points),
+        width: (model_rotated_points[1][0] + x
+            - (model_rotated_points[4][0] + x))
+            .abs(),
+        height: (model_rotated_points[2][2] + z
+            - (model_rotated_points[5][2] + z))
+            .abs(),
+        y_top: radius + y,
+        y_bottom: -radius + y,
+        rotation: y_rotation,
+    }
+}
+
+//the hexagonal portal shared by the finish and teleporters
+pub fn render_portal(
+    model_rotated_points: &[[Fixed; 3]; 14],
+    x: Fixed,
+    y: Fixed,
+    z: Fixed,
+    camera: &Camera,
+    color: u16,
+    polygons: &mut Vec<Polygon, InternalAllocator>,
+) {
+    let mut screen_points: [[Fixed; 2]; 14] = [[Fixed::const_new(0), Fixed::const_new(0)]; 14];
+    let mut translated_points: [[Fixed; 3]; 14] = [[
+        Fixed::const_new(0),
+        Fixed::const_new(0),
+        Fixed::const_new(0),
+    ]; 14];
+
+    for i in 0..(model_rotated_points).len() {
+        (translated_points[i], screen_points[i]) = renderer::render::translate_point(
+            &model_rotated_points[i],
+            camera,
+            x,
+            y,
+            z,
+        );
+    }
+
+    let visible: bool = back_face_culling(&translated_points, 0, 1, 2);
+    if visible {
+        let color: u16 = renderer::utils::get_color(color, 0);
+        let distance0 = renderer::utils::polygon_avg_z(&translated_points, 0, 1, 2);
+        polygons.push(Polygon {
+            distance_from_camera: distance0,
+            shape: renderer::polygon::Shape::Triangle([
+                screen_points[0],
+                screen_points[1],
+                screen_points[2],
+            ]),
+            color: color,
+            draw_always: false,
+        });
+        let distance0 = renderer::utils::polygon_avg_z(&translated_points, 0, 2, 3);
+        polygons.push(Polygon {
+            distance_from_camera: distance0,
+            shape: renderer::polygon::Shape::Triangle([
+                screen_points[0],
+                screen_points[2],
+                screen_points[3],
+            ]),
+            color: color,
+            draw_always: false,
+        });
+        let distance0 = renderer::utils::polygon_avg_z(&translated_points, 0, 3, 4);
+        polygons.push(Polygon {
+            distance_from_camera: distance0,
+            shape: renderer::polygon::Shape::Triangle([
+                screen_points[0],
+                screen_points[3],
+                screen_points[4],
+            ]),
+            color: color,
+            draw_always: false,
+        });
+        let distance0 = renderer::utils::polygon_avg_z(&translated_points, 0, 4, 5);
+        polygons.push(Polygon {
+            distance_from_camera: distance0,
+            shape: renderer::polygon::Shape::Triangle([
+                screen_points[0],
+                screen_points[4],
+                screen_points[5],
+            ]),
+            color: color,
+            draw_always: false,
+        });
+        let distance0 = renderer::utils::polygon_avg_z(&translated_points, 0, 5, 6);
+        polygons.push(Polygon {
+            distance_from_camera: distance0,
+            shape: renderer::polygon::Shape::Triangle([
+                screen_points[0],
+                screen_points[5],
+                screen_points[6],
+            ]),
+            color: color,
+            draw_always: false,
+        });
+        let distance0 = renderer::utils::polygon_avg_z(&translated_points, 0, 6, 1);
+        polygons.push(Polygon {
+            distance_from_camera: distance0,
+            shape: renderer::polygon::Shape::Triangle([
+                screen_points[0],
+                screen_points[6],
+                screen_points[1],
+            ]),
+            color: color,
+            draw_always: false,
+        });
+    }
+    let visible: bool = back_face_culling(&translated_points, 7, 9, 8);
+    if visible {
+        let color: u16 = renderer::utils::get_color(color, 0);
+        let distance0 = renderer::utils::polygon_avg_z(&translated_points, 7, 8, 9);
+        polygons.push(Polygon {
+            distance_from_camera: distance0,
+            shape: renderer::polygon::Shape::Triangle([
+                screen_points[7],
+                screen_points[8],
+                screen_points[9],
+            ]),
+            color: color,
+            draw_always: false,
+        });
+        let distance0 = renderer::utils::polygon_avg_z(&translated_points, 7, 9, 10);
+        polygons.push(Polygon {
+            distance_from_camera: distance0,
+            shape: renderer::polygon::Shape::Triangle([
+                screen_points[7],
+                screen_points[9],
+                screen_points[10],
+            ]),
+            color: color,
+            draw_always: false,
+        });
+        let distance0 = renderer::utils::polygon_avg_z(&translated_points, 7, 10, 11);
+        polygons.push(Polygon {
+            distance_from_camera: distance0,
+            shape: renderer::polygon::Shape::Triangle([
+                screen_points[7],
+                screen_points[10],
+                screen_points[11],
+            ]),
+            color: color,
+            draw_always: false,
+        });
+        let distance0 = renderer::utils::polygon_avg_z(&translated_points, 7, 11, 12);
+        polygons.push(Polygon {
+            distance_from_camera: distance0,
+            shape: renderer::polygon::Shape::Triangle([
+                screen_points[7],
+                screen_points[11],
+                screen_points[12],
+            ]),
+            color: color,
+            draw_always: false,
+        });
+
+        let distance0 = renderer::utils::polygon_avg_z(&translated_points, 7, 12, 13);
+        polygons.push(Polygon {
+            distance_from_camera: distance0,
+            shape: renderer::polygon::Shape::Triangle([
+                screen_points[7],
+                screen_points[12],
+                screen_points[13],
+            ]),
+            color: color,
+            draw_always: false,
+        });
+        let distance0 = renderer::utils::polygon_avg_z(&translated_points, 7, 13, 8);
+        polygons.push(Polygon {
+            distance_from_camera: distance0,
+            shape: renderer::polygon::Shape::Triangle([
+                screen_points[7],
+                screen_points[13],
+                screen_points[8],
+            ]),
+            color: color,
+            draw_always: false,
+        });
+    }
+
+    for i in 1..6 {
+        let visible: bool = back_face_culling(&translated_points, i, i + 8, i + 1);
+        if visible {
+            let color: u16 = renderer::utils::get_color(color, (i % 3 + 1) as i16);
+            let distance0 = renderer::utils::polygon_avg_z(&translated_points, i, i + 8, i + 1);
+            polygons.push(Polygon {
+                distance_from_camera: distance0,
+                shape: renderer::polygon::Shape::Triangle([
+                    screen_points[i],
+                    screen_points[i + 8],
+                    screen_points[i + 1],
+                ]),
+                color: color,
+                draw_always: false,
+            });
+            let distance0 = renderer::utils::polygon_avg_z(&translated_points, i, i + 7, i + 8);
+            polygons.push(Polygon {
+                distance_from_camera: distance0,
+                shape: renderer::polygon::Shape::Triangle([
+                    screen_points[i],
+                    screen_points[i + 8],
+                    screen_points[i + 7],
+                ]),
+                color: color,
+                draw_always: false,
+            });
+        }
+    }
+    let visible: bool = back_face_culling(&translated_points, 6, 13, 8);
+    if visible {
+        let color: u16 = renderer::utils::get_color(color, 1);
+        let distance0 = renderer::utils::polygon_avg_z(&translated_points, 6, 13, 8);
+        polygons.push(Polygon {
+            distance_from_camera: distance0,
+            shape: renderer::polygon::Shape::Triangle([
+                screen_points[6],
+                screen_points[13],
+                screen_points[8],
+            ]),
+            color: color,
+            draw_always: false,
+        });
+        let distance0 = renderer::utils::polygon_avg_z(&translated_points, 6, 1, 8);
+        polygons.push(Polygon {
+            distance_from_camera: distance0,
+            shape: renderer::polygon::Shape::Triangle([
+                screen_points[6],
+                screen_points[1],
+                screen_points[8],
+            ]),
+            color: color,
+            draw_always: false,
+        });
+    }
 }
 
 impl Finish {
@@ -84,41 +378,28 @@ impl Finish {
             id: 0,
             radius: Fixed::const_new(0),
             depth: Fixed::const_new(0),
+            collidable: true,
         }
     }
 
+    pub fn is_collidable(&self) -> bool {
+        self.collidable
+    }
+
+    //center of the portal, x and z
+    pub fn position(&self) -> (Fixed, Fixed) {
+        return (self.x, self.z);
+    }
+
     fn finish_bounding_box(&self) -> BoundingBox {
-        let points: [[Fixed; 2]; 4] = [
-            [
-                self.model_rotated_points[1][0] + self.x,
-                self.model_rotated_points[1][2] + self.z,
-            ],
-            [
-                self.model_rotated_points[4][0] + self.x,
-                self.model_rotated_points[4][2] + self.z,
-            ],
-            [
-                self.model_rotated_points[11][0] + self.x,
-                self.model_rotated_points[11][2] + self.z,
-            ],
-            [
-                self.model_rotated_points[8][0] + self.x,
-                self.model_rotated_points[8][2] + self.z,
-            ],
-        ];
-        BoundingBox {
-            data: points,
-            center: utils::calculate_center(&points),
-            width: (self.model_rotated_points[1][0] + self.x
-                - (self.model_rotated_points[4][0] + self.x))
-                .abs(),
-            height: (self.model_rotated_points[2][2] + self.z
-                - (self.model_rotated_points[5][2] + self.z))
-                .abs(),
-            y_top: self.radius + self.y,
-            y_bottom: -self.radius + self.y,
-            rotation: self.y_rotation,
-        }
+        portal_bounding_box(
+            &self.model_rotated_points,
+            self.x,
+            self.y,
+            self.z,
+            self.radius,
+            self.y_rotation,
+        )
     }
 }
 
@@ -141,30 +422,8 @@ impl Entity for Finish {
 
     fn recalculate_points(&mut self) {
         self.depth = Fixed::from_raw(32);
-        //front face
-        self.points[0] = [Fixed::const_new(0), Fixed::const_new(0), self.depth / 2];
-
         self.radius = Fixed::const_new(2);
-
-        for i in 1..7 {
-            let angle = Fixed::from_raw(43) * i; // Angle in radians (i * 60 degrees)
-            self.points[i] = [
-                self.radius * angle.cos(),
-                self.radius * angle.sin(),
-                self.depth / 2,
-            ];
-        }
-        //back face
-        self.points[7] = [Fixed::const_new(0), Fixed::const_new(0), -self.depth / 2];
-
-        for i in 8..14 {
-            let angle = Fixed::from_raw(43) * (i - 7); // Angle in radians (i * 60 degrees)
-            self.points[i] = [
-                self.radius * angle.cos(),
-                self.radius * angle.sin(),
-                -self.depth / 2,
-            ];
-        }
+        self.points = portal_points(self.radius, self.depth);
     }
 
     fn set_x_rotation(&mut self, x_rotation: Fixed) {
@@ -214,219 +473,15 @@ impl Entity for Finish {
             return;
         }
 
-        let mut screen_points: [[Fixed; 2]; 14] = [[Fixed::const_new(0), Fixed::const_new(0)]; 14];
-        let mut translated_points: [[Fixed; 3]; 14] = [[
-            Fixed::const_new(0),
-            Fixed::const_new(0),
-            Fixed::const_new(0),
-        ]; 14];
-
-        for i in 0..(self.model_rotated_points).len() {
-            (translated_points[i], screen_points[i]) = renderer::render::translate_point(
-                &self.model_rotated_points[i],
-                &camera,
-                self.x,
-                self.y,
-                self.z,
-            );
-        }
-
-        let visible: bool = back_face_culling(&translated_points, 0, 1, 2);
-        if visible {
-            let color: u16 = renderer::utils::get_color(self.color, 0);
-            let distance0 = renderer::utils::polygon_avg_z(&translated_points, 0, 1, 2);
-            polygons.push(Polygon {
-                distance_from_camera: distance0,
-                shape: renderer::polygon::Shape::Triangle([
-                    screen_points[0],
-                    screen_points[1],
-                    screen_points[2],
-                ]),
-                color: color,
-                draw_always: false,
-            });
-            let distance0 = renderer::utils::polygon_avg_z(&translated_points, 0, 2, 3);
-            polygons.push(Polygon {
-                distance_from_camera: distance0,
-                shape: renderer::polygon::Shape::Triangle([
-                    screen_points[0],
-                    screen_points[2],
-                    screen_points[3],
-                ]),
-                color: color,
-                draw_always: false,
-            });
-            let distance0 = renderer::utils::polygon_avg_z(&translated_points, 0, 3, 4);
-            polygons.push(Polygon {
-                distance_from_camera: distance0,
-                shape: renderer::polygon::Shape::Triangle([
-                    screen_points[0],
-                    screen_points[3],
-                    screen_points[4],
-                ]),
-                color: color,
-                draw_always: false,
-            });
-            let distance0 = renderer::utils::polygon_avg_z(&translated_points, 0, 4, 5);
-            polygons.push(Polygon {
-                distance_from_camera: distance0,
-                shape: renderer::polygon::Shape::Triangle([
-                    screen_points[0],
-                    screen_points[4],
-                    screen_points[5],
-                ]),
-                color: color,
-                draw_always: false,
-            });
-            let distance0 = renderer::utils::polygon_avg_z(&translated_points, 0, 5, 6);
-            polygons.push(Polygon {
-                distance_from_camera: distance0,
-                shape: renderer::polygon::Shape::Triangle([
-                    screen_points[0],
-                    screen_points[5],
-                    screen_points[6],
-                ]),
-                color: color,
-                draw_always: false,
-            });
-            let distance0 = renderer::utils::polygon_avg_z(&translated_points, 0, 6, 1);
-            polygons.push(Polygon {
-                distance_from_camera: distance0,
-                shape: renderer::polygon::Shape::Triangle([
-                    screen_points[0],
-                    screen_points[6],
-                    screen_points[1],
-                ]),
-                color: color,
-                draw_always: false,
-            });
-        }
-        let visible: bool = back_face_culling(&translated_points, 7, 9, 8);
-        if visible {
-            let color: u16 = renderer::utils::get_color(self.color, 0);
-            let distance0 = renderer::utils::polygon_avg_z(&translated_points, 7, 8, 9);
-            polygons.push(Polygon {
-                distance_from_camera: distance0,
-                shape: renderer::polygon::Shape::Triangle([
-                    screen_points[7],
-                    screen_points[8],
-                    screen_points[9],
-                ]),
-                color: color,
-                draw_always: false,
-            });
-            let distance0 = renderer::utils::polygon_avg_z(&translated_points, 7, 9, 10);
-            polygons.push(Polygon {
-                distance_from_camera: distance0,
-                shape: renderer::polygon::Shape::Triangle([
-                    screen_points[7],
-                    screen_points[9],
-                    screen_points[10],
-                ]),
-                color: color,
-                draw_always: false,
-            });
-            let distance0 = renderer::utils::polygon_avg_z(&translated_points, 7, 10, 11);
-            polygons.push(Polygon {
-                distance_from_camera: distance0,
-                shape: renderer::polygon::Shape::Triangle([
-                    screen_points[7],
-                    screen_points[10],
-                    screen_points[11],
-                ]),
-                color: color,
-                draw_always: false,
-            });
-            let distance0 = renderer::utils::polygon_avg_z(&translated_points, 7, 11, 12);
-            polygons.push(Polygon {
-                distance_from_camera: distance0,
-                shape: renderer::polygon::Shape::Triangle([
-                    screen_points[7],
-                    screen_points[11],
-                    screen_points[12],
-                ]),
-                color: color,
-                draw_always: false,
-            });
-
-            let distance0 = renderer::utils::polygon_avg_z(&translated_points, 7, 12, 13);
-            polygons.push(Polygon {
-                distance_from_camera: distance0,
-                shape: renderer::polygon::Shape::Triangle([
-                    screen_points[7],
-                    screen_points[12],
-                    screen_points[13],
-                ]),
-                color: color,
-                draw_always: false,
-            });
-            let distance0 = renderer::utils::polygon_avg_z(&translated_points, 7, 13, 8);
-            polygons.push(Polygon {
-                distance_from_camera: distance0,
-                shape: renderer::polygon::Shape::Triangle([
-                    screen_points[7],
-                    screen_points[13],
-                    screen_points[8],
-                ]),
-                color: color,
-                draw_always: false,
-            });
-        }
-
-        for i in 1..6 {
-            let visible: bool = back_face_culling(&translated_points, i, i + 8, i + 1);
-            if visible {
-                let color: u16 = renderer::utils::get_color(self.color, (i % 3 + 1) as i16);
-                let distance0 = renderer::utils::polygon_avg_z(&translated_points, i, i + 8, i + 1);
-                polygons.push(Polygon {
-                    distance_from_camera: distance0,
-                    shape: renderer::polygon::Shape::Triangle([
-                        screen_points[i],
-                        screen_points[i + 8],
-                        screen_points[i + 1],
-                    ]),
-                    color: color,
-                    draw_always: false,
-                });
-                let distance0 = renderer::utils::polygon_avg_z(&translated_points, i, i + 7, i + 8);
-                polygons.push(Polygon {
-                    distance_from_camera: distance0,
-                    shape: renderer::polygon::Shape::Triangle([
-                        screen_points[i],
-                        screen_points[i + 8],
-                        screen_points[i + 7],
-                    ]),
-                    color: color,
-                    draw_always: false,
-                });
-            }
-        }
-        let visible: bool = back_face_culling(&translated_points, 6, 13, 8);
-        if visible {
-            let color: u16 = renderer::utils::get_color(self.color, 1);
-            let distance0 = renderer::utils::polygon_avg_z(&translated_points, 6, 13, 8);
-            polygons.push(Polygon {
-                distance_from_camera: distance0,
-                shape: renderer::polygon::Shape::Triangle([
-                    screen_points[6],
-                    screen_points[13],
-                    screen_points[8],
-                ]),
-                color: color,
-                draw_always: false,
-            });
-            let distance0 = renderer::utils::polygon_avg_z(&translated_points, 6, 1, 8);
-            polygons.push(Polygon {
-                distance_from_camera: distance0,
-                shape: renderer::polygon::Shape::Triangle([
-                    screen_points[6],
-                    screen_points[1],
-                    screen_points[8],
-                ]),
-                color: color,
-                draw_always: false,
-            });
-        }
+        render_portal(
+            &self.model_rotated_points,
+            self.x,
+            self.y,
+            self.z,
+            camera,
+            self.color,
+            polygons,
+        );
     }
 
     fn distance_from_camera(&self, camera: &Camera) -> Fixed {
@@ -452,15 +507,13 @@ impl Entity for Finish {
     fn set_color(&mut self, color: u16) {
         self.color = color;
     }
+    fn get_color(&self) -> u16 {
+        return self.color;
+    }
 
     fn tick(&mut self, effects: &effects::InputGameState) -> Option<effects::OutputEvents> {
         let hitbox = self.finish_bounding_box();
-        if (effects.bounding_box.y_top > hitbox.y_bottom
-            && effects.bounding_box.y_bottom < hitbox.y_top)
-            && rect_simple_overlap_check(effects.bounding_box, &hitbox)
-            && (rect_overlap(&hitbox, effects.bounding_box)
-                || cylinder_and_rotated_rect_collision(effects.bounding_cylinder, &hitbox).1)
-        {
+        if volume_overlap_check(&hitbox, effects.bounding_box, effects.bounding_cylinder) {
             return Some(effects::OutputEvents::GameFinish(effects::Finished {
                 //finished: true,
             }));
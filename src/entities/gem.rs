@@ -0,0 +1,206 @@
+//a spinning gem the player collects by touching it, the player passes through it
+//scripts/bundle_levels.py numbers the gems of a level in "gem", which the save keeps as one bit each
+//size is the height of the gem, it is half as wide
+
+use agb::InternalAllocator;
+use alloc::vec::Vec;
+use serde::Deserialize;
+
+use super::boundingshapes::BoundingShape;
+use super::math;
+use super::BoundingCylinder;
+use super::Camera;
+use super::Entity;
+use crate::effects;
+use crate::renderer;
+use crate::renderer::polygon::Polygon;
+use math::*;
+
+use crate::fixed;
+use fixed::*;
+
+const GEM_COLOR: u16 = 4;
+//turn per frame
+const SPIN_SPEED: Fixed = Fixed::from_raw(3);
+
+//the octahedron of the gem mesh in meshes/library.json, top, the four corners and bottom
+pub const TRIANGLES: [[u8; 3]; 8] = [
+    [1, 0, 2],
+    [2, 0, 3],
+    [3, 0, 4],
+    [4, 0, 1],
+    [2, 5, 1],
+    [3, 5, 2],
+    [4, 5, 3],
+    [1, 5, 4],
+];
+pub const SHADES: [u8; 8] = [0, 1, 0, 1, 2, 3, 2, 3];
+
+//the points of a gem turned by spin around the y-axis, also used by the world map
+pub fn model_points(size: Fixed, spin: Fixed) -> [[Fixed; 3]; 6] {
+    let mut points = [[Fixed::const_new(0); 3]; 6];
+    let radius = size / 4;
+    points[0] = [Fixed::const_new(0), size / 2, Fixed::const_new(0)];
+    for i in 0..4 {
+        let angle = spin + Fixed::from_raw(64 * i as i32);
+        points[i + 1] = [radius * angle.cos(), Fixed::const_new(0), radius * angle.sin()];
+    }
+    points[5] = [Fixed::const_new(0), -size / 2, Fixed::const_new(0)];
+    return points;
+}
+
+fn default_size() -> Fixed {
+    Fixed::from_raw(160)
+}
+
+fn default_color() -> u16 {
+    GEM_COLOR
+}
+
+#[derive(Copy, Clone, Deserialize, Debug)]
+pub struct Gem {
+    #[serde(default = "default_i16")]
+    id: i16,
+    #[serde(default = "default_fixed")]
+    x: Fixed,
+    #[serde(default = "default_fixed")]
+    y: Fixed,
+    #[serde(default = "default_fixed")]
+    z: Fixed,
+
+    #[serde(default = "default_size")]
+    size: Fixed,
+    #[serde(rename = "rotation", default = "default_fixed")]
+    y_rotation: Fixed,
+
+    #[serde(default = "default_fixed_3_6")]
+    model_rotated_points: [[Fixed; 3]; 6],
+
+    #[serde(default = "default_color")]
+    color: u16,
+
+    //bit in the level's collected gems, see save::LevelProgress
+    #[serde(default = "default_u16")]
+    gem: u16,
+    #[serde(default = "default_bool")]
+    collected: bool,
+}
+
+impl Entity for Gem {
+    fn set_x_offset(&mut self, x_offset: Fixed) {
+        self.x = x_offset;
+    }
+
+    fn set_y_offset(&mut self, y_offset: Fixed) {
+        self.y = y_offset;
+    }
+
+    fn set_z_offset(&mut self, z_offset: Fixed) {
+        self.z = z_offset;
+    }
+
+    fn set_size(&mut self, size: Fixed) {
+        self.size = size;
+    }
+
+    //the points only depend on the size and the spin, so they are all made in refresh_model_matrix
+    fn recalculate_points(&mut self) {}
+
+    fn set_x_rotation(&mut self, _x_rotation: Fixed) {}
+
+    fn set_y_rotation(&mut self, y_rotation: Fixed) {
+        self.y_rotation = y_rotation;
+    }
+
+    fn set_z_rotation(&mut self, _z_rotation: Fixed) {}
+
+    fn reload_rotation_matrices(&mut self) {}
+
+    fn refresh_model_matrix(&mut self) {
+        self.model_rotated_points = model_points(self.size, self.y_rotation);
+    }
+
+    fn set_vertex(&mut self, _point: [Fixed; 3], _index: i32) {
+        //not implemented
+    }
+
+    fn render(&mut self, camera: &Camera, polygons: &mut Vec<Polygon, InternalAllocator>, render_distance: Fixed) {
+        if self.collected || self.distance_from_camera(camera) > render_distance {
+            return;
+        }
+
+        renderer::render::render_triangle_list(
+            &self.model_rotated_points,
+            6,
+            &TRIANGLES,
+            &SHADES,
+            self.x,
+            self.y,
+            self.z,
+            camera,
+            self.color,
+            polygons,
+            false,
+        );
+    }
+
+    fn distance_from_camera(&self, camera: &Camera) -> Fixed {
+        return (self.x - camera.x).abs() + (self.y - camera.y).abs() + (self.z - camera.z).abs();
+    }
+
+    fn bounding_shape(&self) -> Option<BoundingShape> {
+        None
+    }
+
+    fn bounding_cylinder(&self) -> BoundingCylinder {
+        BoundingCylinder {
+            x: self.x,
+            z: self.z,
+            radius: self.size / 4,
+            y_top: self.y + self.size / 2,
+            y_bottom: self.y - self.size / 2,
+        }
+    }
+
+    fn get_y(&self) -> Fixed {
+        return self.y;
+    }
+    fn get_height(&self) -> Fixed {
+        return self.size;
+    }
+
+    fn set_color(&mut self, color: u16) {
+        self.color = color;
+    }
+    fn get_color(&self) -> u16 {
+        return self.color;
+    }
+
+    fn tick(&mut self, effects: &effects::InputGameState) -> Option<effects::OutputEvents> {
+        if self.collected {
+            return None;
+        }
+        self.y_rotation = (self.y_rotation + SPIN_SPEED).modulo(Fixed::const_new(1));
+        self.refresh_model_matrix();
+
+        let gem = self.bounding_cylinder();
+        let player = effects.bounding_cylinder;
+        if player.y_bottom > gem.y_top || player.y_top < gem.y_bottom {
+            return None;
+        }
+        let offset = vector_sub_2d([player.x, player.z], [gem.x, gem.z]);
+        if math::vector_len_2d(offset) < player.radius + gem.radius {
+            self.collected = true;
+            return Some(effects::OutputEvents::Collect(effects::Collect { index: self.gem }));
+        }
+        None
+    }
+
+    fn get_id(&self) -> i16 {
+        return self.id;
+    }
+
+    fn set_id(&mut self, id: i16) {
+        self.id = id
+    }
+}
@@ -63,6 +63,9 @@ pub struct Ice {
 
     #[serde(default = "default_fixed")]
     acceleration: Fixed,
+
+    #[serde(default = "default_true")]
+    collidable: bool,
 }
 
 impl Ice {
@@ -86,8 +89,13 @@ impl Ice {
             z_rotation_matrix: [[Fixed::const_new(0); 3]; 3],
             color: 0,
             acceleration: Fixed::const_new(0),
+            collidable: true,
         }
     }
+
+    pub fn is_collidable(&self) -> bool {
+        self.collidable
+    }
 }
 
 impl Entity for Ice {
@@ -229,6 +237,9 @@ impl Entity for Ice {
     fn set_color(&mut self, color: u16) {
         self.color = color;
     }
+    fn get_color(&self) -> u16 {
+        return self.color;
+    }
     fn tick(&mut self, effects: &effects::InputGameState) -> Option<effects::OutputEvents> {
         if effects.support_below_id == self.id {
             return Some(effects::OutputEvents::Sliding(effects::Sliding {acceleration: self.acceleration}));
@@ -0,0 +1,342 @@
+//an entity with its shape given in the level data, as a list of vertices and triangles indexing them
+//library meshes from meshes/library.json are copied into the level by bundle_levels.py
+
+use agb::InternalAllocator;
+use alloc::vec::Vec;
+use serde::Deserialize;
+
+use super::boundingshapes::BoundingShape;
+use super::math;
+use super::BoundingBox;
+use super::BoundingCylinder;
+use super::Camera;
+use super::Entity;
+use crate::effects;
+use crate::renderer;
+use crate::renderer::polygon::Polygon;
+use crate::utils::FixedList;
+use math::*;
+
+use crate::fixed;
+use crate::utils;
+use fixed::*;
+
+pub const MESH_VERTICES: usize = 16;
+pub const MESH_TRIANGLES: usize = 24;
+
+#[derive(Copy, Clone, Deserialize, Debug, PartialEq)]
+pub enum Collider {
+    #[serde(rename = "none")]
+    None,
+    //fits the vertices, turns with the mesh
+    #[serde(rename = "box")]
+    Box,
+    //upright, reaches the vertex furthest from the center
+    #[serde(rename = "cylinder")]
+    Cylinder,
+}
+
+fn default_collider() -> Collider {
+    Collider::None
+}
+
+fn default_scale() -> Fixed {
+    Fixed::const_new(1)
+}
+
+#[derive(Copy, Clone, Deserialize, Debug)]
+pub struct Mesh {
+    #[serde(default = "default_i16")]
+    id: i16,
+    #[serde(default = "default_fixed")]
+    x: Fixed,
+    #[serde(default = "default_fixed")]
+    y: Fixed,
+    #[serde(default = "default_fixed")]
+    z: Fixed,
+
+    #[serde(default = "default_scale")]
+    scale: Fixed,
+
+    #[serde(default = "default_fixed")]
+    x_rotation: Fixed,
+    #[serde(rename = "rotation", default = "default_fixed")]
+    y_rotation: Fixed,
+    #[serde(default = "default_fixed")]
+    z_rotation: Fixed,
+
+    #[serde(default)]
+    vertices: FixedList<[Fixed; 3], MESH_VERTICES>,
+    #[serde(default)]
+    triangles: FixedList<[u8; 3], MESH_TRIANGLES>,
+    //one per triangle, 0 is the lightest and 3 the darkest
+    #[serde(default)]
+    shades: FixedList<u8, MESH_TRIANGLES>,
+
+    #[serde(default = "default_fixed_3_16")]
+    model_rotated_points: [[Fixed; 3]; 16],
+
+    #[serde(default = "default_fixed_3_3")]
+    x_rotation_matrix: [[Fixed; 3]; 3],
+    #[serde(default = "default_fixed_3_3")]
+    y_rotation_matrix: [[Fixed; 3]; 3],
+    #[serde(default = "default_fixed_3_3")]
+    z_rotation_matrix: [[Fixed; 3]; 3],
+
+    #[serde(default = "default_u16")]
+    color: u16,
+
+    #[serde(default = "default_collider")]
+    collider: Collider,
+    //smallest and largest corner of the box around the scaled vertices
+    #[serde(default)]
+    bounds: [[Fixed; 3]; 2],
+    //corners of that box, in the same order as rectangle_model_points
+    #[serde(default = "default_fixed_3_8")]
+    collider_rotated_points: [[Fixed; 3]; 8],
+
+    #[serde(default = "default_true")]
+    collidable: bool,
+}
+
+impl Mesh {
+    #[allow(dead_code)]
+    pub fn default() -> Self {
+        Self {
+            id: 0,
+            x: Fixed::const_new(0),
+            y: Fixed::const_new(0),
+            z: Fixed::const_new(0),
+            scale: Fixed::const_new(1),
+            x_rotation: Fixed::const_new(0),
+            y_rotation: Fixed::const_new(0),
+            z_rotation: Fixed::const_new(0),
+            vertices: FixedList::default(),
+            triangles: FixedList::default(),
+            shades: FixedList::default(),
+            model_rotated_points: [[Fixed::const_new(0); 3]; 16],
+            x_rotation_matrix: [[Fixed::const_new(0); 3]; 3],
+            y_rotation_matrix: [[Fixed::const_new(0); 3]; 3],
+            z_rotation_matrix: [[Fixed::const_new(0); 3]; 3],
+            color: 0,
+            collider: Collider::None,
+            bounds: [[Fixed::const_new(0); 3]; 2],
+            collider_rotated_points: [[Fixed::const_new(0); 3]; 8],
+            collidable: true,
+        }
+    }
+
+    pub fn is_collidable(&self) -> bool {
+        self.collidable
+    }
+
+    fn rotate_point(&self, point: [Fixed; 3]) -> [Fixed; 3] {
+        let mut rotated_point: [Fixed; 3] = matmul(self.x_rotation_matrix, point);
+        rotated_point = matmul(self.y_rotation_matrix, rotated_point);
+        matmul(self.z_rotation_matrix, rotated_point)
+    }
+
+    fn scaled_vertex(&self, index: usize) -> [Fixed; 3] {
+        vector_mul(self.vertices.items[index], self.scale)
+    }
+
+    fn collider_corners(&self) -> [[Fixed; 3]; 8] {
+        let [min, max] = self.bounds;
+        [
+            [max[0], max[1], max[2]],
+            [min[0], max[1], max[2]],
+            [min[0], min[1], max[2]],
+            [max[0], min[1], max[2]],
+            [max[0], max[1], min[2]],
+            [min[0], max[1], min[2]],
+            [min[0], min[1], min[2]],
+            [max[0], min[1], min[2]],
+        ]
+    }
+
+    fn collider_box(&self) -> BoundingBox {
+        let corners = &self.collider_rotated_points;
+        let points: [[Fixed; 2]; 4] = [
+            [corners[0][0] + self.x, corners[0][2] + self.z],
+            [corners[1][0] + self.x, corners[1][2] + self.z],
+            [corners[5][0] + self.x, corners[5][2] + self.z],
+            [corners[4][0] + self.x, corners[4][2] + self.z],
+        ];
+
+        BoundingBox {
+            data: points,
+            center: utils::calculate_center(&points),
+            width: self.bounds[1][0] - self.bounds[0][0],
+            height: self.bounds[1][2] - self.bounds[0][2],
+            y_top: corners[0][1] + self.y,
+            y_bottom: corners[2][1] + self.y,
+            rotation: -self.y_rotation,
+        }
+    }
+
+    fn collider_cylinder(&self) -> BoundingCylinder {
+        let mut radius = Fixed::const_new(0);
+        for point in self.model_rotated_points[..self.vertices.len].iter() {
+            let distance = vector_len_2d([point[0], point[2]]);
+            if distance > radius {
+                radius = distance;
+            }
+        }
+        BoundingCylinder {
+            x: self.x,
+            z: self.z,
+            radius,
+            y_top: self.collider_rotated_points[0][1] + self.y,
+            y_bottom: self.collider_rotated_points[2][1] + self.y,
+        }
+    }
+}
+
+impl Entity for Mesh {
+    fn set_x_offset(&mut self, x_offset: Fixed) {
+        self.x = x_offset;
+    }
+
+    fn set_y_offset(&mut self, y_offset: Fixed) {
+        self.y = y_offset;
+    }
+
+    fn set_z_offset(&mut self, z_offset: Fixed) {
+        self.z = z_offset;
+    }
+
+    fn set_size(&mut self, size: Fixed) {
+        self.scale = size;
+    }
+
+    fn recalculate_points(&mut self) {
+        self.bounds = [[Fixed::const_new(0); 3]; 2];
+        if self.vertices.len == 0 {
+            return;
+        }
+        let first = self.scaled_vertex(0);
+        self.bounds = [first, first];
+        for i in 1..self.vertices.len {
+            let point = self.scaled_vertex(i);
+            for axis in 0..3 {
+                if point[axis] < self.bounds[0][axis] {
+                    self.bounds[0][axis] = point[axis];
+                }
+                if point[axis] > self.bounds[1][axis] {
+                    self.bounds[1][axis] = point[axis];
+                }
+            }
+        }
+    }
+
+    fn set_x_rotation(&mut self, x_rotation: Fixed) {
+        self.x_rotation = x_rotation;
+        self.x_rotation_matrix = utils::x_rotation_matrix(x_rotation);
+    }
+
+    fn set_y_rotation(&mut self, y_rotation: Fixed) {
+        self.y_rotation = y_rotation;
+        self.y_rotation_matrix = utils::y_rotation_matrix(y_rotation);
+    }
+
+    fn set_z_rotation(&mut self, z_rotation: Fixed) {
+        self.z_rotation = z_rotation;
+        self.z_rotation_matrix = utils::z_rotation_matrix(z_rotation);
+    }
+
+    fn reload_rotation_matrices(&mut self) {
+        self.set_x_rotation(self.x_rotation);
+        self.set_y_rotation(self.y_rotation);
+        self.set_z_rotation(self.z_rotation);
+    }
+
+    fn refresh_model_matrix(&mut self) {
+        for i in 0..self.vertices.len {
+            self.model_rotated_points[i] = self.rotate_point(self.scaled_vertex(i));
+        }
+        let corners = self.collider_corners();
+        for i in 0..corners.len() {
+            self.collider_rotated_points[i] = self.rotate_point(corners[i]);
+        }
+    }
+
+    fn set_vertex(&mut self, point: [Fixed; 3], index: i32) {
+        if index < 0 || index as usize >= self.vertices.len {
+            return;
+        }
+        self.vertices.items[index as usize] = point;
+        self.recalculate_points();
+        self.refresh_model_matrix();
+    }
+
+    fn render(
+        &mut self,
+        camera: &Camera,
+        polygons: &mut Vec<Polygon, InternalAllocator>,
+        render_distance: Fixed,
+    ) {
+        if self.distance_from_camera(camera) > render_distance {
+            return;
+        }
+
+        renderer::render::render_triangle_list(
+            &self.model_rotated_points,
+            self.vertices.len,
+            self.triangles.as_slice(),
+            self.shades.as_slice(),
+            self.x,
+            self.y,
+            self.z,
+            camera,
+            self.color,
+            polygons,
+            false,
+        );
+    }
+
+    fn distance_from_camera(&self, camera: &Camera) -> Fixed {
+        return (self.x - camera.x).abs() + (self.y - camera.y).abs() + (self.z - camera.z).abs();
+    }
+
+    fn bounding_shape(&self) -> Option<BoundingShape> {
+        match self.collider {
+            Collider::None => None,
+            Collider::Box => Some(BoundingShape::BoundingBox(self.collider_box())),
+            Collider::Cylinder => Some(BoundingShape::BoundingCylinder(self.collider_cylinder())),
+        }
+    }
+
+    fn bounding_cylinder(&self) -> BoundingCylinder {
+        if self.collider == Collider::None {
+            BoundingCylinder::empty()
+        } else {
+            self.collider_cylinder()
+        }
+    }
+
+    fn get_y(&self) -> Fixed {
+        return self.y;
+    }
+    fn get_height(&self) -> Fixed {
+        return self.bounds[1][1] - self.bounds[0][1];
+    }
+
+    fn set_color(&mut self, color: u16) {
+        self.color = color;
+    }
+    fn get_color(&self) -> u16 {
+        return self.color;
+    }
+
+    fn tick(&mut self, _effects: &effects::InputGameState) -> Option<effects::OutputEvents> {
+        None
+    }
+
+    fn get_id(&self) -> i16 {
+        return self.id;
+    }
+
+    fn set_id(&mut self, id: i16) {
+        self.id = id
+    }
+}
@@ -36,11 +36,40 @@ use bounce::*;
 pub mod ice;
 use ice::*;
 
+pub mod conveyor;
+use conveyor::*;
+
+pub mod wind;
+use wind::*;
+
+pub mod teleporter;
+use teleporter::*;
+
+pub mod decoration;
+use decoration::*;
+
+pub mod mesh;
+use mesh::*;
+
+pub mod enemy;
+use enemy::*;
+
+pub mod pushable;
+use pushable::*;
+
+pub mod sign;
+use sign::*;
+
+pub mod gem;
+use gem::*;
+
 pub mod boundingshapes;
 use boundingshapes::*;
 
 pub mod utils;
 
+pub mod pool;
+
 use serde::Deserialize;
 
 use super::math;
@@ -76,6 +105,24 @@ pub enum EntityEnum {
     Bounce(Bounce),
     #[serde(rename = "ice")]
     Ice(Ice),
+    #[serde(rename = "conveyor")]
+    Conveyor(Conveyor),
+    #[serde(rename = "wind")]
+    Wind(Wind),
+    #[serde(rename = "teleporter")]
+    Teleporter(Teleporter),
+    #[serde(rename = "decoration")]
+    Decoration(Decoration),
+    #[serde(rename = "mesh")]
+    Mesh(Mesh),
+    #[serde(rename = "enemy")]
+    Enemy(Enemy),
+    #[serde(rename = "pushable")]
+    Pushable(Pushable),
+    #[serde(rename = "sign")]
+    Sign(Sign),
+    #[serde(rename = "gem")]
+    Gem(Gem),
     #[serde(rename = "empty")]
     Empty(Empty),
 }
@@ -93,6 +140,15 @@ impl EntityEnum {
             EntityEnum::Body(a) => a.set_x_offset(offset),
             EntityEnum::Bounce(a) => a.set_x_offset(offset),
             EntityEnum::Ice(a) => a.set_x_offset(offset),
+            EntityEnum::Conveyor(a) => a.set_x_offset(offset),
+            EntityEnum::Wind(a) => a.set_x_offset(offset),
+            EntityEnum::Teleporter(a) => a.set_x_offset(offset),
+            EntityEnum::Decoration(a) => a.set_x_offset(offset),
+            EntityEnum::Mesh(a) => a.set_x_offset(offset),
+            EntityEnum::Enemy(a) => a.set_x_offset(offset),
+            EntityEnum::Pushable(a) => a.set_x_offset(offset),
+            EntityEnum::Sign(a) => a.set_x_offset(offset),
+            EntityEnum::Gem(a) => a.set_x_offset(offset),
             EntityEnum::Empty(_a) => {}
         }
     }
@@ -108,6 +164,15 @@ impl EntityEnum {
             EntityEnum::Body(a) => a.set_y_offset(offset),
             EntityEnum::Bounce(a) => a.set_y_offset(offset),
             EntityEnum::Ice(a) => a.set_y_offset(offset),
+            EntityEnum::Conveyor(a) => a.set_y_offset(offset),
+            EntityEnum::Wind(a) => a.set_y_offset(offset),
+            EntityEnum::Teleporter(a) => a.set_y_offset(offset),
+            EntityEnum::Decoration(a) => a.set_y_offset(offset),
+            EntityEnum::Mesh(a) => a.set_y_offset(offset),
+            EntityEnum::Enemy(a) => a.set_y_offset(offset),
+            EntityEnum::Pushable(a) => a.set_y_offset(offset),
+            EntityEnum::Sign(a) => a.set_y_offset(offset),
+            EntityEnum::Gem(a) => a.set_y_offset(offset),
             EntityEnum::Empty(_a) => {}
         }
     }
@@ -123,6 +188,15 @@ impl EntityEnum {
             EntityEnum::Body(a) => a.set_z_offset(offset),
             EntityEnum::Bounce(a) => a.set_z_offset(offset),
             EntityEnum::Ice(a) => a.set_z_offset(offset),
+            EntityEnum::Conveyor(a) => a.set_z_offset(offset),
+            EntityEnum::Wind(a) => a.set_z_offset(offset),
+            EntityEnum::Teleporter(a) => a.set_z_offset(offset),
+            EntityEnum::Decoration(a) => a.set_z_offset(offset),
+            EntityEnum::Mesh(a) => a.set_z_offset(offset),
+            EntityEnum::Enemy(a) => a.set_z_offset(offset),
+            EntityEnum::Pushable(a) => a.set_z_offset(offset),
+            EntityEnum::Sign(a) => a.set_z_offset(offset),
+            EntityEnum::Gem(a) => a.set_z_offset(offset),
             EntityEnum::Empty(_a) => {}
         }
     }
@@ -138,6 +212,15 @@ impl EntityEnum {
             EntityEnum::Body(a) => a.set_x_rotation(rot),
             EntityEnum::Bounce(a) => a.set_x_rotation(rot),
             EntityEnum::Ice(a) => a.set_x_rotation(rot),
+            EntityEnum::Conveyor(a) => a.set_x_rotation(rot),
+            EntityEnum::Wind(a) => a.set_x_rotation(rot),
+            EntityEnum::Teleporter(a) => a.set_x_rotation(rot),
+            EntityEnum::Decoration(a) => a.set_x_rotation(rot),
+            EntityEnum::Mesh(a) => a.set_x_rotation(rot),
+            EntityEnum::Enemy(a) => a.set_x_rotation(rot),
+            EntityEnum::Pushable(a) => a.set_x_rotation(rot),
+            EntityEnum::Sign(a) => a.set_x_rotation(rot),
+            EntityEnum::Gem(a) => a.set_x_rotation(rot),
             EntityEnum::Empty(_a) => {}
         }
     }
@@ -153,6 +236,15 @@ impl EntityEnum {
             EntityEnum::Body(a) => a.set_y_rotation(rot),
             EntityEnum::Bounce(a) => a.set_y_rotation(rot),
             EntityEnum::Ice(a) => a.set_y_rotation(rot),
+            EntityEnum::Conveyor(a) => a.set_y_rotation(rot),
+            EntityEnum::Wind(a) => a.set_y_rotation(rot),
+            EntityEnum::Teleporter(a) => a.set_y_rotation(rot),
+            EntityEnum::Decoration(a) => a.set_y_rotation(rot),
+            EntityEnum::Mesh(a) => a.set_y_rotation(rot),
+            EntityEnum::Enemy(a) => a.set_y_rotation(rot),
+            EntityEnum::Pushable(a) => a.set_y_rotation(rot),
+            EntityEnum::Sign(a) => a.set_y_rotation(rot),
+            EntityEnum::Gem(a) => a.set_y_rotation(rot),
             EntityEnum::Empty(_a) => {}
         }
     }
@@ -168,6 +260,15 @@ impl EntityEnum {
             EntityEnum::Body(a) => a.set_z_rotation(rot),
             EntityEnum::Bounce(a) => a.set_z_rotation(rot),
             EntityEnum::Ice(a) => a.set_z_rotation(rot),
+            EntityEnum::Conveyor(a) => a.set_z_rotation(rot),
+            EntityEnum::Wind(a) => a.set_z_rotation(rot),
+            EntityEnum::Teleporter(a) => a.set_z_rotation(rot),
+            EntityEnum::Decoration(a) => a.set_z_rotation(rot),
+            EntityEnum::Mesh(a) => a.set_z_rotation(rot),
+            EntityEnum::Enemy(a) => a.set_z_rotation(rot),
+            EntityEnum::Pushable(a) => a.set_z_rotation(rot),
+            EntityEnum::Sign(a) => a.set_z_rotation(rot),
+            EntityEnum::Gem(a) => a.set_z_rotation(rot),
             EntityEnum::Empty(_a) => {}
         }
     }
@@ -183,6 +284,15 @@ impl EntityEnum {
             EntityEnum::Body(a) => a.reload_rotation_matrices(),
             EntityEnum::Bounce(a) => a.reload_rotation_matrices(),
             EntityEnum::Ice(a) => a.reload_rotation_matrices(),
+            EntityEnum::Conveyor(a) => a.reload_rotation_matrices(),
+            EntityEnum::Wind(a) => a.reload_rotation_matrices(),
+            EntityEnum::Teleporter(a) => a.reload_rotation_matrices(),
+            EntityEnum::Decoration(a) => a.reload_rotation_matrices(),
+            EntityEnum::Mesh(a) => a.reload_rotation_matrices(),
+            EntityEnum::Enemy(a) => a.reload_rotation_matrices(),
+            EntityEnum::Pushable(a) => a.reload_rotation_matrices(),
+            EntityEnum::Sign(a) => a.reload_rotation_matrices(),
+            EntityEnum::Gem(a) => a.reload_rotation_matrices(),
             EntityEnum::Empty(_a) => {}
         }
     }
@@ -198,6 +308,15 @@ impl EntityEnum {
             EntityEnum::Body(a) => a.refresh_model_matrix(),
             EntityEnum::Bounce(a) => a.refresh_model_matrix(),
             EntityEnum::Ice(a) => a.refresh_model_matrix(),
+            EntityEnum::Conveyor(a) => a.refresh_model_matrix(),
+            EntityEnum::Wind(a) => a.refresh_model_matrix(),
+            EntityEnum::Teleporter(a) => a.refresh_model_matrix(),
+            EntityEnum::Decoration(a) => a.refresh_model_matrix(),
+            EntityEnum::Mesh(a) => a.refresh_model_matrix(),
+            EntityEnum::Enemy(a) => a.refresh_model_matrix(),
+            EntityEnum::Pushable(a) => a.refresh_model_matrix(),
+            EntityEnum::Sign(a) => a.refresh_model_matrix(),
+            EntityEnum::Gem(a) => a.refresh_model_matrix(),
             EntityEnum::Empty(_a) => {}
         }
     }
@@ -215,6 +334,15 @@ impl EntityEnum {
             EntityEnum::Body(a) => a.set_size(size),
             EntityEnum::Bounce(_a) => {}
             EntityEnum::Ice(_a) => {}
+            EntityEnum::Conveyor(_a) => {}
+            EntityEnum::Wind(_a) => {}
+            EntityEnum::Teleporter(_a) => {}
+            EntityEnum::Decoration(_a) => {}
+            EntityEnum::Mesh(_a) => {}
+            EntityEnum::Enemy(_a) => {}
+            EntityEnum::Pushable(_a) => {}
+            EntityEnum::Sign(_a) => {}
+            EntityEnum::Gem(a) => a.set_size(size),
             EntityEnum::Empty(_a) => {}
         }
     }
@@ -230,6 +358,15 @@ impl EntityEnum {
             EntityEnum::Body(a) => a.recalculate_points(),
             EntityEnum::Bounce(a) => a.recalculate_points(),
             EntityEnum::Ice(a) => a.recalculate_points(),
+            EntityEnum::Conveyor(a) => a.recalculate_points(),
+            EntityEnum::Wind(a) => a.recalculate_points(),
+            EntityEnum::Teleporter(a) => a.recalculate_points(),
+            EntityEnum::Decoration(a) => a.recalculate_points(),
+            EntityEnum::Mesh(a) => a.recalculate_points(),
+            EntityEnum::Enemy(a) => a.recalculate_points(),
+            EntityEnum::Pushable(a) => a.recalculate_points(),
+            EntityEnum::Sign(a) => a.recalculate_points(),
+            EntityEnum::Gem(a) => a.recalculate_points(),
             EntityEnum::Empty(_a) => {}
         }
     }
@@ -246,6 +383,15 @@ impl EntityEnum {
             EntityEnum::Body(a) => a.set_vertex(point, index),
             EntityEnum::Bounce(a) => a.set_vertex(point, index),
             EntityEnum::Ice(a) => a.set_vertex(point, index),
+            EntityEnum::Conveyor(a) => a.set_vertex(point, index),
+            EntityEnum::Wind(a) => a.set_vertex(point, index),
+            EntityEnum::Teleporter(a) => a.set_vertex(point, index),
+            EntityEnum::Decoration(a) => a.set_vertex(point, index),
+            EntityEnum::Mesh(a) => a.set_vertex(point, index),
+            EntityEnum::Enemy(a) => a.set_vertex(point, index),
+            EntityEnum::Pushable(a) => a.set_vertex(point, index),
+            EntityEnum::Sign(a) => a.set_vertex(point, index),
+            EntityEnum::Gem(a) => a.set_vertex(point, index),
             EntityEnum::Empty(_a) => {}
         }
     }
@@ -267,6 +413,15 @@ impl EntityEnum {
             EntityEnum::Body(a) => a.render(camera, polygons, render_distance),
             EntityEnum::Bounce(a) => a.render(camera, polygons, render_distance),
             EntityEnum::Ice(a) => a.render(camera, polygons, render_distance),
+            EntityEnum::Conveyor(a) => a.render(camera, polygons, render_distance),
+            EntityEnum::Wind(a) => a.render(camera, polygons, render_distance),
+            EntityEnum::Teleporter(a) => a.render(camera, polygons, render_distance),
+            EntityEnum::Decoration(a) => a.render(camera, polygons, render_distance),
+            EntityEnum::Mesh(a) => a.render(camera, polygons, render_distance),
+            EntityEnum::Enemy(a) => a.render(camera, polygons, render_distance),
+            EntityEnum::Pushable(a) => a.render(camera, polygons, render_distance),
+            EntityEnum::Sign(a) => a.render(camera, polygons, render_distance),
+            EntityEnum::Gem(a) => a.render(camera, polygons, render_distance),
         }
     }
     #[allow(dead_code)]
@@ -282,6 +437,15 @@ impl EntityEnum {
             EntityEnum::Body(a) => a.distance_from_camera(camera),
             EntityEnum::Bounce(a) => a.distance_from_camera(camera),
             EntityEnum::Ice(a) => a.distance_from_camera(camera),
+            EntityEnum::Conveyor(a) => a.distance_from_camera(camera),
+            EntityEnum::Wind(a) => a.distance_from_camera(camera),
+            EntityEnum::Teleporter(a) => a.distance_from_camera(camera),
+            EntityEnum::Decoration(a) => a.distance_from_camera(camera),
+            EntityEnum::Mesh(a) => a.distance_from_camera(camera),
+            EntityEnum::Enemy(a) => a.distance_from_camera(camera),
+            EntityEnum::Pushable(a) => a.distance_from_camera(camera),
+            EntityEnum::Sign(a) => a.distance_from_camera(camera),
+            EntityEnum::Gem(a) => a.distance_from_camera(camera),
             EntityEnum::Empty(_a) => Fixed::const_new(999),
         }
     }
@@ -297,6 +461,15 @@ impl EntityEnum {
             EntityEnum::Body(a) => a.bounding_shape(),
             EntityEnum::Bounce(a) => a.bounding_shape(),
             EntityEnum::Ice(a) => a.bounding_shape(),
+            EntityEnum::Conveyor(a) => a.bounding_shape(),
+            EntityEnum::Wind(a) => a.bounding_shape(),
+            EntityEnum::Teleporter(a) => a.bounding_shape(),
+            EntityEnum::Decoration(a) => a.bounding_shape(),
+            EntityEnum::Mesh(a) => a.bounding_shape(),
+            EntityEnum::Enemy(a) => a.bounding_shape(),
+            EntityEnum::Pushable(a) => a.bounding_shape(),
+            EntityEnum::Sign(a) => a.bounding_shape(),
+            EntityEnum::Gem(a) => a.bounding_shape(),
             EntityEnum::Empty(_a) => None,
         }
     }
@@ -312,9 +485,43 @@ impl EntityEnum {
             EntityEnum::Body(a) => a.bounding_cylinder(),
             EntityEnum::Bounce(a) => a.bounding_cylinder(),
             EntityEnum::Ice(a) => a.bounding_cylinder(),
+            EntityEnum::Conveyor(a) => a.bounding_cylinder(),
+            EntityEnum::Wind(a) => a.bounding_cylinder(),
+            EntityEnum::Teleporter(a) => a.bounding_cylinder(),
+            EntityEnum::Decoration(a) => a.bounding_cylinder(),
+            EntityEnum::Mesh(a) => a.bounding_cylinder(),
+            EntityEnum::Enemy(a) => a.bounding_cylinder(),
+            EntityEnum::Pushable(a) => a.bounding_cylinder(),
+            EntityEnum::Sign(a) => a.bounding_cylinder(),
+            EntityEnum::Gem(a) => a.bounding_cylinder(),
             EntityEnum::Empty(_a) => BoundingCylinder::default(),
         }
     }
+    //non-solid entities keep their bounding shape, but are skipped by the collision checks
+    pub fn is_solid(&self) -> bool {
+        match self {
+            EntityEnum::Cube(a) => a.is_collidable(),
+            EntityEnum::Rectangle(a) => a.is_collidable(),
+            EntityEnum::Mover(a) => a.is_collidable(),
+            EntityEnum::Crumbling(a) => a.is_collidable(),
+            EntityEnum::Finish(a) => a.is_collidable(),
+            EntityEnum::Switch(a) => a.is_collidable(),
+            EntityEnum::Wireframe(a) => a.is_collidable(),
+            EntityEnum::Body(_a) => true,
+            EntityEnum::Bounce(a) => a.is_collidable(),
+            EntityEnum::Ice(a) => a.is_collidable(),
+            EntityEnum::Conveyor(a) => a.is_collidable(),
+            EntityEnum::Wind(_a) => false,
+            EntityEnum::Teleporter(_a) => false,
+            EntityEnum::Decoration(_a) => false,
+            EntityEnum::Mesh(a) => a.is_collidable(),
+            EntityEnum::Enemy(a) => a.is_collidable(),
+            EntityEnum::Pushable(a) => a.is_collidable(),
+            EntityEnum::Sign(a) => a.is_collidable(),
+            EntityEnum::Gem(_a) => false,
+            EntityEnum::Empty(_a) => false,
+        }
+    }
     #[allow(dead_code)]
     pub fn get_y(&self) -> Fixed {
         match self {
@@ -328,6 +535,15 @@ impl EntityEnum {
             EntityEnum::Body(a) => a.get_y(),
             EntityEnum::Bounce(a) => a.get_y(),
             EntityEnum::Ice(a) => a.get_y(),          
+            EntityEnum::Conveyor(a) => a.get_y(),          
+            EntityEnum::Wind(a) => a.get_y(),          
+            EntityEnum::Teleporter(a) => a.get_y(),          
+            EntityEnum::Decoration(a) => a.get_y(),          
+            EntityEnum::Mesh(a) => a.get_y(),          
+            EntityEnum::Enemy(a) => a.get_y(),          
+            EntityEnum::Pushable(a) => a.get_y(),          
+            EntityEnum::Sign(a) => a.get_y(),
+            EntityEnum::Gem(a) => a.get_y(),
             EntityEnum::Empty(_a) => Fixed::const_new(-999),
         }
     }
@@ -343,6 +559,15 @@ impl EntityEnum {
             EntityEnum::Body(a) => a.get_height(),
             EntityEnum::Bounce(a) => a.get_height(),
             EntityEnum::Ice(a) => a.get_height(),
+            EntityEnum::Conveyor(a) => a.get_height(),
+            EntityEnum::Wind(a) => a.get_height(),
+            EntityEnum::Teleporter(a) => a.get_height(),
+            EntityEnum::Decoration(a) => a.get_height(),
+            EntityEnum::Mesh(a) => a.get_height(),
+            EntityEnum::Enemy(a) => a.get_height(),
+            EntityEnum::Pushable(a) => a.get_height(),
+            EntityEnum::Sign(a) => a.get_height(),
+            EntityEnum::Gem(a) => a.get_height(),
             EntityEnum::Empty(_a) => Fixed::const_new(0),
         }
     }
@@ -358,10 +583,44 @@ impl EntityEnum {
             EntityEnum::Body(a) => a.set_color(color),
             EntityEnum::Bounce(a) => a.set_color(color),
             EntityEnum::Ice(a) => a.set_color(color),
+            EntityEnum::Conveyor(a) => a.set_color(color),
+            EntityEnum::Wind(a) => a.set_color(color),
+            EntityEnum::Teleporter(a) => a.set_color(color),
+            EntityEnum::Decoration(a) => a.set_color(color),
+            EntityEnum::Mesh(a) => a.set_color(color),
+            EntityEnum::Enemy(a) => a.set_color(color),
+            EntityEnum::Pushable(a) => a.set_color(color),
+            EntityEnum::Sign(a) => a.set_color(color),
+            EntityEnum::Gem(a) => a.set_color(color),
             EntityEnum::Empty(_a) => {}
         }
     }
 
+    pub fn get_color(&self) -> u16 {
+        match self {
+            EntityEnum::Cube(a) => a.get_color(),
+            EntityEnum::Rectangle(a) => a.get_color(),
+            EntityEnum::Mover(a) => a.get_color(),
+            EntityEnum::Crumbling(a) => a.get_color(),
+            EntityEnum::Finish(a) => a.get_color(),
+            EntityEnum::Switch(a) => a.get_color(),
+            EntityEnum::Wireframe(a) => a.get_color(),
+            EntityEnum::Body(a) => a.get_color(),
+            EntityEnum::Bounce(a) => a.get_color(),
+            EntityEnum::Ice(a) => a.get_color(),
+            EntityEnum::Conveyor(a) => a.get_color(),
+            EntityEnum::Wind(a) => a.get_color(),
+            EntityEnum::Teleporter(a) => a.get_color(),
+            EntityEnum::Decoration(a) => a.get_color(),
+            EntityEnum::Mesh(a) => a.get_color(),
+            EntityEnum::Enemy(a) => a.get_color(),
+            EntityEnum::Pushable(a) => a.get_color(),
+            EntityEnum::Sign(a) => a.get_color(),
+            EntityEnum::Gem(a) => a.get_color(),
+            EntityEnum::Empty(a) => a.get_color(),
+        }
+    }
+
     pub fn tick(&mut self, effects: &effects::InputGameState) -> Option<effects::OutputEvents> {
         match self {
             EntityEnum::Cube(a) => a.tick(effects),
@@ -374,6 +633,15 @@ impl EntityEnum {
             EntityEnum::Body(a) => a.tick(effects),
             EntityEnum::Bounce(a) => a.tick(effects),
             EntityEnum::Ice(a) => a.tick(effects),
+            EntityEnum::Conveyor(a) => a.tick(effects),
+            EntityEnum::Wind(a) => a.tick(effects),
+            EntityEnum::Teleporter(a) => a.tick(effects),
+            EntityEnum::Decoration(a) => a.tick(effects),
+            EntityEnum::Mesh(a) => a.tick(effects),
+            EntityEnum::Enemy(a) => a.tick(effects),
+            EntityEnum::Pushable(a) => a.tick(effects),
+            EntityEnum::Sign(a) => a.tick(effects),
+            EntityEnum::Gem(a) => a.tick(effects),
             EntityEnum::Empty(_a) => None,
         }
     }
@@ -389,6 +657,15 @@ impl EntityEnum {
             EntityEnum::Body(a) => a.get_id(),
             EntityEnum::Bounce(a) => a.get_id(),
             EntityEnum::Ice(a) => a.get_id(),
+            EntityEnum::Conveyor(a) => a.get_id(),
+            EntityEnum::Wind(a) => a.get_id(),
+            EntityEnum::Teleporter(a) => a.get_id(),
+            EntityEnum::Decoration(a) => a.get_id(),
+            EntityEnum::Mesh(a) => a.get_id(),
+            EntityEnum::Enemy(a) => a.get_id(),
+            EntityEnum::Pushable(a) => a.get_id(),
+            EntityEnum::Sign(a) => a.get_id(),
+            EntityEnum::Gem(a) => a.get_id(),
             EntityEnum::Empty(_a) => -1,
         }
     }
@@ -404,6 +681,15 @@ impl EntityEnum {
             EntityEnum::Body(a) => a.set_id(id),
             EntityEnum::Bounce(a) => a.set_id(id),
             EntityEnum::Ice(a) => a.set_id(id),
+            EntityEnum::Conveyor(a) => a.set_id(id),
+            EntityEnum::Wind(a) => a.set_id(id),
+            EntityEnum::Teleporter(a) => a.set_id(id),
+            EntityEnum::Decoration(a) => a.set_id(id),
+            EntityEnum::Mesh(a) => a.set_id(id),
+            EntityEnum::Enemy(a) => a.set_id(id),
+            EntityEnum::Pushable(a) => a.set_id(id),
+            EntityEnum::Sign(a) => a.set_id(id),
+            EntityEnum::Gem(a) => a.set_id(id),
             EntityEnum::Empty(_a) => {}
         }
     }
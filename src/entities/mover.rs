@@ -79,6 +79,12 @@ pub struct Mover {
     wait: u16,
     #[serde(default = "default_u16")]
     waitcounter: u16,
+    //turns around the y-axis per frame, the player standing on it turns along
+    #[serde(default = "default_fixed")]
+    spin: Fixed,
+
+    #[serde(default = "default_true")]
+    collidable: bool,
 }
 
 impl Mover {
@@ -110,9 +116,15 @@ impl Mover {
             direction: 1,
             wait: 0,
             waitcounter: 0,
+            spin: Fixed::const_new(0),
             id: 0,
+            collidable: true,
         }
     }
+
+    pub fn is_collidable(&self) -> bool {
+        self.collidable
+    }
 }
 
 impl Entity for Mover {
@@ -255,6 +267,9 @@ impl Entity for Mover {
     fn set_color(&mut self, color: u16) {
         self.color = color;
     }
+    fn get_color(&self) -> u16 {
+        return self.color;
+    }
 
     fn tick(&mut self, effects: &effects::InputGameState) -> Option<effects::OutputEvents> {
 
@@ -266,6 +281,14 @@ impl Entity for Mover {
             }
         }
 
+        let pivot_x = self.x;
+        let pivot_z = self.z;
+        if self.spin != Fixed::const_new(0) {
+            self.y_rotation = (self.y_rotation + self.spin).modulo(Fixed::const_new(1));
+            self.reload_rotation_matrices();
+            self.refresh_model_matrix();
+        }
+
         let target: [Fixed; 3];
         if self.direction > 0 {
             target = [self.pos_b_x, self.pos_b_y, self.pos_b_z];
@@ -306,10 +329,13 @@ impl Entity for Mover {
 
         //player is standing on the moving block
         if effects.support_below_id == self.id {
-            return Some(effects::OutputEvents::PlayerEvent(effects::MoveXYZ {
+            return Some(effects::OutputEvents::Carry(effects::Carry {
                 move_x: xmovement,
                 move_y: ymovement,
                 move_z: zmovement,
+                turn: self.spin,
+                pivot_x,
+                pivot_z,
             }));
         }
         else {
@@ -0,0 +1,212 @@
+//short lived objects created while a level is running, such as projectiles, debris and particles
+//they live in their own fixed-size pool instead of the entity array, so spawning and despawning
+//never moves a level entity or changes the ids that support_below_id refers to
+
+use agb::InternalAllocator;
+use alloc::vec::Vec;
+
+use super::math;
+use super::Camera;
+use crate::effects;
+use crate::rectangle_model_points;
+use crate::renderer;
+use crate::renderer::polygon::Polygon;
+use math::*;
+
+use crate::fixed;
+use fixed::*;
+
+pub const POOL_SIZE: usize = 24;
+const DEBRIS_GRAVITY: Fixed = Fixed::from_raw(8);
+//puff left behind by a projectile hitting the player
+const IMPACT_PARTICLES: u8 = 3;
+const IMPACT_SPREAD: Fixed = Fixed::from_raw(12);
+const IMPACT_LIFT: Fixed = Fixed::from_raw(8);
+const IMPACT_LIFETIME: u16 = 12;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum DynamicKind {
+    //flies straight and hurts the player on contact
+    Projectile,
+    //falls with gravity
+    Debris,
+    //drifts without gravity, only for looks
+    Particle,
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct Dynamic {
+    pub kind: DynamicKind,
+    pub x: Fixed,
+    pub y: Fixed,
+    pub z: Fixed,
+    pub velocity: [Fixed; 3],
+    pub size: Fixed,
+    pub color: u16,
+    //frames left before despawning, 0 marks a free slot
+    pub lifetime: u16,
+    //only used by projectiles, true to fail the level on a hit instead of knocking the player back
+    pub deadly: bool,
+}
+
+impl Dynamic {
+    pub fn default() -> Self {
+        Self {
+            kind: DynamicKind::Particle,
+            x: Fixed::const_new(0),
+            y: Fixed::const_new(0),
+            z: Fixed::const_new(0),
+            velocity: [Fixed::const_new(0); 3],
+            size: Fixed::const_new(0),
+            color: 0,
+            lifetime: 0,
+            deadly: false,
+        }
+    }
+
+    fn is_active(&self) -> bool {
+        self.lifetime > 0
+    }
+
+    fn hits_player(&self, effects: &effects::InputGameState) -> bool {
+        let half = self.size / 2;
+        if self.y - half > effects.bounding_box.y_top || self.y + half < effects.bounding_box.y_bottom {
+            return false;
+        }
+        let distance = vector_len_2d(vector_sub_2d(
+            [self.x, self.z],
+            [effects.bounding_cylinder.x, effects.bounding_cylinder.z],
+        ));
+        distance < effects.bounding_cylinder.radius + half
+    }
+
+    fn hit_event(&self, effects: &effects::InputGameState) -> effects::OutputEvents {
+        //pushed along the flight direction so a hit from behind doesn't pull the player back
+        let direction = [self.velocity[0], self.velocity[2]];
+        let speed = vector_len_2d(direction);
+        let (push_x, push_z) = if speed > Fixed::const_new(0) {
+            (direction[0] / speed, direction[1] / speed)
+        } else {
+            let diff = vector_sub_2d(
+                [effects.bounding_cylinder.x, effects.bounding_cylinder.z],
+                [self.x, self.z],
+            );
+            let distance = vector_len_2d(diff);
+            if distance > Fixed::const_new(0) {
+                (diff[0] / distance, diff[1] / distance)
+            } else {
+                (Fixed::const_new(0), Fixed::const_new(0))
+            }
+        };
+        effects::OutputEvents::EnemyHit(effects::EnemyHit {
+            deadly: self.deadly,
+            push_x,
+            push_z,
+        })
+    }
+}
+
+pub struct DynamicPool {
+    slots: [Dynamic; POOL_SIZE],
+}
+
+impl DynamicPool {
+    pub fn new() -> Self {
+        Self {
+            slots: [Dynamic::default(); POOL_SIZE],
+        }
+    }
+
+    //takes a free slot, or the one closest to despawning when the pool is full
+    pub fn spawn(&mut self, dynamic: Dynamic) {
+        if dynamic.lifetime == 0 {
+            return;
+        }
+        let mut slot = 0;
+        for i in 0..POOL_SIZE {
+            if !self.slots[i].is_active() {
+                slot = i;
+                break;
+            }
+            if self.slots[i].lifetime < self.slots[slot].lifetime {
+                slot = i;
+            }
+        }
+        self.slots[slot] = dynamic;
+    }
+
+    //spawns count copies with spread added to the horizontal velocity, evenly spaced around the y axis
+    pub fn spawn_burst(&mut self, dynamic: Dynamic, count: u8, spread: Fixed) {
+        for i in 0..count as i32 {
+            let direction = directional_vector_2d(Fixed::const_new(i) / count as i32);
+            let mut piece = dynamic;
+            piece.velocity[0] += direction[0] * spread;
+            piece.velocity[2] += direction[1] * spread;
+            self.spawn(piece);
+        }
+    }
+
+    pub fn tick(
+        &mut self,
+        effects: &effects::InputGameState,
+        events: &mut Vec<effects::OutputEvents, InternalAllocator>,
+    ) {
+        for i in 0..POOL_SIZE {
+            let dynamic = &mut self.slots[i];
+            if !dynamic.is_active() {
+                continue;
+            }
+            if dynamic.kind == DynamicKind::Debris {
+                dynamic.velocity[1] -= DEBRIS_GRAVITY;
+            }
+            dynamic.x += dynamic.velocity[0];
+            dynamic.y += dynamic.velocity[1];
+            dynamic.z += dynamic.velocity[2];
+            dynamic.lifetime -= 1;
+
+            if dynamic.kind == DynamicKind::Projectile && dynamic.hits_player(effects) {
+                events.push(dynamic.hit_event(effects));
+                dynamic.lifetime = 0;
+
+                //the freed slot is reused by the burst
+                let impact = Dynamic {
+                    kind: DynamicKind::Particle,
+                    velocity: [Fixed::const_new(0), IMPACT_LIFT, Fixed::const_new(0)],
+                    size: dynamic.size / 2,
+                    lifetime: IMPACT_LIFETIME,
+                    ..*dynamic
+                };
+                self.spawn_burst(impact, IMPACT_PARTICLES, IMPACT_SPREAD);
+            }
+        }
+    }
+
+    pub fn render(
+        &self,
+        camera: &Camera,
+        polygons: &mut Vec<Polygon, InternalAllocator>,
+        render_distance: Fixed,
+    ) {
+        for dynamic in self.slots.iter() {
+            if !dynamic.is_active() {
+                continue;
+            }
+            let distance =
+                (dynamic.x - camera.x).abs() + (dynamic.y - camera.y).abs() + (dynamic.z - camera.z).abs();
+            if distance > render_distance {
+                continue;
+            }
+            renderer::render::render_rect(
+                &rectangle_model_points(dynamic.size, dynamic.size, dynamic.size),
+                dynamic.x,
+                dynamic.y,
+                dynamic.z,
+                Fixed::const_new(0),
+                camera,
+                dynamic.color,
+                polygons,
+                false,
+            );
+        }
+    }
+}
@@ -0,0 +1,293 @@
+//a box the player can shove around, see moveutils::attempt_move and moveutils::settle_pushables
+//it falls when nothing is below it and weighs down switches it comes to rest on
+
+use agb::InternalAllocator;
+use alloc::vec::Vec;
+use serde::Deserialize;
+
+use super::boundingshapes::BoundingShape;
+use super::math;
+use super::BoundingBox;
+use super::BoundingCylinder;
+use super::Camera;
+use super::Entity;
+use crate::effects;
+use crate::rectangle_model_points;
+use crate::renderer;
+use crate::renderer::polygon::Polygon;
+use math::*;
+
+use crate::fixed;
+use crate::utils;
+use fixed::*;
+
+const GRAVITY: Fixed = Fixed::from_raw(16);
+const MAX_FALL_SPEED: Fixed = Fixed::from_raw(128);
+//stops falling here, so a block pushed off the level doesn't fall forever
+const LOWEST_Y: Fixed = Fixed::const_new(-500);
+//the footprint used for pushing is this much smaller, so a block can slide along a wall it touches
+const FOOTPRINT_SCALE: Fixed = Fixed::from_raw(250);
+
+#[derive(Copy, Clone, Deserialize, Debug)]
+pub struct Pushable {
+    #[serde(default = "default_i16")]
+    id: i16,
+    #[serde(default = "default_fixed")]
+    x: Fixed,
+    #[serde(default = "default_fixed")]
+    y: Fixed,
+    #[serde(default = "default_fixed")]
+    z: Fixed,
+
+    #[serde(default = "default_fixed")]
+    xsize: Fixed,
+    #[serde(default = "default_fixed")]
+    ysize: Fixed,
+    #[serde(default = "default_fixed")]
+    zsize: Fixed,
+
+    #[serde(default = "default_fixed")]
+    x_rotation: Fixed,
+    #[serde(rename = "rotation", default = "default_fixed")]
+    y_rotation: Fixed,
+    #[serde(default = "default_fixed")]
+    z_rotation: Fixed,
+
+    #[serde(default = "default_fixed_3_8")]
+    points: [[Fixed; 3]; 8],
+    #[serde(default = "default_fixed_3_8")]
+    model_rotated_points: [[Fixed; 3]; 8],
+
+    #[serde(default = "default_fixed_3_3")]
+    x_rotation_matrix: [[Fixed; 3]; 3],
+    #[serde(default = "default_fixed_3_3")]
+    y_rotation_matrix: [[Fixed; 3]; 3],
+    #[serde(default = "default_fixed_3_3")]
+    z_rotation_matrix: [[Fixed; 3]; 3],
+
+    #[serde(default = "default_u16")]
+    color: u16,
+
+    #[serde(default = "default_fixed")]
+    yspeed: Fixed,
+    //id of the entity the block rests on, -1 while falling
+    #[serde(default = "negative_i16")]
+    support_id: i16,
+
+    #[serde(default = "default_true")]
+    collidable: bool,
+}
+
+impl Pushable {
+    #[allow(dead_code)]
+    pub fn default() -> Self {
+        Self {
+            id: 0,
+            x: Fixed::const_new(0),
+            y: Fixed::const_new(0),
+            z: Fixed::const_new(0),
+            xsize: Fixed::const_new(0),
+            ysize: Fixed::const_new(0),
+            zsize: Fixed::const_new(0),
+            x_rotation: Fixed::const_new(0),
+            y_rotation: Fixed::const_new(0),
+            z_rotation: Fixed::const_new(0),
+            points: [[Fixed::const_new(0); 3]; 8],
+            model_rotated_points: [[Fixed::const_new(0); 3]; 8],
+            x_rotation_matrix: [[Fixed::const_new(0); 3]; 3],
+            y_rotation_matrix: [[Fixed::const_new(0); 3]; 3],
+            z_rotation_matrix: [[Fixed::const_new(0); 3]; 3],
+            color: 0,
+            yspeed: Fixed::const_new(0),
+            support_id: -1,
+            collidable: true,
+        }
+    }
+
+    pub fn is_collidable(&self) -> bool {
+        self.collidable
+    }
+
+    fn box_with_scale(&self, scale: Fixed) -> BoundingBox {
+        let corners = [0, 1, 5, 4];
+        let points: [[Fixed; 2]; 4] = corners.map(|i| {
+            [
+                self.model_rotated_points[i][0] * scale + self.x,
+                self.model_rotated_points[i][2] * scale + self.z,
+            ]
+        });
+
+        BoundingBox {
+            data: points,
+            center: utils::calculate_center(&points),
+            width: self.xsize * scale,
+            height: self.zsize * scale,
+            y_top: self.model_rotated_points[0][1] + self.y,
+            y_bottom: self.model_rotated_points[2][1] + self.y,
+            rotation: -self.y_rotation,
+        }
+    }
+
+    //slightly shrunk box for checking if the block fits somewhere
+    pub fn footprint(&self) -> BoundingBox {
+        self.box_with_scale(FOOTPRINT_SCALE)
+    }
+
+    pub fn push(&mut self, x: Fixed, z: Fixed) {
+        self.x += x;
+        self.z += z;
+    }
+
+    //ground and support_id as returned by check_support_below for the block
+    pub fn fall(&mut self, ground: Fixed, support_id: i16) {
+        if self.y < LOWEST_Y {
+            return;
+        }
+        self.yspeed -= GRAVITY;
+        if self.yspeed < -MAX_FALL_SPEED {
+            self.yspeed = -MAX_FALL_SPEED;
+        }
+
+        let bottom = self.model_rotated_points[2][1] + self.y;
+        if bottom + self.yspeed <= ground {
+            self.y += ground - bottom;
+            self.yspeed = Fixed::const_new(0);
+            self.support_id = support_id;
+        } else {
+            self.y += self.yspeed;
+            self.support_id = -1;
+        }
+    }
+
+    pub fn support_id(&self) -> i16 {
+        return self.support_id;
+    }
+}
+
+impl Entity for Pushable {
+    fn set_x_offset(&mut self, x_offset: Fixed) {
+        self.x = x_offset;
+    }
+
+    fn set_y_offset(&mut self, y_offset: Fixed) {
+        self.y = y_offset;
+    }
+
+    fn set_z_offset(&mut self, z_offset: Fixed) {
+        self.z = z_offset;
+    }
+
+    fn set_size(&mut self, size: Fixed) {
+        self.xsize = size;
+        self.ysize = size;
+        self.zsize = size;
+    }
+
+    fn recalculate_points(&mut self) {
+        self.points = rectangle_model_points(self.xsize, self.ysize, self.zsize);
+    }
+
+    fn set_x_rotation(&mut self, x_rotation: Fixed) {
+        self.x_rotation = x_rotation;
+        self.x_rotation_matrix = utils::x_rotation_matrix(x_rotation);
+    }
+
+    fn set_y_rotation(&mut self, y_rotation: Fixed) {
+        self.y_rotation = y_rotation;
+        self.y_rotation_matrix = utils::y_rotation_matrix(y_rotation);
+    }
+
+    fn set_z_rotation(&mut self, z_rotation: Fixed) {
+        self.z_rotation = z_rotation;
+        self.z_rotation_matrix = utils::z_rotation_matrix(z_rotation);
+    }
+
+    fn reload_rotation_matrices(&mut self) {
+        self.set_x_rotation(self.x_rotation);
+        self.set_y_rotation(self.y_rotation);
+        self.set_z_rotation(self.z_rotation);
+    }
+
+    fn refresh_model_matrix(&mut self) {
+        for i in 0..self.points.len() {
+            let point: &[Fixed; 3] = &self.points[i];
+
+            let mut rotated_point: [Fixed; 3] = matmul(self.x_rotation_matrix, *point);
+            rotated_point = matmul(self.y_rotation_matrix, rotated_point);
+            rotated_point = matmul(self.z_rotation_matrix, rotated_point);
+
+            self.model_rotated_points[i] = rotated_point;
+        }
+    }
+
+    fn set_vertex(&mut self, _point: [Fixed; 3], _index: i32) {
+        //not implemented
+    }
+
+    fn render(
+        &mut self,
+        camera: &Camera,
+        polygons: &mut Vec<Polygon, InternalAllocator>,
+        render_distance: Fixed,
+    ) {
+        if self.distance_from_camera(camera) > render_distance {
+            return;
+        }
+
+        renderer::render::render_rect(
+            &self.model_rotated_points,
+            self.x,
+            self.y,
+            self.z,
+            self.y_rotation,
+            camera,
+            self.color,
+            polygons,
+            false,
+        );
+    }
+
+    fn distance_from_camera(&self, camera: &Camera) -> Fixed {
+        return (self.x - camera.x).abs() + (self.y - camera.y).abs() + (self.z - camera.z).abs();
+    }
+
+    fn bounding_shape(&self) -> Option<BoundingShape> {
+        Some(BoundingShape::BoundingBox(self.box_with_scale(Fixed::const_new(1))))
+    }
+
+    fn bounding_cylinder(&self) -> BoundingCylinder {
+        BoundingCylinder {
+            x: self.x,
+            z: self.z,
+            radius: self.xsize / 2,
+            y_top: self.model_rotated_points[0][1] + self.y,
+            y_bottom: self.model_rotated_points[2][1] + self.y,
+        }
+    }
+
+    fn get_y(&self) -> Fixed {
+        return self.y;
+    }
+    fn get_height(&self) -> Fixed {
+        return self.ysize;
+    }
+
+    fn set_color(&mut self, color: u16) {
+        self.color = color;
+    }
+    fn get_color(&self) -> u16 {
+        return self.color;
+    }
+
+    fn tick(&mut self, _effects: &effects::InputGameState) -> Option<effects::OutputEvents> {
+        None
+    }
+
+    fn get_id(&self) -> i16 {
+        return self.id;
+    }
+
+    fn set_id(&mut self, id: i16) {
+        self.id = id
+    }
+}
@@ -56,6 +56,10 @@ pub struct Rectangle {
 
     #[serde(default = "default_u16")]
     color: u16,
+
+    //set to false for scenery that the player can walk through
+    #[serde(default = "default_true")]
+    collidable: bool,
 }
 
 impl Rectangle {
@@ -78,8 +82,13 @@ impl Rectangle {
             y_rotation_matrix: [[Fixed::const_new(0); 3]; 3],
             z_rotation_matrix: [[Fixed::const_new(0); 3]; 3],
             color: 0,
+            collidable: true,
         }
     }
+
+    pub fn is_collidable(&self) -> bool {
+        self.collidable
+    }
 }
 
 impl Entity for Rectangle {
@@ -218,6 +227,9 @@ impl Entity for Rectangle {
     fn set_color(&mut self, color: u16) {
         self.color = color;
     }
+    fn get_color(&self) -> u16 {
+        return self.color;
+    }
     fn tick(&mut self, _effects: &effects::InputGameState) -> Option<effects::OutputEvents> {
         return None;
     }
@@ -0,0 +1,274 @@
+//a board on a post that shows a message while the player stands near it
+//the text is written in the level file under "text", scripts/bundle_levels.py moves it into a
+//table of the level's messages and leaves its index in "message"
+//xsize is the width of the board and ysize the height of the whole sign, centered on y
+
+use agb::InternalAllocator;
+use alloc::vec::Vec;
+use serde::Deserialize;
+
+use super::boundingshapes::BoundingShape;
+use super::math;
+use super::BoundingBox;
+use super::BoundingCylinder;
+use super::Camera;
+use super::Entity;
+use crate::effects;
+use crate::renderer;
+use crate::renderer::polygon::Polygon;
+use math::*;
+
+use crate::fixed;
+use fixed::*;
+
+use crate::utils;
+
+const POST_COLOR: u16 = 3;
+const POST_WIDTH: Fixed = Fixed::from_raw(38);
+const BOARD_THICKNESS: Fixed = Fixed::from_raw(24);
+
+fn default_width() -> Fixed {
+    Fixed::const_new(1)
+}
+
+fn default_height() -> Fixed {
+    Fixed::from_raw(384)
+}
+
+//how close the player has to be to read the sign
+fn default_radius() -> Fixed {
+    Fixed::from_raw(384)
+}
+
+#[derive(Copy, Clone, Deserialize, Debug)]
+pub struct Sign {
+    #[serde(default = "default_i16")]
+    id: i16,
+    #[serde(default = "default_fixed")]
+    x: Fixed,
+    #[serde(default = "default_fixed")]
+    y: Fixed,
+    #[serde(default = "default_fixed")]
+    z: Fixed,
+
+    #[serde(default = "default_width")]
+    xsize: Fixed,
+    #[serde(default = "default_height")]
+    ysize: Fixed,
+
+    #[serde(default = "default_fixed")]
+    x_rotation: Fixed,
+    #[serde(rename = "rotation", default = "default_fixed")]
+    y_rotation: Fixed,
+    #[serde(default = "default_fixed")]
+    z_rotation: Fixed,
+
+    #[serde(default = "default_fixed_3_8")]
+    post_points: [[Fixed; 3]; 8],
+    #[serde(default = "default_fixed_3_8")]
+    board_points: [[Fixed; 3]; 8],
+    #[serde(default = "default_fixed_3_8")]
+    post_rotated_points: [[Fixed; 3]; 8],
+    #[serde(default = "default_fixed_3_8")]
+    board_rotated_points: [[Fixed; 3]; 8],
+
+    #[serde(default = "default_fixed_3_3")]
+    x_rotation_matrix: [[Fixed; 3]; 3],
+    #[serde(default = "default_fixed_3_3")]
+    y_rotation_matrix: [[Fixed; 3]; 3],
+    #[serde(default = "default_fixed_3_3")]
+    z_rotation_matrix: [[Fixed; 3]; 3],
+
+    #[serde(default = "default_u16")]
+    color: u16,
+
+    //index in the level's messages, see levels::message
+    #[serde(default = "default_u16")]
+    message: u16,
+    #[serde(default = "default_radius")]
+    radius: Fixed,
+
+    #[serde(default = "default_true")]
+    collidable: bool,
+}
+
+impl Sign {
+    pub fn is_collidable(&self) -> bool {
+        self.collidable
+    }
+
+    fn rotate(&self, point: &[Fixed; 3]) -> [Fixed; 3] {
+        let mut rotated_point: [Fixed; 3] = matmul(self.x_rotation_matrix, *point);
+        rotated_point = matmul(self.y_rotation_matrix, rotated_point);
+        rotated_point = matmul(self.z_rotation_matrix, rotated_point);
+        return rotated_point;
+    }
+}
+
+impl Entity for Sign {
+    fn set_x_offset(&mut self, x_offset: Fixed) {
+        self.x = x_offset;
+    }
+
+    fn set_y_offset(&mut self, y_offset: Fixed) {
+        self.y = y_offset;
+    }
+
+    fn set_z_offset(&mut self, z_offset: Fixed) {
+        self.z = z_offset;
+    }
+
+    fn set_size(&mut self, _size: Fixed) {}
+
+    fn recalculate_points(&mut self) {
+        //the post goes from the bottom to the middle of the board, the board takes the top 2/5
+        let board_height = self.ysize * 2 / 5;
+        let board_y = self.ysize / 2 - board_height / 2;
+        let post_height = self.ysize - board_height / 2;
+        let post_y = -self.ysize / 2 + post_height / 2;
+
+        self.post_points = utils::rectangle_model_points(POST_WIDTH, post_height, POST_WIDTH);
+        for point in self.post_points.iter_mut() {
+            point[1] += post_y;
+        }
+        self.board_points = utils::rectangle_model_points(self.xsize, board_height, BOARD_THICKNESS);
+        for point in self.board_points.iter_mut() {
+            point[1] += board_y;
+        }
+    }
+
+    fn set_x_rotation(&mut self, x_rotation: Fixed) {
+        self.x_rotation = x_rotation;
+        self.x_rotation_matrix = utils::x_rotation_matrix(x_rotation);
+    }
+
+    fn set_y_rotation(&mut self, y_rotation: Fixed) {
+        self.y_rotation = y_rotation;
+        self.y_rotation_matrix = utils::y_rotation_matrix(y_rotation);
+    }
+
+    fn set_z_rotation(&mut self, z_rotation: Fixed) {
+        self.z_rotation = z_rotation;
+        self.z_rotation_matrix = utils::z_rotation_matrix(z_rotation);
+    }
+
+    fn reload_rotation_matrices(&mut self) {
+        self.set_x_rotation(self.x_rotation);
+        self.set_y_rotation(self.y_rotation);
+        self.set_z_rotation(self.z_rotation);
+    }
+
+    fn refresh_model_matrix(&mut self) {
+        for i in 0..8 {
+            self.post_rotated_points[i] = self.rotate(&self.post_points[i]);
+            self.board_rotated_points[i] = self.rotate(&self.board_points[i]);
+        }
+    }
+
+    fn set_vertex(&mut self, _point: [Fixed; 3], _index: i32) {
+        //not implemented
+    }
+
+    fn render(&mut self, camera: &Camera, polygons: &mut Vec<Polygon, InternalAllocator>, render_distance: Fixed) {
+        if self.distance_from_camera(camera) > render_distance {
+            return;
+        }
+
+        renderer::render::render_rect(
+            &self.post_rotated_points,
+            self.x,
+            self.y,
+            self.z,
+            self.y_rotation,
+            camera,
+            POST_COLOR,
+            polygons,
+            false
+        );
+        renderer::render::render_rect(
+            &self.board_rotated_points,
+            self.x,
+            self.y,
+            self.z,
+            self.y_rotation,
+            camera,
+            self.color,
+            polygons,
+            false
+        );
+    }
+
+    fn distance_from_camera(&self, camera: &Camera) -> Fixed {
+        return (self.x - camera.x).abs() + (self.y - camera.y).abs() + (self.z - camera.z).abs();
+    }
+
+    //the footprint of the board, from the bottom of the post to the top of the board
+    fn bounding_shape(&self) -> Option<BoundingShape> {
+        let points: [[Fixed; 2]; 4] = [
+            [self.board_rotated_points[0][0] + self.x, self.board_rotated_points[0][2] + self.z],
+            [self.board_rotated_points[1][0] + self.x, self.board_rotated_points[1][2] + self.z],
+            [self.board_rotated_points[5][0] + self.x, self.board_rotated_points[5][2] + self.z],
+            [self.board_rotated_points[4][0] + self.x, self.board_rotated_points[4][2] + self.z],
+        ];
+
+        Some(BoundingShape::BoundingBox(BoundingBox {
+            data: points,
+            center: utils::calculate_center(&points),
+            width: self.xsize,
+            height: BOARD_THICKNESS,
+            y_top: self.y + self.ysize / 2,
+            y_bottom: self.y - self.ysize / 2,
+            rotation: self.y_rotation,
+        }))
+    }
+
+    fn bounding_cylinder(&self) -> BoundingCylinder {
+        BoundingCylinder {
+            x: self.x,
+            z: self.z,
+            radius: self.xsize / 2,
+            y_top: self.y + self.ysize / 2,
+            y_bottom: self.y - self.ysize / 2,
+        }
+    }
+
+    fn get_y(&self) -> Fixed {
+        return self.y;
+    }
+    fn get_height(&self) -> Fixed {
+        return self.ysize;
+    }
+
+    fn set_color(&mut self, color: u16) {
+        self.color = color;
+    }
+    fn get_color(&self) -> u16 {
+        return self.color;
+    }
+
+    fn tick(&mut self, effects: &effects::InputGameState) -> Option<effects::OutputEvents> {
+        //close enough in the same way as a switch, and not far above or below the sign
+        let offset = vector_sub_2d(effects.bounding_box.center, [self.x, self.z]);
+        if offset[0].abs() > self.radius || offset[1].abs() > self.radius {
+            return None;
+        }
+        let feet = effects.bounding_cylinder.y_bottom;
+        if feet > self.y + self.ysize || feet < self.y - self.ysize {
+            return None;
+        }
+        if math::vector_len_2d(offset) < self.radius {
+            return Some(effects::OutputEvents::Message(effects::Message {
+                index: self.message,
+            }));
+        }
+        None
+    }
+
+    fn get_id(&self) -> i16 {
+        return self.id;
+    }
+
+    fn set_id(&mut self, id: i16) {
+        self.id = id
+    }
+}
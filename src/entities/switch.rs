@@ -60,10 +60,29 @@ pub struct Switch {
 
     #[serde(default = "default_bool")]
     state: bool,
+
+    //a pressure switch stays flipped only while the player or a pushable block rests on it
+    #[serde(default = "default_bool")]
+    pressure: bool,
+    //a pushable block rests on the switch, set by moveutils::settle_pushables
+    #[serde(default = "default_bool")]
+    weighted: bool,
+    #[serde(default = "default_bool")]
+    was_weighted: bool,
+
+    #[serde(default = "default_true")]
+    collidable: bool,
 }
 
 impl Switch {
+    pub fn is_collidable(&self) -> bool {
+        self.collidable
+    }
+
     pub fn position_offset_from_state(&self) -> (Fixed, Fixed) {
+        if self.pressure {
+            return (Fixed::const_new(0), Fixed::const_new(0));
+        }
         //90 derived from: LENGTH * cos(45) / 2
         //where the length is the height of the stick in y dir
         let mut result: Fixed = (self.points[1][1] - self.points[2][1]).abs() * Fixed::from_raw(90);
@@ -81,6 +100,14 @@ impl Switch {
         self.reload_rotation_matrices();
         self.refresh_model_matrix();
     }
+
+    pub fn is_on(&self) -> bool {
+        return self.state;
+    }
+
+    pub fn set_weighted(&mut self, weighted: bool) {
+        self.weighted = weighted;
+    }
 }
 
 impl Entity for Switch {
@@ -99,9 +126,16 @@ impl Entity for Switch {
     fn set_size(&mut self, _size: Fixed) {}
 
     fn recalculate_points(&mut self) {
-        self.xsize = Fixed::from_raw(48);
-        self.ysize = Fixed::from_raw(300);
-        self.zsize = Fixed::from_raw(48);
+        if self.pressure {
+            //a flat plate instead of a lever
+            self.xsize = Fixed::const_new(1);
+            self.ysize = Fixed::from_raw(32);
+            self.zsize = Fixed::const_new(1);
+        } else {
+            self.xsize = Fixed::from_raw(48);
+            self.ysize = Fixed::from_raw(300);
+            self.zsize = Fixed::from_raw(48);
+        }
 
         self.points = utils::rectangle_model_points(self.xsize, self.ysize, self.zsize)
 
@@ -123,7 +157,9 @@ impl Entity for Switch {
     }
 
     fn reload_rotation_matrices(&mut self) {
-        if self.state {
+        if self.pressure {
+            self.x_rotation = Fixed::const_new(0);
+        } else if self.state {
             self.x_rotation = Fixed::from_raw(32);
         } else {
             self.x_rotation = Fixed::from_raw(224);
@@ -156,10 +192,15 @@ impl Entity for Switch {
         }
 
         let (x_add, z_add) = self.position_offset_from_state();
+        //a pressed plate sinks into the ground
+        let mut y = self.y;
+        if self.pressure && self.state {
+            y -= self.ysize / 2;
+        }
         renderer::render::render_rect(
             &self.model_rotated_points,
             self.x + x_add,
-            self.y,
+            y,
             self.z + z_add,
             self.y_rotation,
             camera,
@@ -229,8 +270,28 @@ impl Entity for Switch {
     fn set_color(&mut self, color: u16) {
         self.color = color;
     }
+    fn get_color(&self) -> u16 {
+        return self.color;
+    }
 
     fn tick(&mut self, effects: &effects::InputGameState) -> Option<effects::OutputEvents> {
+        if self.pressure {
+            let pressed = self.weighted || effects.support_below_id == self.id;
+            if pressed != self.state {
+                self.flip();
+                return Some(effects::OutputEvents::SwitchAction(effects::SwitchFlip {}));
+            }
+            return None;
+        }
+
+        //a block landing on the switch flips it like the player would
+        let landed = self.weighted && !self.was_weighted;
+        self.was_weighted = self.weighted;
+        if landed {
+            self.flip();
+            return Some(effects::OutputEvents::SwitchAction(effects::SwitchFlip {}));
+        }
+
         if effects.action_requested {
             if math::vector_len_2d(vector_sub_2d(effects.bounding_box.center, [self.x, self.z]))
                 < Fixed::from_raw(400)
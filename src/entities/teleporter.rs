@@ -0,0 +1,261 @@
+//a portal that sends the player to another teleporter, target is the id of the partner
+//links are one way, so give both portals a target for a two way pair
+
+use agb::InternalAllocator;
+use alloc::vec::Vec;
+use serde::Deserialize;
+
+use super::boundingshapes::BoundingShape;
+use super::finish::{portal_bounding_box, portal_points, render_portal};
+use super::math;
+use super::utils::volume_overlap_check;
+use super::BoundingCylinder;
+use super::Camera;
+use super::Entity;
+use crate::effects;
+use crate::renderer::polygon::Polygon;
+use math::*;
+
+use crate::fixed;
+use fixed::*;
+
+use crate::utils;
+
+#[derive(Copy, Clone, Deserialize, Debug)]
+pub struct Teleporter {
+    #[serde(default = "default_i16")]
+    id: i16,
+    #[serde(default = "default_fixed")]
+    x: Fixed,
+    #[serde(default = "default_fixed")]
+    y: Fixed,
+    #[serde(default = "default_fixed")]
+    z: Fixed,
+
+    #[serde(default = "default_fixed")]
+    size: Fixed,
+
+    #[serde(default = "default_fixed")]
+    x_rotation: Fixed,
+    #[serde(rename = "rotation", default = "default_fixed")]
+    y_rotation: Fixed,
+    #[serde(default = "default_fixed")]
+    z_rotation: Fixed,
+
+    #[serde(default = "default_fixed_3_14")]
+    points: [[Fixed; 3]; 14],
+    #[serde(default = "default_fixed_3_14")]
+    model_rotated_points: [[Fixed; 3]; 14],
+
+    #[serde(default = "default_fixed_3_3")]
+    x_rotation_matrix: [[Fixed; 3]; 3],
+    #[serde(default = "default_fixed_3_3")]
+    y_rotation_matrix: [[Fixed; 3]; 3],
+    #[serde(default = "default_fixed_3_3")]
+    z_rotation_matrix: [[Fixed; 3]; 3],
+
+    #[serde(default = "default_u16")]
+    color: u16,
+
+    #[serde(default = "default_fixed")]
+    radius: Fixed,
+    #[serde(default = "default_fixed")]
+    depth: Fixed,
+
+    //-1 means the portal is only an exit
+    #[serde(default = "negative_i16")]
+    target: i16,
+    //turn the player to face this angle when they arrive here
+    #[serde(default = "default_bool")]
+    set_angle: bool,
+    #[serde(default = "default_fixed")]
+    angle: Fixed,
+
+    //only teleport when the player enters, so arriving at a portal doesn't send them straight back
+    #[serde(default = "default_bool")]
+    player_inside: bool,
+}
+
+impl Teleporter {
+    #[allow(dead_code)]
+    pub fn default() -> Self {
+        Self {
+            id: 0,
+            x: Fixed::const_new(0),
+            y: Fixed::const_new(0),
+            z: Fixed::const_new(0),
+            size: Fixed::const_new(1),
+            x_rotation: Fixed::const_new(0),
+            y_rotation: Fixed::const_new(0),
+            z_rotation: Fixed::const_new(0),
+            points: [[Fixed::const_new(0); 3]; 14],
+            model_rotated_points: [[Fixed::const_new(0); 3]; 14],
+            x_rotation_matrix: [[Fixed::const_new(0); 3]; 3],
+            y_rotation_matrix: [[Fixed::const_new(0); 3]; 3],
+            z_rotation_matrix: [[Fixed::const_new(0); 3]; 3],
+            color: 0,
+            radius: Fixed::const_new(0),
+            depth: Fixed::const_new(0),
+            target: -1,
+            set_angle: false,
+            angle: Fixed::const_new(0),
+            player_inside: false,
+        }
+    }
+
+    //where the player should be placed when arriving, at the bottom edge of the portal
+    pub fn exit_position(&self) -> (Fixed, Fixed, Fixed) {
+        (self.x, self.y - self.radius, self.z)
+    }
+
+    pub fn exit_angle(&self) -> Option<Fixed> {
+        if self.set_angle {
+            Some(self.angle)
+        } else {
+            None
+        }
+    }
+
+    pub fn arrive(&mut self) {
+        self.player_inside = true;
+    }
+}
+
+impl Entity for Teleporter {
+    fn set_x_offset(&mut self, x_offset: Fixed) {
+        self.x = x_offset;
+    }
+
+    fn set_y_offset(&mut self, y_offset: Fixed) {
+        self.y = y_offset;
+    }
+
+    fn set_z_offset(&mut self, z_offset: Fixed) {
+        self.z = z_offset;
+    }
+
+    fn set_size(&mut self, size: Fixed) {
+        self.size = size;
+    }
+
+    fn recalculate_points(&mut self) {
+        self.depth = Fixed::from_raw(32);
+        self.radius = Fixed::const_new(2);
+        self.points = portal_points(self.radius, self.depth);
+    }
+
+    fn set_x_rotation(&mut self, x_rotation: Fixed) {
+        self.x_rotation = x_rotation;
+        self.x_rotation_matrix = utils::x_rotation_matrix(x_rotation);
+    }
+
+    fn set_y_rotation(&mut self, y_rotation: Fixed) {
+        self.y_rotation = y_rotation;
+        self.y_rotation_matrix = utils::y_rotation_matrix(y_rotation);
+    }
+
+    fn set_z_rotation(&mut self, z_rotation: Fixed) {
+        self.z_rotation = z_rotation;
+        self.z_rotation_matrix = utils::z_rotation_matrix(z_rotation);
+    }
+
+    fn reload_rotation_matrices(&mut self) {
+        self.set_x_rotation(self.x_rotation);
+        self.set_y_rotation(self.y_rotation);
+        self.set_z_rotation(self.z_rotation);
+    }
+
+    fn refresh_model_matrix(&mut self) {
+        for i in 0..self.points.len() {
+            let point: &[Fixed; 3] = &self.points[i];
+
+            let mut rotated_point: [Fixed; 3] = matmul(self.x_rotation_matrix, *point);
+            rotated_point = matmul(self.y_rotation_matrix, rotated_point);
+            rotated_point = matmul(self.z_rotation_matrix, rotated_point);
+
+            self.model_rotated_points[i] = rotated_point;
+        }
+    }
+
+    fn set_vertex(&mut self, _point: [Fixed; 3], _index: i32) {
+        //not implemented
+    }
+
+    fn render(
+        &mut self,
+        camera: &Camera,
+        polygons: &mut Vec<Polygon, InternalAllocator>,
+        render_distance: Fixed,
+    ) {
+        if self.distance_from_camera(camera) > render_distance {
+            return;
+        }
+
+        render_portal(
+            &self.model_rotated_points,
+            self.x,
+            self.y,
+            self.z,
+            camera,
+            self.color,
+            polygons,
+        );
+    }
+
+    fn distance_from_camera(&self, camera: &Camera) -> Fixed {
+        return (self.x - camera.x).abs() + (self.y - camera.y).abs() + (self.z - camera.z).abs();
+    }
+
+    fn bounding_shape(&self) -> Option<BoundingShape> {
+        //the player passes through the portal
+        None
+    }
+
+    fn bounding_cylinder(&self) -> BoundingCylinder {
+        BoundingCylinder::empty()
+    }
+
+    fn get_y(&self) -> Fixed {
+        return self.y;
+    }
+    fn get_height(&self) -> Fixed {
+        return self.radius * 2;
+    }
+
+    fn set_color(&mut self, color: u16) {
+        self.color = color;
+    }
+    fn get_color(&self) -> u16 {
+        return self.color;
+    }
+
+    fn tick(&mut self, effects: &effects::InputGameState) -> Option<effects::OutputEvents> {
+        let hitbox = portal_bounding_box(
+            &self.model_rotated_points,
+            self.x,
+            self.y,
+            self.z,
+            self.radius,
+            self.y_rotation,
+        );
+        let inside = volume_overlap_check(&hitbox, effects.bounding_box, effects.bounding_cylinder);
+        let entered = inside && !self.player_inside;
+        self.player_inside = inside;
+
+        if entered && self.target >= 0 {
+            return Some(effects::OutputEvents::Teleport(effects::Teleport {
+                target: self.target,
+            }));
+        } else {
+            None
+        }
+    }
+
+    fn get_id(&self) -> i16 {
+        return self.id;
+    }
+
+    fn set_id(&mut self, id: i16) {
+        self.id = id
+    }
+}
@@ -138,6 +138,18 @@ pub fn rect_overlap(first: &BoundingBox, second: &BoundingBox) -> bool {
     return false;
 }
 
+//true if the player box or cylinder is inside or touching the volume, used for non-solid triggers
+pub fn volume_overlap_check(
+    volume: &BoundingBox,
+    player_box: &BoundingBox,
+    player_cylinder: &BoundingCylinder,
+) -> bool {
+    return (player_box.y_top > volume.y_bottom && player_box.y_bottom < volume.y_top)
+        && rect_simple_overlap_check(player_box, volume)
+        && (rect_overlap(volume, player_box)
+            || cylinder_and_rotated_rect_collision(player_cylinder, volume).1);
+}
+
 pub fn vertical_room_for_box(
     first: &BoundingBox,
     second: &BoundingBox,
@@ -211,6 +223,19 @@ fn vertical_room_for_cylinder(
     // }
 }
 
+//top of a solid entity under the player, -999 when it isn't below the player
+fn support_top(e: &EntityEnum, bottom: &BoundingBox, fallback: &BoundingCylinder) -> Fixed {
+    if let Some(top_shape) = e.bounding_shape() {
+        if let BoundingShape::BoundingBox(top) = top_shape {
+            return vertical_room_for_box(&top, bottom, fallback, Fixed::const_new(-999));
+        } else if let BoundingShape::BoundingCylinder(top) = top_shape {
+            //using player cylinder as fallback, as the box-cylinder check seems borked if it's small as the player is
+            return vertical_room_for_cylinder(&top, fallback, Fixed::const_new(-999));
+        }
+    }
+    return Fixed::const_new(-999);
+}
+
 //determine if the element in the entiry array is below us and how far
 pub fn check_support_below(
     entity_array: &[EntityEnum],
@@ -227,29 +252,12 @@ pub fn check_support_below(
         if let EntityEnum::Empty(_) = e {
             break;
         }
-        if i != 0 && i != 1 {
-            //let top: BoundingBox = e.bounding_box();
-            let top_shape = e.bounding_shape();
-            if let Some(top_shape) = top_shape {
-                if let BoundingShape::BoundingBox(top) = top_shape {
-                    let d: Fixed =
-                        vertical_room_for_box(&top, &bottom, &fallback, Fixed::const_new(-999));
-                    if d > height {
-                        height = d;
-                        if (height - bottom.y_bottom).abs() < DISTANCE_NUDGE_FACTOR {
-                            collider_id = e.get_id();
-                        }
-                    }
-                } else if let BoundingShape::BoundingCylinder(top) = top_shape {
-                    //using player cylinder as fallback, as the box-cylinder check seems borked if it's small as the player is
-                    let d: Fixed =
-                        vertical_room_for_cylinder(&top, &fallback, Fixed::const_new(-999));
-                    if d > height {
-                        height = d;
-                        if (height - bottom.y_bottom).abs() < DISTANCE_NUDGE_FACTOR  {
-                            collider_id = e.get_id();
-                        }
-                    }
+        if i != 0 && i != 1 && e.is_solid() {
+            let d: Fixed = support_top(e, bottom, fallback);
+            if d > height {
+                height = d;
+                if (height - bottom.y_bottom).abs() < DISTANCE_NUDGE_FACTOR {
+                    collider_id = e.get_id();
                 }
             }
         }
@@ -257,6 +265,30 @@ pub fn check_support_below(
     return (height, collider_id);
 }
 
+//where the player would land, the highest solid top under the player however far down it is
+//returns -1 as the id when there is nothing below
+pub fn check_landing_below(
+    entity_array: &[EntityEnum],
+    bottom: &BoundingBox,
+    fallback: &BoundingCylinder,
+) -> (Fixed, i16) {
+    let mut height: Fixed = Fixed::const_new(-999);
+    let mut landing_id: i16 = -1;
+    for (i, e) in entity_array.iter().enumerate() {
+        if let EntityEnum::Empty(_) = e {
+            break;
+        }
+        if i != 0 && i != 1 && e.is_solid() {
+            let d: Fixed = support_top(e, bottom, fallback);
+            if d > height {
+                height = d;
+                landing_id = e.get_id();
+            }
+        }
+    }
+    return (height, landing_id);
+}
+
 pub fn check_block_above(
     entity_array: &[EntityEnum],
     top: &BoundingBox,
@@ -267,7 +299,7 @@ pub fn check_block_above(
         if let EntityEnum::Empty(_) = e {
             break;
         }
-        if i != 0 && i != 1 {
+        if i != 0 && i != 1 && e.is_solid() {
             let bottom_shape = e.bounding_shape();
 
             if let Some(bottom_shape) = bottom_shape {
@@ -404,14 +436,25 @@ pub fn horizontal_collision_check(
     entity_array: &[EntityEnum],
     cyl1: BoundingCylinder,
 ) -> (Fixed, bool) {
+    match horizontal_collision(entity_array, cyl1) {
+        Some((_, wallangle)) => (wallangle, true),
+        None => (Fixed::default(), false),
+    }
+}
+
+//the index and wall angle of the first entity the cylinder runs into
+pub fn horizontal_collision(
+    entity_array: &[EntityEnum],
+    cyl1: BoundingCylinder,
+) -> Option<(usize, Fixed)> {
     for (i, e) in entity_array.iter().enumerate() {
-        if i != 0 && i != 1 {
+        if i != 0 && i != 1 && e.is_solid() {
             let shape2: Option<BoundingShape> = e.bounding_shape();
 
             if let Some(BoundingShape::BoundingBox(box2)) = shape2 {
                 if box2.rotation == Fixed::const_new(0) {
                     if cylinder_and_rect_collision(&cyl1, &box2) {
-                        return (Fixed::const_new(0), true);
+                        return Some((i, Fixed::const_new(0)));
                     }
                 } else {
                     if cyl1.y_top <= box2.y_bottom || box2.y_top <= cyl1.y_bottom {
@@ -419,7 +462,7 @@ pub fn horizontal_collision_check(
                     }
                     let (wallangle, ok) = cylinder_and_rotated_rect_collision(&cyl1, &box2);
                     if ok {
-                        return (wallangle, true);
+                        return Some((i, wallangle));
                     }
                 }
             } else if let Some(BoundingShape::BoundingCylinder(cyl2)) = shape2 {
@@ -433,14 +476,48 @@ pub fn horizontal_collision_check(
                 let sum_radius = cyl1.radius + cyl2.radius;
                 if distance_squared < sum_radius * sum_radius {
                     //TODO: estimate the angle for the vector here
-                    return (vector_angle(dx, dz), true);
+                    return Some((i, vector_angle(dx, dz)));
                 }
             }
         }
     }
-    return (Fixed::default(), false);
+    None
+}
+
+//true if the box overlaps a solid entity other than the player or the entity at skip,
+//boxes stacked on top of each other don't count
+pub fn box_collision_check(entity_array: &[EntityEnum], moving: &BoundingBox, skip: usize) -> bool {
+    for (i, e) in entity_array.iter().enumerate() {
+        if let EntityEnum::Empty(_) = e {
+            break;
+        }
+        if i == 0 || i == 1 || i == skip || !e.is_solid() {
+            continue;
+        }
+        match e.bounding_shape() {
+            Some(BoundingShape::BoundingBox(other)) => {
+                if moving.y_top <= other.y_bottom || other.y_top <= moving.y_bottom {
+                    continue;
+                }
+                if rect_simple_overlap_check(moving, &other) && rect_overlap(moving, &other) {
+                    return true;
+                }
+            }
+            Some(BoundingShape::BoundingCylinder(other)) => {
+                if moving.y_top <= other.y_bottom || other.y_top <= moving.y_bottom {
+                    continue;
+                }
+                if cylinder_and_rotated_rect_collision(&other, moving).1 {
+                    return true;
+                }
+            }
+            None => {}
+        }
+    }
+    false
 }
 
+
 fn vector_angle(dx: Fixed, dz: Fixed) -> Fixed {
     let hyp = vector_len_2d([dx, dz]);
     if hyp == Fixed::const_new(0) {
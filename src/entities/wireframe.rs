@@ -59,6 +59,9 @@ pub struct Wireframe {
 
     #[serde(default = "default_bool")]
     solid: bool,
+
+    #[serde(default = "default_true")]
+    collidable: bool,
 }
 
 impl Wireframe {
@@ -82,8 +85,13 @@ impl Wireframe {
             z_rotation_matrix: [[Fixed::const_new(0); 3]; 3],
             color: 0,
             solid: false,
+            collidable: true,
         }
     }
+
+    pub fn is_collidable(&self) -> bool {
+        self.collidable
+    }
     pub fn toggle(&mut self) {
         self.solid = !self.solid;
     }
@@ -246,6 +254,9 @@ impl Entity for Wireframe {
     fn set_color(&mut self, color: u16) {
         self.color = color;
     }
+    fn get_color(&self) -> u16 {
+        return self.color;
+    }
     fn tick(&mut self, _effects: &effects::InputGameState) -> Option<effects::OutputEvents> {
         return None;
     }
@@ -13,6 +13,10 @@ pub fn default_bool() -> bool {
     false
 }
 
+pub fn default_true() -> bool {
+    true
+}
+
 pub fn default_u16() -> u16 {
     0
 }
@@ -30,6 +34,10 @@ pub fn positive_i16() -> i16 {
     1
 }
 
+pub fn negative_i16() -> i16 {
+    -1
+}
+
 
 pub fn default_fixed() -> Fixed {
     Fixed::new(0)
@@ -49,6 +57,10 @@ pub fn default_fixed_3_11() -> [[Fixed; 3]; 11] {
 }
 
 
+pub fn default_fixed_3_6() -> [[Fixed; 3]; 6] {
+    default_fixed_generic::<6, 3>()
+}
+
 pub fn default_fixed_3_8() -> [[Fixed; 3]; 8] {
     default_fixed_generic::<8, 3>()
 }
@@ -57,6 +69,10 @@ pub fn default_fixed_3_14() -> [[Fixed; 3]; 14] {
     default_fixed_generic::<14, 3>()
 }
 
+pub fn default_fixed_3_16() -> [[Fixed; 3]; 16] {
+    default_fixed_generic::<16, 3>()
+}
+
 pub const fn i32_to_fixed(m: i32) -> Fixed {
     return Fixed(Num::from_raw(m << 8));
 }
@@ -0,0 +1,234 @@
+//information drawn over the 3d view during play, such as the level timer
+//widgets are registered once when the level starts and updated every frame with set_*,
+//the 3d view repaints the whole page so widgets are drawn every frame, but their text is
+//only formatted again when the value shown changes
+
+extern crate alloc;
+use agb::InternalAllocator;
+use alloc::format;
+use alloc::vec::Vec;
+
+use crate::renderer;
+use crate::textengine;
+
+const SCREEN_WIDTH: u16 = 240;
+const SCREEN_HEIGHT: u16 = 160;
+const TILE_SIZE: u16 = 8;
+const TILE_ADVANCE: u16 = 12;
+const TEXT_COLOR: u16 = 46;
+const DIM_COLOR: u16 = 43;
+//switch indicator boxes, in pixels
+const BOX_SIZE: u16 = 6;
+const BOX_ADVANCE: u16 = 9;
+//most switches the indicator has room for
+pub const MAX_SWITCHES: usize = 16;
+//message box of signs, along the bottom of the screen like the pause menu box
+const MESSAGE_LEFT: u16 = 8;
+const MESSAGE_RIGHT: u16 = 232;
+const MESSAGE_BOTTOM: u16 = 152;
+const MESSAGE_PADDING: u16 = 6;
+const MESSAGE_WIDTH: u16 = MESSAGE_RIGHT - MESSAGE_LEFT - 2 * MESSAGE_PADDING;
+const MESSAGE_LINES: usize = 4;
+const MESSAGE_COLOR: u16 = 42;
+
+pub enum WidgetKind {
+    //time since the level started, the value is in frames
+    Timer,
+    //a number after a label, such as the attempt count
+    Counter(&'static str),
+    //a box per switch in the level, filled for the ones that are on
+    Switches,
+    //camera with an "a" while the camera follows the player and an "m" while it doesn't
+    CameraMode,
+}
+
+struct Widget {
+    kind: WidgetKind,
+    x: u16,
+    y: u16,
+    value: i32,
+    //switch states as bits, the lowest is the first switch
+    flags: u32,
+    count: u8,
+    //tiles of the text, rebuilt when dirty
+    tiles: Vec<u8>,
+    dirty: bool,
+    visible: bool,
+}
+
+pub struct Hud {
+    widgets: Vec<Widget, InternalAllocator>,
+    //sign message being shown and its wrapped lines, wrapped again only when another one is shown
+    message: Option<u16>,
+    message_lines: Vec<&'static str>,
+}
+
+impl Hud {
+    pub fn new() -> Self {
+        Self {
+            widgets: Vec::new_in(InternalAllocator),
+            message: None,
+            message_lines: Vec::new(),
+        }
+    }
+
+    //adds a widget with its top left corner at x, y and returns the id to update it with
+    pub fn add(&mut self, kind: WidgetKind, x: u16, y: u16) -> usize {
+        self.widgets.push(Widget {
+            kind,
+            x,
+            y,
+            value: 0,
+            flags: 0,
+            count: 0,
+            tiles: Vec::new(),
+            dirty: true,
+            visible: true,
+        });
+        return self.widgets.len() - 1;
+    }
+
+    pub fn set_value(&mut self, id: usize, value: i32) {
+        let widget = &mut self.widgets[id];
+        //the timer text only shows whole seconds
+        let changed = match widget.kind {
+            WidgetKind::Timer => value / 60 != widget.value / 60,
+            _ => value != widget.value,
+        };
+        widget.dirty |= changed;
+        widget.value = value;
+    }
+
+    pub fn set_visible(&mut self, id: usize, visible: bool) {
+        self.widgets[id].visible = visible;
+    }
+
+    pub fn set_flag(&mut self, id: usize, on: bool) {
+        self.set_value(id, on as i32);
+    }
+
+    //states of up to MAX_SWITCHES switches as bits, count is how many there are
+    pub fn set_switches(&mut self, id: usize, flags: u32, count: u8) {
+        let widget = &mut self.widgets[id];
+        widget.dirty |= flags != widget.flags || count != widget.count;
+        widget.flags = flags;
+        widget.count = count;
+    }
+
+    //index tells the messages apart, so the text is only wrapped when it changes
+    pub fn show_message(&mut self, index: u16, text: &'static str) {
+        if self.message == Some(index) {
+            return;
+        }
+        self.message = Some(index);
+        self.message_lines = textengine::draw::wrap(text, MESSAGE_WIDTH);
+        self.message_lines.truncate(MESSAGE_LINES);
+    }
+
+    pub fn hide_message(&mut self) {
+        self.message = None;
+    }
+
+    //the box grows upwards with the number of lines, text past MESSAGE_LINES lines is left out
+    pub fn draw_message(&self, page: u16) {
+        if self.message.is_none() {
+            return;
+        }
+        let lines = self.message_lines.len().clamp(1, MESSAGE_LINES) as u16;
+        let text_height = (lines - 1) * textengine::draw::LINE_HEIGHT + TILE_SIZE;
+        let top = MESSAGE_BOTTOM - text_height - 2 * MESSAGE_PADDING;
+        renderer::hw::fill_area(page, MESSAGE_COLOR, MESSAGE_LEFT, MESSAGE_RIGHT, top, MESSAGE_BOTTOM);
+        textengine::draw::write_lines(
+            MESSAGE_LEFT + MESSAGE_PADDING,
+            top + MESSAGE_PADDING,
+            MESSAGE_WIDTH,
+            text_height,
+            &self.message_lines,
+            textengine::draw::Align::Left,
+            TEXT_COLOR,
+            page,
+        );
+    }
+
+    pub fn draw(&mut self, page: u16) {
+        for widget in self.widgets.iter_mut() {
+            if !widget.visible {
+                continue;
+            }
+            if widget.dirty {
+                widget.tiles = match widget.kind {
+                    WidgetKind::Timer => {
+                        let seconds = widget.value / 60;
+                        textengine::chartoindex::convert_to_tiles(&format!(
+                            "{}:{:02}",
+                            seconds / 60,
+                            seconds % 60
+                        ))
+                    }
+                    WidgetKind::Counter(label) => textengine::chartoindex::convert_to_tiles(
+                        &format!("{} {}", label, widget.value),
+                    ),
+                    WidgetKind::CameraMode => textengine::chartoindex::convert_to_tiles(
+                        if widget.value != 0 { "a" } else { "m" },
+                    ),
+                    WidgetKind::Switches => Vec::new(),
+                };
+                widget.dirty = false;
+            }
+
+            match widget.kind {
+                WidgetKind::Switches => draw_switches(widget, page),
+                WidgetKind::CameraMode => {
+                    draw_camera_icon(widget.x, widget.y, page);
+                    draw_tiles(&widget.tiles, widget.x + TILE_ADVANCE, widget.y, page);
+                }
+                _ => draw_tiles(&widget.tiles, widget.x, widget.y, page),
+            }
+        }
+    }
+}
+
+//tiles that don't fit on the screen are left out
+fn draw_tiles(tiles: &[u8], x: u16, y: u16, page: u16) {
+    if y + TILE_SIZE > SCREEN_HEIGHT {
+        return;
+    }
+    for (i, &tile) in tiles.iter().enumerate() {
+        let tile_x = x + i as u16 * TILE_ADVANCE;
+        if tile_x + TILE_SIZE > SCREEN_WIDTH {
+            return;
+        }
+        textengine::draw::write_tile(tile_x, y, tile as usize, TEXT_COLOR, page);
+    }
+}
+
+fn draw_switches(widget: &Widget, page: u16) {
+    if widget.y + BOX_SIZE > SCREEN_HEIGHT {
+        return;
+    }
+    for i in 0..(widget.count as usize).min(MAX_SWITCHES) {
+        let x = widget.x + i as u16 * BOX_ADVANCE;
+        if x + BOX_SIZE > SCREEN_WIDTH {
+            return;
+        }
+        let y = widget.y;
+        if widget.flags & (1 << i) != 0 {
+            renderer::hw::fill_area(page, TEXT_COLOR, x, x + BOX_SIZE, y, y + BOX_SIZE);
+        } else {
+            renderer::hw::fill_area(page, DIM_COLOR, x, x + BOX_SIZE, y, y + 1);
+            renderer::hw::fill_area(page, DIM_COLOR, x, x + BOX_SIZE, y + BOX_SIZE - 1, y + BOX_SIZE);
+            renderer::hw::fill_area(page, DIM_COLOR, x, x + 1, y, y + BOX_SIZE);
+            renderer::hw::fill_area(page, DIM_COLOR, x + BOX_SIZE - 1, x + BOX_SIZE, y, y + BOX_SIZE);
+        }
+    }
+}
+
+//a box with a lens on the front, 10 by 8 pixels
+fn draw_camera_icon(x: u16, y: u16, page: u16) {
+    if x + 10 > SCREEN_WIDTH || y + TILE_SIZE > SCREEN_HEIGHT {
+        return;
+    }
+    renderer::hw::fill_area(page, TEXT_COLOR, x, x + 7, y + 2, y + 8);
+    renderer::hw::fill_area(page, TEXT_COLOR, x + 7, x + 10, y + 3, y + 7);
+    renderer::hw::fill_area(page, TEXT_COLOR, x + 1, x + 4, y, y + 2);
+}
@@ -0,0 +1,131 @@
+//scripted input for the attract mode, plays the first level by itself
+//it steers towards the platforms of the level in the order they are listed, then to the finish,
+//jumping the gaps and pressing the buttons of the mapping so the demo goes through handle_input
+//like a player would
+
+extern crate alloc;
+use agb::input::Button;
+use alloc::vec::Vec;
+
+use super::mapping::{Action, Mapping};
+use super::Buttons;
+use crate::entities::EntityEnum;
+use crate::fixed::Fixed;
+use crate::math::vector_len_2d;
+use crate::player::Player;
+//a jump from the ground is this long, so it is started this far from the next platform
+const JUMP_DISTANCE: Fixed = Fixed::const_new(4);
+//closer than this the player is on the platform already and walks the rest
+const WALK_DISTANCE: Fixed = Fixed::const_new(2);
+//the next platform is the target once the player is this close to the center
+const ARRIVE_DISTANCE: Fixed = Fixed::const_new(1);
+//frames jump is held for, enough for the highest jump
+const JUMP_FRAMES: u16 = 10;
+
+//the d-pad buttons for each direction, with the offset from the camera yaw they turn the player to
+//see Player::forward and the others
+const DIRECTIONS: [(usize, Action, Option<Action>); 8] = [
+    (0, Action::MoveLeft, None),
+    (32, Action::MoveUp, Some(Action::MoveLeft)),
+    (64, Action::MoveUp, None),
+    (96, Action::MoveUp, Some(Action::MoveRight)),
+    (128, Action::MoveRight, None),
+    (160, Action::MoveDown, Some(Action::MoveRight)),
+    (192, Action::MoveDown, None),
+    (224, Action::MoveDown, Some(Action::MoveLeft)),
+];
+
+pub struct DemoInput {
+    current: Button,
+    previous: Button,
+    //centers of the solid rectangles and the finish, x and z
+    path: Vec<[Fixed; 2]>,
+    //index in path the player is heading to
+    target: usize,
+    jump_frames_left: u16,
+}
+
+impl Buttons for DemoInput {
+    fn is_pressed(&self, buttons: Button) -> bool {
+        return !(self.current & buttons).is_empty();
+    }
+
+    fn is_just_pressed(&self, buttons: Button) -> bool {
+        return !(self.current & buttons).is_empty() && (self.previous & buttons).is_empty();
+    }
+}
+
+impl DemoInput {
+    //entities are the loaded level, without the player
+    pub fn new(entities: &[EntityEnum]) -> Self {
+        let mut path = Vec::new();
+        let mut finish = None;
+        for entity in entities {
+            match entity {
+                EntityEnum::Rectangle(rectangle) if rectangle.is_collidable() => {
+                    let cylinder = entity.bounding_cylinder();
+                    path.push([cylinder.x, cylinder.z]);
+                }
+                EntityEnum::Finish(portal) => {
+                    let (x, z) = portal.position();
+                    finish = Some([x, z]);
+                }
+                _ => {}
+            }
+        }
+        if let Some(finish) = finish {
+            path.push(finish);
+        }
+        Self {
+            current: Button::empty(),
+            previous: Button::empty(),
+            path,
+            target: 0,
+            jump_frames_left: 0,
+        }
+    }
+
+    //picks the buttons of this frame from where the player is, call it where input.update would be
+    pub fn update(&mut self, player: &Player, controls: &Mapping) {
+        self.previous = self.current;
+        self.current = Button::empty();
+
+        if self.path.is_empty() {
+            return;
+        }
+        let mut offset = [self.path[self.target][0] - player.x, self.path[self.target][1] - player.z];
+        if vector_len_2d(offset) < ARRIVE_DISTANCE && self.target + 1 < self.path.len() {
+            self.target += 1;
+            offset = [self.path[self.target][0] - player.x, self.path[self.target][1] - player.z];
+        }
+        let distance = vector_len_2d(offset);
+
+        //the direction that points the most towards the target
+        let mut best = DIRECTIONS[0];
+        let mut best_dot = Fixed::const_new(-100);
+        for direction in DIRECTIONS {
+            let angle = Fixed::from_raw(((player.camera_yaw() + direction.0) % 256) as i32);
+            let dot = angle.cos() * offset[0] + angle.sin() * offset[1];
+            if dot > best_dot {
+                best_dot = dot;
+                best = direction;
+            }
+        }
+        self.current |= controls.buttons(best.1);
+        if let Some(action) = best.2 {
+            self.current |= controls.buttons(action);
+        }
+
+        if player.is_grounded()
+            && self.jump_frames_left == 0
+            && distance > WALK_DISTANCE
+            && distance <= JUMP_DISTANCE
+        {
+            self.jump_frames_left = JUMP_FRAMES;
+        }
+        if self.jump_frames_left > 0 {
+            self.jump_frames_left -= 1;
+            self.current |= controls.buttons(Action::Jump);
+        }
+    }
+}
@@ -0,0 +1,261 @@
+//maps what the player wants to do to the buttons that do it
+//the game and the menus ask for actions instead of buttons, so the controls can be changed
+//in the options menu, either to one of the presets or one action at a time
+
+extern crate alloc;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use agb::input::{Button, ButtonController};
+
+use super::Buttons;
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum Action {
+    //the d-pad, also used to move around the menus
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    Jump,
+    //flips switches, dashes, and ground pounds together with down
+    Use,
+    CameraLeft,
+    CameraRight,
+    CameraMode,
+    Pause,
+    //half speed while held, no button has it unless the player gives it one
+    Precision,
+    //menus only
+    Confirm,
+    Back,
+}
+
+const ACTION_COUNT: usize = 13;
+
+//the actions that can be changed one by one, movement and the menu buttons always stay the same
+//so the menus can't become unusable
+pub const REMAPPABLE: [Action; 7] = [
+    Action::Jump,
+    Action::Use,
+    Action::CameraLeft,
+    Action::CameraRight,
+    Action::CameraMode,
+    Action::Pause,
+    Action::Precision,
+];
+
+//actions that must always have a button, without them a level can't be played or left
+const REQUIRED: [Action; 3] = [Action::Jump, Action::Use, Action::Pause];
+
+//buttons an action can be changed to, the d-pad is left out as it is always used for moving
+const BINDABLE: [Button; 6] = [
+    Button::A,
+    Button::B,
+    Button::L,
+    Button::R,
+    Button::SELECT,
+    Button::START,
+];
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum Preset {
+    Standard,
+    //B jumps and A is the use button
+    Swapped,
+    //jumping and using on the shoulder buttons, the camera turns with B and A
+    Shoulder,
+    //the shoulder layout mirrored, jumping with L
+    LeftHanded,
+}
+
+pub const PRESETS: [Preset; 4] = [Preset::Standard, Preset::Swapped, Preset::Shoulder, Preset::LeftHanded];
+
+impl Preset {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Preset::Standard => "standard",
+            Preset::Swapped => "swapped",
+            Preset::Shoulder => "shoulder",
+            Preset::LeftHanded => "left hand",
+        }
+    }
+}
+
+pub fn action_name(action: Action) -> &'static str {
+    match action {
+        Action::MoveUp => "up",
+        Action::MoveDown => "down",
+        Action::MoveLeft => "left",
+        Action::MoveRight => "right",
+        Action::Jump => "jump",
+        Action::Use => "switch, dash",
+        Action::CameraLeft => "camera left",
+        Action::CameraRight => "camera right",
+        Action::CameraMode => "camera mode",
+        Action::Pause => "pause",
+        Action::Precision => "precision",
+        Action::Confirm => "ok",
+        Action::Back => "back",
+    }
+}
+
+//the button glyphs of the font, buttons without one are written out
+pub fn button_name(buttons: Button) -> String {
+    if buttons.is_empty() {
+        return String::from("none");
+    }
+    let names = [
+        (Button::A, "Ⓐ"),
+        (Button::B, "Ⓑ"),
+        (Button::L, "Ⓛ"),
+        (Button::R, "Ⓡ"),
+        (Button::UP, "↑"),
+        (Button::DOWN, "↓"),
+        (Button::LEFT, "←"),
+        (Button::RIGHT, "→"),
+        (Button::SELECT, "select"),
+        (Button::START, "start"),
+    ];
+    let parts: Vec<&str> = names
+        .iter()
+        .filter(|(button, _)| buttons.contains(*button))
+        .map(|(_, name)| *name)
+        .collect();
+    return parts.join("/");
+}
+
+//the button pressed on this frame that an action can be changed to, for the controls menu
+pub fn just_pressed_bindable(input: &ButtonController) -> Option<Button> {
+    return BINDABLE.iter().copied().find(|&button| input.is_just_pressed(button));
+}
+
+#[derive(Copy, Clone, PartialEq)]
+pub struct Mapping {
+    buttons: [Button; ACTION_COUNT],
+}
+
+impl Mapping {
+    pub fn default() -> Self {
+        return Self::preset(Preset::Standard);
+    }
+
+    pub fn preset(preset: Preset) -> Self {
+        let (jump, use_button, camera_left, camera_right) = match preset {
+            Preset::Standard => (Button::A, Button::B, Button::L, Button::R),
+            Preset::Swapped => (Button::B, Button::A, Button::L, Button::R),
+            Preset::Shoulder => (Button::R, Button::L, Button::B, Button::A),
+            Preset::LeftHanded => (Button::L, Button::R, Button::B, Button::A),
+        };
+        Self {
+            buttons: [
+                Button::UP,
+                Button::DOWN,
+                Button::LEFT,
+                Button::RIGHT,
+                jump,
+                use_button,
+                camera_left,
+                camera_right,
+                Button::SELECT,
+                Button::START,
+                Button::empty(),
+                Button::A | Button::START,
+                Button::B,
+            ],
+        }
+    }
+
+    //the preset this mapping is, None if actions were changed one by one
+    pub fn matching_preset(&self) -> Option<Preset> {
+        return PRESETS.iter().copied().find(|&preset| Self::preset(preset) == *self);
+    }
+
+    pub fn buttons(&self, action: Action) -> Button {
+        return self.buttons[action as usize];
+    }
+
+    //an action that used the button gets the old button of this one, so the buttons are swapped
+    //giving precision a button leaves the action that had it without one, unless that action
+    //can't be without one, then it moves to a free button or the change is refused
+    //returns false if the mapping wasn't changed
+    pub fn bind(&mut self, action: Action, button: Button) -> bool {
+        let old = self.buttons[action as usize];
+        let mut replacement = old;
+        let displaced = REMAPPABLE
+            .iter()
+            .copied()
+            .find(|&other| other != action && self.buttons[other as usize] == button);
+        if let Some(displaced) = displaced {
+            if old.is_empty() && REQUIRED.contains(&displaced) {
+                match self.free_button() {
+                    Some(free) => replacement = free,
+                    None => return false,
+                }
+            }
+            self.buttons[displaced as usize] = replacement;
+        }
+        self.buttons[action as usize] = button;
+        return true;
+    }
+
+    //a bindable button no remappable action has
+    fn free_button(&self) -> Option<Button> {
+        return BINDABLE
+            .iter()
+            .copied()
+            .find(|&button| REMAPPABLE.iter().all(|&action| self.buttons[action as usize] != button));
+    }
+
+    pub fn is_pressed(&self, input: &impl Buttons, action: Action) -> bool {
+        return input.is_pressed(self.buttons(action));
+    }
+
+    pub fn is_just_pressed(&self, input: &impl Buttons, action: Action) -> bool {
+        return input.is_just_pressed(self.buttons(action));
+    }
+
+    //a line for the keys screen, like "Ⓐ jump", or "select: camera mode" for written out buttons
+    pub fn describe(&self, action: Action) -> String {
+        let name = button_name(self.buttons(action));
+        if name.chars().count() > 1 {
+            return format!("{}: {}", name, action_name(action));
+        }
+        return format!("{} {}", name, action_name(action));
+    }
+
+    //two bytes per remappable action, for the save
+    pub fn to_bytes(self) -> [u8; 2 * REMAPPABLE.len()] {
+        let mut bytes = [0; 2 * REMAPPABLE.len()];
+        for (i, action) in REMAPPABLE.iter().enumerate() {
+            let bits = self.buttons(*action).bits() as u16;
+            bytes[2 * i..2 * i + 2].copy_from_slice(&bits.to_le_bytes());
+        }
+        return bytes;
+    }
+
+    //the default mapping if a stored button isn't one an action can have
+    //or two actions share a button, actions can be stored without one unless they are required
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let mut mapping = Self::default();
+        if bytes.len() < 2 * REMAPPABLE.len() {
+            return mapping;
+        }
+        let mut used = Button::empty();
+        for (i, action) in REMAPPABLE.iter().enumerate() {
+            let bits = u16::from_le_bytes([bytes[2 * i], bytes[2 * i + 1]]) as u32;
+            match Button::from_bits(bits) {
+                Some(button) if button.is_empty() && !REQUIRED.contains(action) => {
+                    mapping.buttons[*action as usize] = button
+                }
+                Some(button) if BINDABLE.contains(&button) && !used.contains(button) => {
+                    mapping.buttons[*action as usize] = button;
+                    used |= button;
+                }
+                _ => return Self::default(),
+            }
+        }
+        return mapping;
+    }
+}
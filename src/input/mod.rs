@@ -0,0 +1,167 @@
+use crate::{
+    options::Options, player, utils::GameState, Fixed
+};
+use agb::input::{Button, ButtonController};
+use player::*;
+
+pub mod demo;
+pub mod mapping;
+use mapping::Action;
+
+//where the pressed buttons come from, the hardware or the scripted demo
+pub trait Buttons {
+    fn is_pressed(&self, buttons: Button) -> bool;
+    fn is_just_pressed(&self, buttons: Button) -> bool;
+}
+
+impl Buttons for ButtonController {
+    fn is_pressed(&self, buttons: Button) -> bool {
+        return ButtonController::is_pressed(self, buttons);
+    }
+
+    fn is_just_pressed(&self, buttons: Button) -> bool {
+        return ButtonController::is_just_pressed(self, buttons);
+    }
+}
+
+pub fn handle_input(
+    player: &mut Player,
+    input: &impl Buttons,
+    game_state: GameState,
+    options: &Options,
+) -> GameState {
+    let mut new_game_state = game_state;
+    let controls = &options.mapping;
+    let pressed = |action: Action| controls.is_pressed(input, action);
+    let pitch = if options.invert_camera { -1 } else { 1 };
+    //holding both camera buttons together turns the d-pad into pitch and zoom controls
+    let adjusting_camera = pressed(Action::CameraLeft) && pressed(Action::CameraRight);
+    if adjusting_camera {
+        if pressed(Action::MoveUp) {
+            player.camera_pitch(pitch);
+        } else if pressed(Action::MoveDown) {
+            player.camera_pitch(-pitch);
+        }
+        if pressed(Action::MoveLeft) {
+            player.camera_zoom(-Fixed::from_raw(16));
+        } else if pressed(Action::MoveRight) {
+            player.camera_zoom(Fixed::from_raw(16));
+        }
+    } else if pressed(Action::CameraLeft) != pressed(Action::CameraRight) {
+        let speed = options.camera_speed as usize;
+        if pressed(Action::CameraLeft) != options.invert_camera {
+            player.camera_left(speed);
+        } else {
+            player.camera_right(speed);
+        }
+    }
+
+    // if input.is_just_pressed(Button::A) {
+    //     player.jump();
+    // }
+    if pressed(Action::Jump) {
+        player.try_jumping();
+    }
+    else {
+        player.cancel_jump();
+    }
+
+    if controls.is_just_pressed(input, Action::CameraMode) {
+        player.autorotate_camera = !player.autorotate_camera;
+    }
+
+    //the action button with down pressed in the air is a ground pound, otherwise it flips switches or dashes
+    if controls.is_just_pressed(input, Action::Use)
+        && !(pressed(Action::MoveDown) && player.ground_pound())
+    {
+        player.action = true;
+    }
+
+    if controls.is_just_pressed(input, Action::Pause) {
+        new_game_state = GameState::Paused;
+    }
+
+    player.precision = pressed(Action::Precision);
+
+
+    if adjusting_camera {
+        player.move_toward(Fixed::const_new(0), Fixed::const_new(0));
+    }
+    else if pressed(Action::MoveUp) && pressed(Action::MoveLeft) {
+        let (x, z) = player.forward_left();
+        player.move_toward(x, z);
+        /*
+
+        if attempt_move(player, x, z, entities, body) {
+            return;
+        }*/
+    }
+    else if pressed(Action::MoveDown) && pressed(Action::MoveLeft) {
+        let (x, z) = player.back_left();
+        player.move_toward(x, z);
+        /*
+        if attempt_move(player, x, z, entities, body) {
+            return;
+        }
+        */
+    }
+    else if pressed(Action::MoveUp) && pressed(Action::MoveRight) {
+        let (x, z) = player.forward_right();
+        player.move_toward(x, z);
+        /*
+        if attempt_move(player, x, z, entities, body) {
+            return;
+        }
+        */
+    }
+    else if pressed(Action::MoveDown) && pressed(Action::MoveRight) {
+        let (x, z) = player.back_right();
+        player.move_toward(x, z);
+        /*
+        if attempt_move(player, x, z, entities, body) {
+            return;
+        }
+        */
+    }
+    else if pressed(Action::MoveUp) {
+        let (x, z) = player.forward();
+
+        player.move_toward(x, z);
+        /*
+        if attempt_move(player, x, z, entities, body) {
+            return;
+        }
+        */
+    }
+    else if pressed(Action::MoveDown) {
+        let (x, z) = player.back();
+        player.move_toward(x, z);
+        /*
+        if attempt_move(player, x, z, entities, body) {
+            return;
+        }
+        */
+    }
+    else if pressed(Action::MoveLeft) {
+        let (x, z) = player.left();
+        player.move_toward(x, z);
+        /*
+        if attempt_move(player, x, z, entities, body) {
+            return;
+        }
+        */
+    }
+    else if pressed(Action::MoveRight) {
+        let (x, z) = player.right();
+        player.move_toward(x, z);
+        /*
+        if attempt_move(player, x, z, entities, body) {
+            return;
+        }
+        */
+    }
+    else {
+        player.move_toward(Fixed::const_new(0), Fixed::const_new(0));
+    }
+    return new_game_state
+}
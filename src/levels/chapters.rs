@@ -0,0 +1,94 @@
+//chapters of the world map and the rules that unlock them and their levels
+//the chapters are listed in config/chapters.json and a level names its chapter in its settings,
+//scripts/bundle_levels.py turns both into the tables in levelstore
+
+use alloc::format;
+use alloc::string::String;
+
+use super::levelstore::{BONUS_LEVELS, CHAPTERS, LEVELS, LEVEL_CHAPTERS, LEVEL_GEMS};
+use crate::save::LevelProgress;
+
+pub struct Chapter {
+    pub name: &'static str,
+    //the chapter opens when either count is reached, a count of 0 isn't a rule
+    //and a chapter without rules is open from the start
+    pub unlock_levels: u8,
+    pub unlock_gems: u8,
+}
+
+//finished levels, bonus levels don't count
+pub fn completed_count(progress: &[LevelProgress]) -> usize {
+    return (0..LEVELS.len())
+        .filter(|&level| !BONUS_LEVELS[level] && progress[level].completed)
+        .count();
+}
+
+pub fn gem_count(progress: &[LevelProgress]) -> usize {
+    return progress.iter().map(|level| level.gem_count()).sum();
+}
+
+//every level that isn't a bonus level finished, with all of its gems
+pub fn fully_completed(progress: &[LevelProgress]) -> bool {
+    return (0..LEVELS.len()).all(|level| {
+        BONUS_LEVELS[level]
+            || (progress[level].completed && progress[level].gem_count() >= LEVEL_GEMS[level] as usize)
+    });
+}
+
+pub fn chapter_unlocked(chapter: usize, progress: &[LevelProgress]) -> bool {
+    let rules = &CHAPTERS[chapter];
+    if rules.unlock_levels == 0 && rules.unlock_gems == 0 {
+        return true;
+    }
+    return (rules.unlock_levels > 0 && completed_count(progress) >= rules.unlock_levels as usize)
+        || (rules.unlock_gems > 0 && gem_count(progress) >= rules.unlock_gems as usize);
+}
+
+//the levels of a chapter open one after the other, bonus levels when everything else is done
+//a finished level stays open, so old saves keep what they had
+pub fn level_unlocked(level: usize, progress: &[LevelProgress]) -> bool {
+    if progress[level].completed {
+        return true;
+    }
+    let chapter = LEVEL_CHAPTERS[level];
+    if !chapter_unlocked(chapter, progress) {
+        return false;
+    }
+    if BONUS_LEVELS[level] {
+        return fully_completed(progress);
+    }
+    match previous_level(level) {
+        Some(previous) => progress[previous].completed,
+        None => true,
+    }
+}
+
+//the level before this one in its chapter, leaving out bonus levels
+fn previous_level(level: usize) -> Option<usize> {
+    return (0..level)
+        .rev()
+        .find(|&i| LEVEL_CHAPTERS[i] == LEVEL_CHAPTERS[level] && !BONUS_LEVELS[i]);
+}
+
+pub fn chapter_levels(chapter: usize) -> impl Iterator<Item = usize> {
+    return (0..LEVELS.len()).filter(move |&level| LEVEL_CHAPTERS[level] == chapter);
+}
+
+//what is still needed to open a locked level, shown on the world map
+pub fn unlock_hint(level: usize, progress: &[LevelProgress]) -> String {
+    let chapter = &CHAPTERS[LEVEL_CHAPTERS[level]];
+    if !chapter_unlocked(LEVEL_CHAPTERS[level], progress) {
+        let levels_left = (chapter.unlock_levels as usize).saturating_sub(completed_count(progress));
+        let gems_left = (chapter.unlock_gems as usize).saturating_sub(gem_count(progress));
+        if chapter.unlock_gems == 0 {
+            return format!("finish {} more", levels_left);
+        } else if chapter.unlock_levels == 0 {
+            return format!("find {} more gems", gems_left);
+        }
+        return format!("finish {} or find {} gems", levels_left, gems_left);
+    }
+    if BONUS_LEVELS[level] {
+        return String::from("finish all with every gem");
+    }
+    return String::from("finish the one before");
+}
@@ -1,19 +1,123 @@
 // DO NOT EDIT: This file was automatically generated by bundle_levels.py.
 // Any modifications will be overwritten during the next build.
 
+use super::chapters::Chapter;
 
 
 pub const LEVELSIZE: usize = 19;
 
 const LEVEL1: &str = r#"[
-    { "type": "rectangle", "data": { "xsize": 3, "ysize": 1, "zsize": 3, "x": 0.0, "y": 0, "z": 0, "color": 6, "rotation": 0.0 } },
-    { "type": "rectangle", "data": { "xsize": 3, "ysize": 1, "zsize": 3, "x": -0.65, "y": 0, "z": 5, "color": 6, "rotation": -0.04 } },
-    { "type": "rectangle", "data": { "xsize": 3, "ysize": 1, "zsize": 3, "x": -2.56, "y": 0, "z": 9.5, "color": 6, "rotation": -0.08 } },
-    { "type": "rectangle", "data": { "xsize": 3, "ysize": 1, "zsize": 3, "x": -5.6, "y": 0, "z": 13.5, "color": 6, "rotation": -0.12 } },
-    { "type": "rectangle", "data": { "xsize": 3, "ysize": 1, "zsize": 3, "x": -9.5, "y": 0, "z": 16.5, "color": 6, "rotation": -0.16 } },
-    { "type": "rectangle", "data": { "xsize": 3, "ysize": 1, "zsize": 3, "x": -14.5, "y": 0, "z": 18.5, "color": 6, "rotation": -0.18 } },
-    { "type": "rectangle", "data": { "xsize": 3, "ysize": 1, "zsize": 3, "x": -19, "y": 0, "z": 19, "color": 6, "rotation": -0.22 } },
-    { "type": "finish", "data": {"size": 3, "x": -21, "y": 3, "z": 20, "color": 2, "rotation": -0.22 } }
+    {
+        "type": "rectangle",
+        "data": {
+            "xsize": 3,
+            "ysize": 1,
+            "zsize": 3,
+            "x": 0.0,
+            "y": 0,
+            "z": 0,
+            "color": 6,
+            "rotation": 0.0
+        }
+    },
+    {
+        "type": "rectangle",
+        "data": {
+            "xsize": 3,
+            "ysize": 1,
+            "zsize": 3,
+            "x": -0.65,
+            "y": 0,
+            "z": 5,
+            "color": 6,
+            "rotation": -0.04
+        }
+    },
+    {
+        "type": "rectangle",
+        "data": {
+            "xsize": 3,
+            "ysize": 1,
+            "zsize": 3,
+            "x": -2.56,
+            "y": 0,
+            "z": 9.5,
+            "color": 6,
+            "rotation": -0.08
+        }
+    },
+    {
+        "type": "rectangle",
+        "data": {
+            "xsize": 3,
+            "ysize": 1,
+            "zsize": 3,
+            "x": -5.6,
+            "y": 0,
+            "z": 13.5,
+            "color": 6,
+            "rotation": -0.12
+        }
+    },
+    {
+        "type": "rectangle",
+        "data": {
+            "xsize": 3,
+            "ysize": 1,
+            "zsize": 3,
+            "x": -9.5,
+            "y": 0,
+            "z": 16.5,
+            "color": 6,
+            "rotation": -0.16
+        }
+    },
+    {
+        "type": "rectangle",
+        "data": {
+            "xsize": 3,
+            "ysize": 1,
+            "zsize": 3,
+            "x": -14.5,
+            "y": 0,
+            "z": 18.5,
+            "color": 6,
+            "rotation": -0.18
+        }
+    },
+    {
+        "type": "rectangle",
+        "data": {
+            "xsize": 3,
+            "ysize": 1,
+            "zsize": 3,
+            "x": -19,
+            "y": 0,
+            "z": 19,
+            "color": 6,
+            "rotation": -0.22
+        }
+    },
+    {
+        "type": "finish",
+        "data": {
+            "size": 3,
+            "x": -21,
+            "y": 3,
+            "z": 20,
+            "color": 2,
+            "rotation": -0.22
+        }
+    },
+    {
+        "type": "gem",
+        "data": {
+            "x": -5.6,
+            "y": 1.25,
+            "z": 13.5,
+            "gem": 0
+        }
+    }
 ]"#;
 
 const LEVEL2: &str = r#"[
@@ -191,29 +295,174 @@ const LEVEL2: &str = r#"[
             "color": 2,
             "rotation": 0
         }
+    },
+    {
+        "type": "sign",
+        "data": {
+            "x": -1.75,
+            "y": 1.25,
+            "z": 0,
+            "radius": 2.5,
+            "color": 7,
+            "message": 0
+        }
+    },
+    {
+        "type": "gem",
+        "data": {
+            "x": 10,
+            "y": 1.25,
+            "z": 17,
+            "gem": 0
+        }
     }
-]
-"#;
+]"#;
 
 const LEVEL3: &str = r#"[
-    { "type": "rectangle", "data": { "xsize": 3, "ysize": 1, "zsize": 3, "x": 0.0, "y": 0, "z": 0, "color": 6, "rotation": 0.0 } },
-    { "type": "rectangle", "data": { "xsize": 3, "ysize": 1, "zsize": 3, "x": 4.0, "y": 3.5, "z": 0, "color": 6, "rotation": 0.0 } },
-    { "type": "rectangle", "data": { "xsize": 3, "ysize": 1, "zsize": 3, "x": 0.0, "y": 7, "z": 0, "color": 6, "rotation": 0.0 } },
-    { "type": "rectangle", "data": { "xsize": 3, "ysize": 1, "zsize": 3, "x": 4.0, "y": 10.5, "z": 0, "color": 6, "rotation": 0.0 } },
-    {"type": "mover",
-        "data": { 
-            "xsize": 4, "ysize": 1, "zsize": 4, "x": 8, "y": 10.5, "z": 0,
-            "pos_a_x": 8, "pos_a_y": 10.5, "pos_a_z": 0,
-            "pos_b_x": 28, "pos_b_y": 15, "pos_b_z": 7,
-            "speed": 2, "wait": 20, "color": 9
-        }
-    },
-    { "type": "rectangle", "data": { "xsize": 3, "ysize": 1, "zsize": 3, "x": 32, "y": 15, "z": 7, "color": 6, "rotation": 0.0 } },
-    { "type": "rectangle", "data": { "xsize": 3, "ysize": 1, "zsize": 3, "x": 28, "y": 18.5, "z": 7, "color": 6, "rotation": 0.0 } },
-    { "type": "rectangle", "data": { "xsize": 3, "ysize": 1, "zsize": 3, "x": 32, "y": 22, "z": 7, "color": 6, "rotation": 0.0 } },
-    { "type": "rectangle", "data": { "xsize": 3, "ysize": 1, "zsize": 3, "x": 28, "y": 25.5, "z": 7, "color": 6, "rotation": 0.0 } },
-    { "type": "finish", "data": {"size": 3, "x": 26.5, "y": 28.5, "z": 7, "color": 2, "rotation": -0.22 } }
-
+    {
+        "type": "rectangle",
+        "data": {
+            "xsize": 3,
+            "ysize": 1,
+            "zsize": 3,
+            "x": 0.0,
+            "y": 0,
+            "z": 0,
+            "color": 6,
+            "rotation": 0.0
+        }
+    },
+    {
+        "type": "rectangle",
+        "data": {
+            "xsize": 3,
+            "ysize": 1,
+            "zsize": 3,
+            "x": 4.0,
+            "y": 3.5,
+            "z": 0,
+            "color": 6,
+            "rotation": 0.0
+        }
+    },
+    {
+        "type": "rectangle",
+        "data": {
+            "xsize": 3,
+            "ysize": 1,
+            "zsize": 3,
+            "x": 0.0,
+            "y": 7,
+            "z": 0,
+            "color": 6,
+            "rotation": 0.0
+        }
+    },
+    {
+        "type": "rectangle",
+        "data": {
+            "xsize": 3,
+            "ysize": 1,
+            "zsize": 3,
+            "x": 4.0,
+            "y": 10.5,
+            "z": 0,
+            "color": 6,
+            "rotation": 0.0
+        }
+    },
+    {
+        "type": "mover",
+        "data": {
+            "xsize": 4,
+            "ysize": 1,
+            "zsize": 4,
+            "x": 8,
+            "y": 10.5,
+            "z": 0,
+            "pos_a_x": 8,
+            "pos_a_y": 10.5,
+            "pos_a_z": 0,
+            "pos_b_x": 28,
+            "pos_b_y": 15,
+            "pos_b_z": 7,
+            "speed": 2,
+            "wait": 20,
+            "color": 9
+        }
+    },
+    {
+        "type": "rectangle",
+        "data": {
+            "xsize": 3,
+            "ysize": 1,
+            "zsize": 3,
+            "x": 32,
+            "y": 15,
+            "z": 7,
+            "color": 6,
+            "rotation": 0.0
+        }
+    },
+    {
+        "type": "rectangle",
+        "data": {
+            "xsize": 3,
+            "ysize": 1,
+            "zsize": 3,
+            "x": 28,
+            "y": 18.5,
+            "z": 7,
+            "color": 6,
+            "rotation": 0.0
+        }
+    },
+    {
+        "type": "rectangle",
+        "data": {
+            "xsize": 3,
+            "ysize": 1,
+            "zsize": 3,
+            "x": 32,
+            "y": 22,
+            "z": 7,
+            "color": 6,
+            "rotation": 0.0
+        }
+    },
+    {
+        "type": "rectangle",
+        "data": {
+            "xsize": 3,
+            "ysize": 1,
+            "zsize": 3,
+            "x": 28,
+            "y": 25.5,
+            "z": 7,
+            "color": 6,
+            "rotation": 0.0
+        }
+    },
+    {
+        "type": "finish",
+        "data": {
+            "size": 3,
+            "x": 26.5,
+            "y": 28.5,
+            "z": 7,
+            "color": 2,
+            "rotation": -0.22
+        }
+    },
+    {
+        "type": "gem",
+        "data": {
+            "x": 4.0,
+            "y": 11.75,
+            "z": 0,
+            "gem": 0
+        }
+    }
 ]"#;
 
 const LEVEL4: &str = r#"[
@@ -240,7 +489,8 @@ const LEVEL4: &str = r#"[
             "y": 0,
             "z": 5,
             "rotation": 0,
-            "lifetime": 25, "color": 7
+            "lifetime": 25,
+            "color": 7
         }
     },
     {
@@ -253,7 +503,8 @@ const LEVEL4: &str = r#"[
             "y": 0,
             "z": 10,
             "rotation": 0,
-            "lifetime": 25, "color": 7
+            "lifetime": 25,
+            "color": 7
         }
     },
     {
@@ -266,7 +517,8 @@ const LEVEL4: &str = r#"[
             "y": 0,
             "z": 15,
             "rotation": 0,
-            "lifetime": 25, "color": 7
+            "lifetime": 25,
+            "color": 7
         }
     },
     {
@@ -279,7 +531,8 @@ const LEVEL4: &str = r#"[
             "y": 0,
             "z": 20,
             "rotation": 0,
-            "lifetime": 25, "color": 7
+            "lifetime": 25,
+            "color": 7
         }
     },
     {
@@ -340,9 +593,27 @@ const LEVEL4: &str = r#"[
             "color": 2,
             "rotation": 0.25
         }
+    },
+    {
+        "type": "sign",
+        "data": {
+            "x": -1,
+            "y": 1.25,
+            "z": -1,
+            "color": 7,
+            "message": 0
+        }
+    },
+    {
+        "type": "gem",
+        "data": {
+            "x": 0,
+            "y": 1.25,
+            "z": 25,
+            "gem": 0
+        }
     }
-]
-"#;
+]"#;
 
 const LEVEL5: &str = r#"[
     {
@@ -358,7 +629,6 @@ const LEVEL5: &str = r#"[
             "rotation": 0.0
         }
     },
-
     {
         "type": "wireframe",
         "data": {
@@ -373,7 +643,6 @@ const LEVEL5: &str = r#"[
             "rotation": 0.125
         }
     },
-
     {
         "type": "bounce",
         "data": {
@@ -387,7 +656,6 @@ const LEVEL5: &str = r#"[
             "rotation": 0.0
         }
     },
-
     {
         "type": "rectangle",
         "data": {
@@ -401,7 +669,6 @@ const LEVEL5: &str = r#"[
             "rotation": 0.0
         }
     },
-
     {
         "type": "rectangle",
         "data": {
@@ -415,7 +682,6 @@ const LEVEL5: &str = r#"[
             "rotation": 0.125
         }
     },
-
     {
         "type": "rectangle",
         "data": {
@@ -452,7 +718,6 @@ const LEVEL5: &str = r#"[
             "rotation": 0.25
         }
     },
-
     {
         "type": "wireframe",
         "data": {
@@ -467,7 +732,6 @@ const LEVEL5: &str = r#"[
             "rotation": 0.0
         }
     },
-
     {
         "type": "rectangle",
         "data": {
@@ -481,7 +745,6 @@ const LEVEL5: &str = r#"[
             "rotation": 0.0
         }
     },
-
     {
         "type": "mover",
         "data": {
@@ -502,7 +765,6 @@ const LEVEL5: &str = r#"[
             "color": 9
         }
     },
-
     {
         "type": "rectangle",
         "data": {
@@ -526,9 +788,27 @@ const LEVEL5: &str = r#"[
             "color": 2,
             "rotation": 0.25
         }
+    },
+    {
+        "type": "sign",
+        "data": {
+            "x": -1,
+            "y": 1.25,
+            "z": -1,
+            "color": 7,
+            "message": 0
+        }
+    },
+    {
+        "type": "gem",
+        "data": {
+            "x": 0,
+            "y": 8.25,
+            "z": 25,
+            "gem": 0
+        }
     }
-]
-"#;
+]"#;
 
 const LEVEL6: &str = r#"[
     {
@@ -609,7 +889,6 @@ const LEVEL6: &str = r#"[
             "rotation": 0.0
         }
     },
-
     {
         "type": "rectangle",
         "data": {
@@ -622,7 +901,6 @@ const LEVEL6: &str = r#"[
             "rotation": 0.125,
             "lifetime": 10,
             "color": 6
-
         }
     },
     {
@@ -642,32 +920,223 @@ const LEVEL6: &str = r#"[
     {
         "type": "rectangle",
         "data": {
-            "xsize": 2,
+            "xsize": 2,
+            "ysize": 1,
+            "zsize": 2,
+            "x": 27,
+            "y": 18,
+            "z": 17,
+            "rotation": 0.125,
+            "lifetime": 10,
+            "color": 6
+        }
+    },
+    {
+        "type": "finish",
+        "data": {
+            "size": 3,
+            "x": 28,
+            "y": 21,
+            "z": 18,
+            "color": 2,
+            "rotation": 0.125
+        }
+    },
+    {
+        "type": "gem",
+        "data": {
+            "x": 0,
+            "y": 5.25,
+            "z": 10,
+            "gem": 0
+        }
+    }
+]"#;
+
+const LEVEL7: &str = r#"[
+    {
+        "type": "rectangle",
+        "data": {
+            "xsize": 3,
+            "ysize": 1,
+            "zsize": 3,
+            "x": 0,
+            "y": 0,
+            "z": 0,
+            "color": 6,
+            "rotation": 0.0
+        }
+    },
+    {
+        "type": "rectangle",
+        "data": {
+            "xsize": 0.1,
+            "ysize": 1,
+            "zsize": 3,
+            "x": 0,
+            "y": 0,
+            "z": 3,
+            "color": 6,
+            "rotation": 0.0
+        }
+    },
+    {
+        "type": "rectangle",
+        "data": {
+            "xsize": 0.1,
+            "ysize": 1,
+            "zsize": 3,
+            "x": 1,
+            "y": 0,
+            "z": 6,
+            "color": 6,
+            "rotation": 0.1
+        }
+    },
+    {
+        "type": "rectangle",
+        "data": {
+            "xsize": 0.1,
+            "ysize": 1,
+            "zsize": 3,
+            "x": 2,
+            "y": 0,
+            "z": 9,
+            "color": 6,
+            "rotation": 0.0
+        }
+    },
+    {
+        "type": "rectangle",
+        "data": {
+            "xsize": 0.1,
+            "ysize": 1,
+            "zsize": 3,
+            "x": 1,
+            "y": 0,
+            "z": 12,
+            "color": 6,
+            "rotation": -0.1
+        }
+    },
+    {
+        "type": "rectangle",
+        "data": {
+            "xsize": 0.1,
+            "ysize": 1,
+            "zsize": 3,
+            "x": 0,
+            "y": 0,
+            "z": 15,
+            "color": 6,
+            "rotation": 0.0
+        }
+    },
+    {
+        "type": "rectangle",
+        "data": {
+            "xsize": 0.1,
+            "ysize": 1,
+            "zsize": 3,
+            "x": 1,
+            "y": 0,
+            "z": 18,
+            "color": 6,
+            "rotation": 0.1
+        }
+    },
+    {
+        "type": "rectangle",
+        "data": {
+            "xsize": 0.1,
+            "ysize": 1,
+            "zsize": 3,
+            "x": 2,
+            "y": 0,
+            "z": 21,
+            "color": 6,
+            "rotation": 0.25
+        }
+    },
+    {
+        "type": "rectangle",
+        "data": {
+            "xsize": 0.1,
+            "ysize": 1,
+            "zsize": 3,
+            "x": 2,
+            "y": 0,
+            "z": 24,
+            "color": 6,
+            "rotation": 0.25
+        }
+    },
+    {
+        "type": "rectangle",
+        "data": {
+            "xsize": 0.1,
+            "ysize": 1,
+            "zsize": 3,
+            "x": 2,
+            "y": 0,
+            "z": 27,
+            "color": 6,
+            "rotation": 0.25
+        }
+    },
+    {
+        "type": "rectangle",
+        "data": {
+            "xsize": 0.1,
+            "ysize": 1,
+            "zsize": 3,
+            "x": 2,
+            "y": 0,
+            "z": 30,
+            "color": 6,
+            "rotation": 0.25
+        }
+    },
+    {
+        "type": "rectangle",
+        "data": {
+            "xsize": 0.1,
+            "ysize": 1,
+            "zsize": 3,
+            "x": 2,
+            "y": 0,
+            "z": 33,
+            "color": 6,
+            "rotation": 0.25
+        }
+    },
+    {
+        "type": "rectangle",
+        "data": {
+            "xsize": 3,
             "ysize": 1,
-            "zsize": 2,
-            "x": 27,
-            "y": 18,
-            "z": 17,
-            "rotation": 0.125,
-            "lifetime": 10,
-            "color": 6
+            "zsize": 3,
+            "x": 2,
+            "y": 0,
+            "z": 38,
+            "color": 6,
+            "rotation": 0.0
         }
     },
     {
         "type": "finish",
         "data": {
             "size": 3,
-            "x": 28,
-            "y": 21,
-            "z": 18,
+            "x": 2,
+            "y": 3,
+            "z": 39.5,
             "color": 2,
-            "rotation": 0.125
+            "rotation": 0
         }
     }
-]
-"#;
+]"#;
 
-const LEVEL7: &str = r#"[
+const LEVEL8: &str = r#"[
     {
         "type": "rectangle",
         "data": {
@@ -682,146 +1151,202 @@ const LEVEL7: &str = r#"[
         }
     },
     {
-        "type": "rectangle",
+        "type": "mover",
         "data": {
-            "xsize": 0.1,
+            "xsize": 3,
             "ysize": 1,
             "zsize": 3,
             "x": 0,
             "y": 0,
-            "z": 3,
-            "color": 6,
-            "rotation": 0.0
+            "z": 5,
+            "pos_a_x": -5,
+            "pos_a_y": 0,
+            "pos_a_z": 5,
+            "pos_b_x": 5,
+            "pos_b_y": 0,
+            "pos_b_z": 5,
+            "speed": 0.7,
+            "wait": 3,
+            "color": 9
         }
     },
     {
-        "type": "rectangle",
+        "type": "mover",
         "data": {
-            "xsize": 0.1,
+            "xsize": 3,
             "ysize": 1,
             "zsize": 3,
-            "x": 1,
+            "x": 0,
             "y": 0,
-            "z": 6,
-            "color": 6,
-            "rotation": 0.1
+            "z": 10,
+            "pos_a_x": 5,
+            "pos_a_y": 0,
+            "pos_a_z": 10,
+            "pos_b_x": -5,
+            "pos_b_y": 0,
+            "pos_b_z": 10,
+            "speed": 0.7,
+            "wait": 3,
+            "color": 9
         }
     },
     {
-        "type": "rectangle",
+        "type": "mover",
         "data": {
-            "xsize": 0.1,
+            "xsize": 3,
             "ysize": 1,
             "zsize": 3,
-            "x": 2,
+            "x": 0,
             "y": 0,
-            "z": 9,
-            "color": 6,
-            "rotation": 0.0
+            "z": 15,
+            "pos_a_x": -5,
+            "pos_a_y": 0,
+            "pos_a_z": 15,
+            "pos_b_x": 5,
+            "pos_b_y": 0,
+            "pos_b_z": 15,
+            "speed": 0.7,
+            "wait": 3,
+            "color": 9
         }
     },
     {
-        "type": "rectangle",
+        "type": "mover",
         "data": {
-            "xsize": 0.1,
+            "xsize": 3,
             "ysize": 1,
             "zsize": 3,
-            "x": 1,
+            "x": 0,
             "y": 0,
-            "z": 12,
-            "color": 6,
-            "rotation": -0.1
+            "z": 20,
+            "pos_a_x": 5,
+            "pos_a_y": 0,
+            "pos_a_z": 20,
+            "pos_b_x": -5,
+            "pos_b_y": 0,
+            "pos_b_z": 20,
+            "speed": 0.7,
+            "wait": 3,
+            "color": 9
         }
     },
     {
         "type": "rectangle",
         "data": {
-            "xsize": 0.1,
+            "xsize": 3,
             "ysize": 1,
             "zsize": 3,
             "x": 0,
             "y": 0,
-            "z": 15,
+            "z": 25,
             "color": 6,
             "rotation": 0.0
         }
     },
     {
-        "type": "rectangle",
+        "type": "mover",
         "data": {
-            "xsize": 0.1,
+            "xsize": 3,
             "ysize": 1,
             "zsize": 3,
-            "x": 1,
+            "x": 0,
             "y": 0,
-            "z": 18,
-            "color": 6,
-            "rotation": 0.1
+            "z": 35,
+            "pos_a_x": 0,
+            "pos_a_y": 0,
+            "pos_a_z": 30,
+            "pos_b_x": 0,
+            "pos_b_y": 0,
+            "pos_b_z": 40,
+            "speed": 0.7,
+            "wait": 5,
+            "color": 9
         }
     },
     {
         "type": "rectangle",
         "data": {
-            "xsize": 0.1,
+            "xsize": 3,
             "ysize": 1,
             "zsize": 3,
-            "x": 2,
+            "x": 0,
             "y": 0,
-            "z": 21,
+            "z": 25,
             "color": 6,
-            "rotation": 0.25
+            "rotation": 0.0
         }
     },
     {
-        "type": "rectangle",
+        "type": "mover",
         "data": {
-            "xsize": 0.1,
+            "xsize": 3,
             "ysize": 1,
             "zsize": 3,
-            "x": 2,
+            "x": 0,
             "y": 0,
-            "z": 24,
-            "color": 6,
-            "rotation": 0.25
+            "z": 50,
+            "pos_a_x": 0,
+            "pos_a_y": 0,
+            "pos_a_z": 55,
+            "pos_b_x": 0,
+            "pos_b_y": 0,
+            "pos_b_z": 45,
+            "speed": 0.7,
+            "wait": 5,
+            "color": 9
         }
     },
     {
         "type": "rectangle",
         "data": {
-            "xsize": 0.1,
+            "xsize": 3,
             "ysize": 1,
             "zsize": 3,
-            "x": 2,
+            "x": 0,
             "y": 0,
-            "z": 27,
+            "z": 60,
             "color": 6,
-            "rotation": 0.25
+            "rotation": 0.0
         }
     },
     {
-        "type": "rectangle",
+        "type": "mover",
         "data": {
-            "xsize": 0.1,
+            "xsize": 3,
             "ysize": 1,
             "zsize": 3,
-            "x": 2,
+            "x": 0,
             "y": 0,
-            "z": 30,
-            "color": 6,
-            "rotation": 0.25
+            "z": 65,
+            "pos_a_x": 0,
+            "pos_a_y": 10,
+            "pos_a_z": 65,
+            "pos_b_x": 0,
+            "pos_b_y": -10,
+            "pos_b_z": 65,
+            "speed": 0.7,
+            "wait": 3,
+            "color": 9
         }
     },
     {
-        "type": "rectangle",
+        "type": "mover",
         "data": {
-            "xsize": 0.1,
+            "xsize": 3,
             "ysize": 1,
             "zsize": 3,
-            "x": 2,
+            "x": 0,
             "y": 0,
-            "z": 33,
-            "color": 6,
-            "rotation": 0.25
+            "z": 70,
+            "pos_a_x": 0,
+            "pos_a_y": -10,
+            "pos_a_z": 70,
+            "pos_b_x": 0,
+            "pos_b_y": 10,
+            "pos_b_z": 70,
+            "speed": 0.7,
+            "wait": 3,
+            "color": 9
         }
     },
     {
@@ -830,9 +1355,9 @@ const LEVEL7: &str = r#"[
             "xsize": 3,
             "ysize": 1,
             "zsize": 3,
-            "x": 2,
+            "x": 0,
             "y": 0,
-            "z": 38,
+            "z": 75,
             "color": 6,
             "rotation": 0.0
         }
@@ -841,88 +1366,22 @@ const LEVEL7: &str = r#"[
         "type": "finish",
         "data": {
             "size": 3,
-            "x": 2,
+            "x": 0,
             "y": 3,
-            "z": 39.5,
+            "z": 76.5,
             "color": 2,
             "rotation": 0
         }
-    }
-]
-"#;
-
-const LEVEL8: &str = r#"[
-    { "type": "rectangle", "data": { "xsize": 3, "ysize": 1, "zsize": 3, "x": 0, "y": 0, "z": 0, "color": 6, "rotation": 0.0 } },
-    {"type": "mover",
-        "data": { 
-            "xsize": 3, "ysize": 1, "zsize": 3, "x": 0, "y": 0, "z": 5,
-            "pos_a_x": -5, "pos_a_y": 0, "pos_a_z": 5,
-            "pos_b_x": 5, "pos_b_y": 0, "pos_b_z": 5,
-            "speed": 0.7, "wait": 3, "color": 9
-        }
-    },
-    {"type": "mover",
-        "data": { 
-            "xsize": 3, "ysize": 1, "zsize": 3, "x": 0, "y": 0, "z": 10,
-            "pos_a_x": 5, "pos_a_y": 0, "pos_a_z": 10,
-            "pos_b_x": -5, "pos_b_y": 0, "pos_b_z": 10,
-            "speed": 0.7, "wait": 3, "color": 9
-        }
-    },
-    {"type": "mover",
-        "data": { 
-            "xsize": 3, "ysize": 1, "zsize": 3, "x": 0, "y": 0, "z": 15,
-            "pos_a_x": -5, "pos_a_y": 0, "pos_a_z": 15,
-            "pos_b_x": 5, "pos_b_y": 0, "pos_b_z": 15,
-            "speed": 0.7, "wait": 3, "color": 9
-        }
-    },
-    {"type": "mover",
-        "data": { 
-            "xsize": 3, "ysize": 1, "zsize": 3, "x": 0, "y": 0, "z": 20,
-            "pos_a_x": 5, "pos_a_y": 0, "pos_a_z": 20,
-            "pos_b_x": -5, "pos_b_y": 0, "pos_b_z": 20,
-            "speed": 0.7, "wait": 3, "color": 9
-        }
-    },
-    { "type": "rectangle", "data": { "xsize": 3, "ysize": 1, "zsize": 3, "x": 0, "y": 0, "z": 25, "color": 6, "rotation": 0.0 } },
-       {"type": "mover",
-        "data": { 
-            "xsize": 3, "ysize": 1, "zsize": 3, "x": 0, "y": 0, "z": 35,
-            "pos_a_x": 0, "pos_a_y": 0, "pos_a_z": 30,
-            "pos_b_x": 0, "pos_b_y": 0, "pos_b_z": 40,
-            "speed": 0.7, "wait": 5, "color": 9
-        }
-    },
-    { "type": "rectangle", "data": { "xsize": 3, "ysize": 1, "zsize": 3, "x": 0, "y": 0, "z": 25, "color": 6, "rotation": 0.0 } },
-       {"type": "mover",
-        "data": { 
-            "xsize": 3, "ysize": 1, "zsize": 3, "x": 0, "y": 0, "z": 50,
-            "pos_a_x": 0, "pos_a_y": 0, "pos_a_z": 55,
-            "pos_b_x": 0, "pos_b_y": 0, "pos_b_z": 45,
-            "speed": 0.7, "wait": 5, "color": 9
-        }
-    },
-    { "type": "rectangle", "data": { "xsize": 3, "ysize": 1, "zsize": 3, "x": 0, "y": 0, "z": 60, "color": 6, "rotation": 0.0 } },
-
-    {"type": "mover",
-        "data": { 
-            "xsize": 3, "ysize": 1, "zsize": 3, "x": 0, "y": 0, "z": 65,
-            "pos_a_x": 0, "pos_a_y": 10, "pos_a_z": 65,
-            "pos_b_x": 0, "pos_b_y": -10, "pos_b_z": 65,
-            "speed": 0.7, "wait": 3, "color": 9
-        }
     },
-    {"type": "mover",
-        "data": { 
-            "xsize": 3, "ysize": 1, "zsize": 3, "x": 0, "y": 0, "z": 70,
-            "pos_a_x": 0, "pos_a_y": -10, "pos_a_z": 70,
-            "pos_b_x": 0, "pos_b_y": 10, "pos_b_z": 70,
-            "speed": 0.7, "wait": 3, "color": 9
+    {
+        "type": "gem",
+        "data": {
+            "x": 0,
+            "y": 1.25,
+            "z": 60,
+            "gem": 0
         }
-    },
-    { "type": "rectangle", "data": { "xsize": 3, "ysize": 1, "zsize": 3, "x": 0, "y": 0, "z": 75, "color": 6, "rotation": 0.0 } },
-    { "type": "finish", "data": {"size": 3, "x": 0, "y": 3, "z": 76.5, "color": 2, "rotation": 0 } }
+    }
 ]"#;
 
 const LEVEL9: &str = r#"[
@@ -1060,9 +1519,27 @@ const LEVEL9: &str = r#"[
             "color": 2,
             "rotation": 0.25
         }
+    },
+    {
+        "type": "sign",
+        "data": {
+            "x": -1,
+            "y": 1.25,
+            "z": -1,
+            "color": 7,
+            "message": 0
+        }
+    },
+    {
+        "type": "gem",
+        "data": {
+            "x": 0,
+            "y": 1.25,
+            "z": 32,
+            "gem": 0
+        }
     }
-]
-"#;
+]"#;
 
 const LEVEL10: &str = r#"[
     {
@@ -1166,8 +1643,7 @@ const LEVEL10: &str = r#"[
             "rotation": 0.25
         }
     }
-]
-"#;
+]"#;
 
 const LEVEL11: &str = r#"[
     {
@@ -1375,9 +1851,17 @@ const LEVEL11: &str = r#"[
             "color": 2,
             "rotation": 0.25
         }
+    },
+    {
+        "type": "gem",
+        "data": {
+            "x": 34,
+            "y": 11.25,
+            "z": 23,
+            "gem": 0
+        }
     }
-]
-"#;
+]"#;
 
 const LEVEL12: &str = r#"[
     {
@@ -1403,7 +1887,8 @@ const LEVEL12: &str = r#"[
             "y": 0,
             "z": 5,
             "rotation": 0,
-            "lifetime": 5, "color": 7
+            "lifetime": 5,
+            "color": 7
         }
     },
     {
@@ -1416,7 +1901,8 @@ const LEVEL12: &str = r#"[
             "y": 0,
             "z": 10,
             "rotation": 0,
-            "lifetime": 5, "color": 7
+            "lifetime": 5,
+            "color": 7
         }
     },
     {
@@ -1429,10 +1915,10 @@ const LEVEL12: &str = r#"[
             "y": 0,
             "z": 15,
             "rotation": 0,
-            "lifetime": 5, "color": 7
+            "lifetime": 5,
+            "color": 7
         }
     },
-
     {
         "type": "bounce",
         "data": {
@@ -1456,7 +1942,8 @@ const LEVEL12: &str = r#"[
             "y": 3,
             "z": 23,
             "rotation": 0.125,
-            "lifetime": 5, "color": 7
+            "lifetime": 5,
+            "color": 7
         }
     },
     {
@@ -1482,7 +1969,8 @@ const LEVEL12: &str = r#"[
             "y": 6,
             "z": 23,
             "rotation": 0.125,
-            "lifetime": 5, "color": 7
+            "lifetime": 5,
+            "color": 7
         }
     },
     {
@@ -1495,7 +1983,8 @@ const LEVEL12: &str = r#"[
             "y": 6,
             "z": 19,
             "rotation": 0.125,
-            "lifetime": 5, "color": 7
+            "lifetime": 5,
+            "color": 7
         }
     },
     {
@@ -1521,7 +2010,8 @@ const LEVEL12: &str = r#"[
             "y": 6,
             "z": 16,
             "rotation": 0,
-            "lifetime": 5, "color": 7
+            "lifetime": 5,
+            "color": 7
         }
     },
     {
@@ -1537,7 +2027,7 @@ const LEVEL12: &str = r#"[
             "rotation": 0
         }
     },
-	{
+    {
         "type": "finish",
         "data": {
             "size": 3,
@@ -1547,9 +2037,17 @@ const LEVEL12: &str = r#"[
             "color": 2,
             "rotation": 0.25
         }
+    },
+    {
+        "type": "gem",
+        "data": {
+            "x": 21,
+            "y": 7.25,
+            "z": 16,
+            "gem": 0
+        }
     }
-]
-"#;
+]"#;
 
 const LEVEL13: &str = r#"[
     {
@@ -1594,7 +2092,6 @@ const LEVEL13: &str = r#"[
             "rotation": 0.0
         }
     },
-
     {
         "type": "ice",
         "data": {
@@ -1637,7 +2134,6 @@ const LEVEL13: &str = r#"[
             "rotation": 0
         }
     },
-
     {
         "type": "ice",
         "data": {
@@ -1733,8 +2229,7 @@ const LEVEL13: &str = r#"[
             "rotation": 0.25
         }
     }
-]
-"#;
+]"#;
 
 const LEVEL14: &str = r#"[
     {
@@ -1778,7 +2273,6 @@ const LEVEL14: &str = r#"[
             "color": 7
         }
     },
-
     {
         "type": "crumbling",
         "data": {
@@ -1970,7 +2464,7 @@ const LEVEL14: &str = r#"[
             "rotation": 0.0
         }
     },
-	{
+    {
         "type": "rectangle",
         "data": {
             "xsize": 3,
@@ -1994,8 +2488,7 @@ const LEVEL14: &str = r#"[
             "rotation": 0
         }
     }
-]
-"#;
+]"#;
 
 const LEVEL15: &str = r#"[
     {
@@ -2011,7 +2504,6 @@ const LEVEL15: &str = r#"[
             "rotation": 0.0
         }
     },
-
     {
         "type": "crumbling",
         "data": {
@@ -2151,7 +2643,8 @@ const LEVEL15: &str = r#"[
             "acceleration": 0.004,
             "rotation": 0.0
         }
-    },    {
+    },
+    {
         "type": "ice",
         "data": {
             "xsize": 3,
@@ -2189,7 +2682,78 @@ const LEVEL15: &str = r#"[
             "rotation": 0
         }
     }
-]
-"#;
+]"#;
+
+pub const LEVELS: [&'static str; 15] = [LEVEL1, LEVEL2, LEVEL3, LEVEL4, LEVEL5, LEVEL6, LEVEL7, LEVEL8, LEVEL9, LEVEL10, LEVEL11, LEVEL12, LEVEL13, LEVEL14, LEVEL15];
+
+const LEVEL1_SETTINGS: &str = r#"{"movement": {"move_cap": 0.25, "ground_accel": 0.125, "air_accel": 0.03125, "float_gravity": 0.5, "base_gravity": 0.125, "jump_power": 1.0, "coyote_frames": 5, "jump_buffer_frames": 5, "turn_rate": 0.0625, "accel_curve": "linear"}}"#;
+
+const LEVEL2_SETTINGS: &str = r#"{"movement": {"move_cap": 0.25, "ground_accel": 0.125, "air_accel": 0.03125, "float_gravity": 0.5, "base_gravity": 0.125, "jump_power": 1.0, "coyote_frames": 5, "jump_buffer_frames": 5, "turn_rate": 0.0625, "accel_curve": "linear"}}"#;
+
+const LEVEL3_SETTINGS: &str = r#"{"movement": {"move_cap": 0.25, "ground_accel": 0.125, "air_accel": 0.03125, "float_gravity": 0.5, "base_gravity": 0.125, "jump_power": 1.0, "coyote_frames": 5, "jump_buffer_frames": 5, "turn_rate": 0.0625, "accel_curve": "linear"}}"#;
+
+const LEVEL4_SETTINGS: &str = r#"{"movement": {"move_cap": 0.25, "ground_accel": 0.125, "air_accel": 0.03125, "float_gravity": 0.5, "base_gravity": 0.125, "jump_power": 1.0, "coyote_frames": 5, "jump_buffer_frames": 5, "turn_rate": 0.0625, "accel_curve": "linear"}}"#;
+
+const LEVEL5_SETTINGS: &str = r#"{"movement": {"move_cap": 0.25, "ground_accel": 0.125, "air_accel": 0.03125, "float_gravity": 0.5, "base_gravity": 0.125, "jump_power": 1.0, "coyote_frames": 5, "jump_buffer_frames": 5, "turn_rate": 0.0625, "accel_curve": "linear"}}"#;
+
+const LEVEL6_SETTINGS: &str = r#"{"movement": {"move_cap": 0.25, "ground_accel": 0.125, "air_accel": 0.03125, "float_gravity": 0.5, "base_gravity": 0.125, "jump_power": 1.0, "coyote_frames": 5, "jump_buffer_frames": 5, "turn_rate": 0.0625, "accel_curve": "linear"}}"#;
+
+const LEVEL7_SETTINGS: &str = r#"{"movement": {"move_cap": 0.25, "ground_accel": 0.125, "air_accel": 0.03125, "float_gravity": 0.5, "base_gravity": 0.125, "jump_power": 1.0, "coyote_frames": 5, "jump_buffer_frames": 5, "turn_rate": 0.0625, "accel_curve": "linear"}}"#;
+
+const LEVEL8_SETTINGS: &str = r#"{"intro": [{"x": 0, "y": 7, "z": 66, "yaw": 0, "pitch": -0.05, "duration": 90}, {"x": 4, "y": 9, "z": 45, "yaw": 0, "pitch": -0.05, "duration": 90}, {"x": -4, "y": 9, "z": 22, "yaw": 0, "pitch": -0.05, "duration": 90}], "movement": {"move_cap": 0.25, "ground_accel": 0.125, "air_accel": 0.03125, "float_gravity": 0.5, "base_gravity": 0.125, "jump_power": 1.0, "coyote_frames": 5, "jump_buffer_frames": 5, "turn_rate": 0.0625, "accel_curve": "linear"}}"#;
+
+const LEVEL9_SETTINGS: &str = r#"{"movement": {"move_cap": 0.25, "ground_accel": 0.125, "air_accel": 0.03125, "float_gravity": 0.5, "base_gravity": 0.125, "jump_power": 1.0, "coyote_frames": 5, "jump_buffer_frames": 5, "turn_rate": 0.0625, "accel_curve": "linear"}}"#;
+
+const LEVEL10_SETTINGS: &str = r#"{"movement": {"move_cap": 0.25, "ground_accel": 0.125, "air_accel": 0.03125, "float_gravity": 0.5, "base_gravity": 0.125, "jump_power": 1.0, "coyote_frames": 5, "jump_buffer_frames": 5, "turn_rate": 0.0625, "accel_curve": "linear"}}"#;
+
+const LEVEL11_SETTINGS: &str = r#"{"movement": {"move_cap": 0.25, "ground_accel": 0.125, "air_accel": 0.03125, "float_gravity": 0.5, "base_gravity": 0.125, "jump_power": 1.0, "coyote_frames": 5, "jump_buffer_frames": 5, "turn_rate": 0.0625, "accel_curve": "linear"}}"#;
+
+const LEVEL12_SETTINGS: &str = r#"{"movement": {"move_cap": 0.25, "ground_accel": 0.125, "air_accel": 0.03125, "float_gravity": 0.5, "base_gravity": 0.125, "jump_power": 1.0, "coyote_frames": 5, "jump_buffer_frames": 5, "turn_rate": 0.0625, "accel_curve": "linear"}}"#;
+
+const LEVEL13_SETTINGS: &str = r#"{"movement": {"move_cap": 0.25, "ground_accel": 0.125, "air_accel": 0.03125, "float_gravity": 0.5, "base_gravity": 0.125, "jump_power": 1.0, "coyote_frames": 5, "jump_buffer_frames": 5, "turn_rate": 0.0625, "accel_curve": "linear"}}"#;
+
+const LEVEL14_SETTINGS: &str = r#"{"movement": {"move_cap": 0.25, "ground_accel": 0.125, "air_accel": 0.03125, "float_gravity": 0.5, "base_gravity": 0.125, "jump_power": 1.0, "coyote_frames": 5, "jump_buffer_frames": 5, "turn_rate": 0.0625, "accel_curve": "linear"}}"#;
+
+const LEVEL15_SETTINGS: &str = r#"{"movement": {"move_cap": 0.25, "ground_accel": 0.125, "air_accel": 0.03125, "float_gravity": 0.5, "base_gravity": 0.125, "jump_power": 1.0, "coyote_frames": 5, "jump_buffer_frames": 5, "turn_rate": 0.0625, "accel_curve": "linear"}}"#;
+
+pub const LEVEL_SETTINGS: [&'static str; 15] = [LEVEL1_SETTINGS, LEVEL2_SETTINGS, LEVEL3_SETTINGS, LEVEL4_SETTINGS, LEVEL5_SETTINGS, LEVEL6_SETTINGS, LEVEL7_SETTINGS, LEVEL8_SETTINGS, LEVEL9_SETTINGS, LEVEL10_SETTINGS, LEVEL11_SETTINGS, LEVEL12_SETTINGS, LEVEL13_SETTINGS, LEVEL14_SETTINGS, LEVEL15_SETTINGS];
+
+const LEVEL1_MESSAGES: [&str; 0] = [];
+
+const LEVEL2_MESSAGES: [&str; 1] = [r#"tan platforms crumble soon after you land on them. keep moving!"#];
+
+const LEVEL3_MESSAGES: [&str; 0] = [];
+
+const LEVEL4_MESSAGES: [&str; 1] = [r#"walk up to a switch and press Ⓑ to flip it. this makes red blocks solid or see-through."#];
+
+const LEVEL5_MESSAGES: [&str; 1] = [r#"yellow pads bounce you up. hold Ⓐ as you land on one to bounce higher."#];
+
+const LEVEL6_MESSAGES: [&str; 0] = [];
+
+const LEVEL7_MESSAGES: [&str; 0] = [];
+
+const LEVEL8_MESSAGES: [&str; 0] = [];
+
+const LEVEL9_MESSAGES: [&str; 1] = [r#"ice is slippery. you keep sliding after letting go, so slow down early."#];
+
+const LEVEL10_MESSAGES: [&str; 0] = [];
+
+const LEVEL11_MESSAGES: [&str; 0] = [];
+
+const LEVEL12_MESSAGES: [&str; 0] = [];
+
+const LEVEL13_MESSAGES: [&str; 0] = [];
+
+const LEVEL14_MESSAGES: [&str; 0] = [];
+
+const LEVEL15_MESSAGES: [&str; 0] = [];
+
+pub const LEVEL_MESSAGES: [&[&str]; 15] = [&LEVEL1_MESSAGES, &LEVEL2_MESSAGES, &LEVEL3_MESSAGES, &LEVEL4_MESSAGES, &LEVEL5_MESSAGES, &LEVEL6_MESSAGES, &LEVEL7_MESSAGES, &LEVEL8_MESSAGES, &LEVEL9_MESSAGES, &LEVEL10_MESSAGES, &LEVEL11_MESSAGES, &LEVEL12_MESSAGES, &LEVEL13_MESSAGES, &LEVEL14_MESSAGES, &LEVEL15_MESSAGES];
+
+pub const CHAPTERS: [Chapter; 4] = [Chapter { name: r#"first steps"#, unlock_levels: 0, unlock_gems: 0 }, Chapter { name: r#"up and away"#, unlock_levels: 4, unlock_gems: 0 }, Chapter { name: r#"thin ice"#, unlock_levels: 7, unlock_gems: 5 }, Chapter { name: r#"bonus"#, unlock_levels: 0, unlock_gems: 0 }];
+
+pub const LEVEL_CHAPTERS: [usize; 15] = [0, 0, 0, 0, 0, 1, 1, 1, 2, 2, 2, 2, 2, 2, 3];
+
+pub const LEVEL_GEMS: [u8; 15] = [1, 1, 1, 1, 1, 1, 0, 1, 1, 0, 1, 1, 0, 0, 0];
 
-pub const LEVELS: [&'static str; 15] = [LEVEL1, LEVEL2, LEVEL3, LEVEL4, LEVEL5, LEVEL6, LEVEL7, LEVEL8, LEVEL9, LEVEL10, LEVEL11, LEVEL12, LEVEL13, LEVEL14, LEVEL15];
\ No newline at end of file
+pub const BONUS_LEVELS: [bool; 15] = [false, false, false, false, false, false, false, false, false, false, false, false, false, false, true];
\ No newline at end of file
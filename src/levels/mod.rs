@@ -1,11 +1,34 @@
+use crate::camera::controller::CameraSettings;
+use crate::camera::path::Keyframe;
+use crate::player::{Abilities, MovementProfile};
 use crate::EntityEnum;
 
+pub mod chapters;
 pub mod levelstore;
 
 extern crate alloc;
 use alloc::vec::Vec;
+use serde::Deserialize;
 use serde_json_core::from_slice;
 
+//level wide options from the "settings" object of a level file
+#[derive(Copy, Clone, Deserialize, Debug, Default)]
+pub struct LevelSettings {
+    #[serde(default)]
+    pub abilities: Abilities,
+    #[serde(default)]
+    pub movement: MovementProfile,
+    #[serde(default)]
+    pub camera: CameraSettings,
+}
+
+//the intro keyframes are kept apart from LevelSettings, so the settings stay Copy
+#[derive(Deserialize)]
+struct IntroSettings {
+    #[serde(default)]
+    intro: Vec<Keyframe>,
+}
+
 
 #[inline(never)]
 pub fn load_level(level: usize, entity_array: &mut [EntityEnum]) -> usize{
@@ -22,3 +45,26 @@ pub fn load_level(level: usize, entity_array: &mut [EntityEnum]) -> usize{
     }
 	return parsed_entities.len();
 }
+
+pub fn load_settings(level: usize) -> LevelSettings {
+    let message_bytes = levelstore::LEVEL_SETTINGS[level].as_bytes();
+    //scripts/bundle_levels.py checks the settings, so they can't fail to read here
+    let (settings, _): (LevelSettings, usize) = from_slice(message_bytes).unwrap();
+    return settings;
+}
+
+//text of a sign, see scripts/bundle_levels.py
+pub fn message(level: usize, index: u16) -> &'static str {
+    match levelstore::LEVEL_MESSAGES[level].get(index as usize) {
+        Some(text) => text,
+        None => "",
+    }
+}
+
+//camera keyframes of the intro flyover, empty if the level has none
+pub fn load_intro(level: usize) -> Vec<Keyframe> {
+    let message_bytes = levelstore::LEVEL_SETTINGS[level].as_bytes();
+    //the keyframes are checked by scripts/bundle_levels.py like the rest of the settings
+    let (settings, _): (IntroSettings, usize) = from_slice(message_bytes).unwrap();
+    return settings.intro;
+}
@@ -26,7 +26,7 @@ mod entities;
 use cube::Cube;
 use effects::OutputEvents;
 use empty::Empty;
-use entities::utils::{check_block_above, check_support_below};
+use entities::utils::{check_block_above, check_landing_below, check_support_below};
 use entities::*;
 
 mod camera;
@@ -43,21 +43,33 @@ mod input;
 use fixed::*;
 mod audio;
 mod effects;
+mod hud;
 mod levels;
 mod mathlut;
 mod menu;
 mod moveutils;
+mod options;
 mod save;
 mod textengine;
 use body::Body;
 use entities::boundingshapes::{BoundingBox, BoundingShape};
 use renderer::polygon::Polygon;
+use camera::path::{CameraPath, Keyframe};
+use hud::{Hud, WidgetKind};
+use input::mapping::Action;
+use levels::chapters;
+use save::LevelProgress;
 
-const DRAWDISTANCE: Fixed = Fixed::const_new(35);
 const POLYGON_LIMIT: i16 = 60;
 //IMPORTANT: if flashing to real hardware, set save type to match the memory type of the cartridge
 const SAVE_TYPE: save::SaveType = save::SaveType::Flash64K;//None;
 const FLOOR_LEVEL: Fixed = Fixed::const_new(-500);
+//point above the player's feet the camera keeps a clear line of sight to
+const CAMERA_FOCUS_HEIGHT: Fixed = Fixed::const_new(1);
+//palette steps per frame when fading around a teleport
+const TELEPORT_FADE_SPEED: i32 = 2;
+//the demo goes back to the title after this many frames, in case it gets stuck
+const DEMO_FRAME_LIMIT: i32 = 1800;
 
 /*
 The main function must take 1 arguments and never return. The agb::entry decorator
@@ -70,39 +82,50 @@ fn main(mut gba: agb::Gba) -> ! {
 
     const LEVEL_COUNT: usize = levels::levelstore::LEVELS.len();
 
-    let mut completed_levels: Vec<bool, InternalAllocator> =
+    let mut progress: Vec<LevelProgress, InternalAllocator> =
         Vec::with_capacity_in(LEVEL_COUNT, InternalAllocator);
-    completed_levels.resize(LEVEL_COUNT, true);
-    //save::store_save(&mut gba, &mut completed_levels);
+    progress.resize(LEVEL_COUNT, LevelProgress::default());
+    //save::store_save(&mut gba, &mut progress);
 
     match save::read_save(&mut gba, levels::levelstore::LEVELS.len(), SAVE_TYPE) {
         Ok(saved_data) => {
-            completed_levels = saved_data;
+            progress = saved_data;
         }
         Err(_) => {
             agb::println!("Failed to read save data");
         }
     }
+    let mut options = match save::read_options(&mut gba, SAVE_TYPE) {
+        Ok(options) => options,
+        Err(_) => {
+            agb::println!("Failed to read options");
+            options::Options::default()
+        }
+    };
 
     let mut input = ButtonController::new();
 
-    let mut bitmap4: agb::display::bitmap4::Bitmap4 = gba.display.video.bitmap4();
+    //switches the display to mode 4, the pages and the palette are written directly after this
+    let _bitmap4: agb::display::bitmap4::Bitmap4 = gba.display.video.bitmap4();
     let mut page: u16 = 0;
-    renderer::utils::init_palette(&mut bitmap4);
+    renderer::utils::init_palette();
     gba.sound.enable();
+    audio::set_volume(options.sound, options.volume);
     let vblank: agb::interrupt::VBlank = agb::interrupt::VBlank::get();
     let mut entity_array: [EntityEnum; LEVELSIZE + 2] =
         [EntityEnum::Empty(Empty::default()); LEVELSIZE + 2];
     let mut entity_render_order: [usize; LEVELSIZE + 2] = [0; LEVELSIZE + 2];
 
-    menu::presstart(&mut input, &mut page);
-    audio::play_sound(6, &vblank, &gba.sound);
-
     let mut selected_level: usize = 0;
     let mut canceled: bool;
 
-    let mut game_state = GameState::Menu;
-    let mut camera_follow = true;
+    let mut game_state = GameState::Title;
+    //the level is played by the demo input instead of the player
+    let mut demo: bool;
+    let mut camera_follow = options.camera_follow;
+    //tries at the level being played, shown on the hud
+    let mut attempts: i32 = 0;
+    let mut attempts_level: usize = LEVEL_COUNT;
 
     //TODO: enable this when the game is finished
     loop {
@@ -112,28 +135,51 @@ fn main(mut gba: agb::Gba) -> ! {
             game_state = GameState::Menu;
         }
 
+        demo = false;
+        if game_state == GameState::Title {
+            if menu::title::show(&mut input, &mut page, &options.mapping) {
+                demo = true;
+                selected_level = 0;
+            } else {
+                audio::play_sound(6, &vblank, &gba.sound);
+                game_state = GameState::Menu;
+            }
+        }
+
         if game_state == GameState::Menu {
-            let option = menu::mainmenu(&mut input, &mut page, &vblank, &gba.sound);
+            let option = menu::mainmenu(&mut input, &mut page, &vblank, &gba.sound, &options.mapping);
             if option == 1 {
                 audio::play_sound(6, &vblank, &gba.sound);
-                menu::info(&mut input, &mut page);
+                menu::options::show(&mut input, &mut page, &vblank, &gba.sound, &mut options);
+                camera_follow = options.camera_follow;
+                let _ = save::store_save(&mut gba, &mut progress, &options, SAVE_TYPE);
+                audio::play_sound(4, &vblank, &gba.sound);
+                continue;
+            } else if option == 2 {
+                audio::play_sound(6, &vblank, &gba.sound);
+                menu::info(&mut input, &mut page, &options.mapping);
                 audio::play_sound(4, &vblank, &gba.sound);
                 continue;
             } else {
                 //pass
                 audio::play_sound(6, &vblank, &gba.sound);
             }
+            game_state = GameState::WorldMap;
+        }
 
-            (selected_level, canceled) = menu::levelmenu(
+        if game_state == GameState::WorldMap {
+            (selected_level, canceled) = menu::world::show(
                 selected_level,
                 &mut input,
                 &mut page,
                 &vblank,
                 &gba.sound,
-                &completed_levels,
+                &progress,
+                &options.mapping,
             );
             if canceled {
                 audio::play_sound(4, &vblank, &gba.sound);
+                game_state = GameState::Menu;
                 continue;
             }
             audio::play_sound(6, &vblank, &gba.sound);
@@ -142,19 +188,31 @@ fn main(mut gba: agb::Gba) -> ! {
         //selected_level = 4;
 
         let levelsize = levels::load_level(selected_level, &mut entity_array);
+        if !demo {
+            if attempts_level != selected_level {
+                attempts_level = selected_level;
+                attempts = 0;
+            }
+            attempts += 1;
+        }
+        let settings = levels::load_settings(selected_level);
 
         let mut player1: Player = Player::default();
-        player1.autorotate_camera = camera_follow;
+        //the demo steers along the path, which needs the camera to follow the player
+        player1.autorotate_camera = camera_follow || demo;
+        player1.abilities = settings.abilities;
+        player1.movement = settings.movement;
+        player1.camera_controller.set_settings(settings.camera);
         player1.init(&vblank, &gba.sound);
 
         player1.camera.set_x_rotation(Fixed::from_raw(0));
         player1.camera.set_y_rotation(Fixed::from_raw(0));
         player1.camera.set_z_rotation(Fixed::from_raw(0));
-        player1.camera.local_y = Fixed::const_new(3);
 
         player1.y = Fixed::const_new(3);
         player1.z = Fixed::const_new(0);
         player1.camera_left(0);
+        player1.snap_camera();
 
         //player body consists of entities 0 and 1
         entity_array[0] = EntityEnum::Body(Body::default());
@@ -182,23 +240,69 @@ fn main(mut gba: agb::Gba) -> ! {
         let mut event_loop: Vec<OutputEvents, InternalAllocator> = Vec::new_in(InternalAllocator);
         let mut polygons: Vec<Polygon, InternalAllocator> = Vec::new_in(InternalAllocator);
         let mut polygon_indices: Vec<usize, InternalAllocator> = Vec::new_in(InternalAllocator);
+        let mut dynamic_pool = pool::DynamicPool::new();
+
+        //ground level and id of what the player would land on, -1 while grounded or above nothing
+        let mut shadow_ground: Fixed = Fixed::const_new(0);
+        let mut shadow_support_id: i16 = -1;
+
+        let mut hud = Hud::new();
+        let hud_timer = hud.add(WidgetKind::Timer, 4, 4);
+        let hud_attempts = hud.add(WidgetKind::Counter("try"), 4, 16);
+        let hud_switches = hud.add(WidgetKind::Switches, 4, 28);
+        let hud_camera = hud.add(WidgetKind::CameraMode, 212, 4);
+        hud.set_visible(hud_attempts, !demo);
+        hud.set_value(hud_attempts, attempts);
+        hud.set_visible(hud_timer, options.show_timer);
+        let mut level_frames: i32 = 0;
+
+        //bits of the gems picked up in this try, they are kept when the level is finished
+        let mut collected_gems: u8 = 0;
+        //set when the finished level isn't followed by an open one
+        let mut return_to_map = false;
+
+        //id of the teleporter the player is being sent to, -1 when not teleporting
+        let mut teleport_target: i16 = -1;
+        let mut brightness: i32 = renderer::utils::FADE_STEPS;
+        let mut demo_input = input::demo::DemoInput::new(&entity_array[2..levelsize + 2]);
 
         game_state = GameState::Playing;
 
-        while game_state == GameState::Playing || game_state == GameState::CompleteAnimation {
+        //the intro flies the camera through the level and ends where it is now
+        let intro = levels::load_intro(selected_level);
+        let mut intro_path = CameraPath::new(intro, Keyframe::from_camera(&player1.camera));
+        if !intro_path.is_finished() && !demo {
+            game_state = GameState::Intro;
+        }
+
+        while game_state == GameState::Playing
+            || game_state == GameState::CompleteAnimation
+            || game_state == GameState::Intro
+        {
+
+            if !demo {
+                camera_follow = player1.autorotate_camera;
+            }
 
-            camera_follow = player1.autorotate_camera;
-            
             if game_state == GameState::Playing {
+                level_frames += 1;
                 input.update();
 
-                game_state = input::handle_input(&mut player1, &input, game_state);
+                if demo {
+                    //any button ends the demo and goes back to the title
+                    if input.is_just_pressed(Button::all()) || level_frames > DEMO_FRAME_LIMIT {
+                        game_state = GameState::Title;
+                        break;
+                    }
+                    demo_input.update(&player1, &options.mapping);
+                    game_state = input::handle_input(&mut player1, &demo_input, game_state, &options);
+                } else {
+                    game_state = input::handle_input(&mut player1, &input, game_state, &options);
+                }
 
-                moveutils::attempt_move(
-                    &mut player1,
-                    &entity_array,
-                    &entity_array[0].bounding_cylinder(),
-                );
+                let body = entity_array[0].bounding_cylinder();
+                moveutils::attempt_move(&mut player1, &mut entity_array, &body);
+                moveutils::settle_pushables(&mut entity_array);
 
 
                 let mut player_box = BoundingBox::default();
@@ -222,7 +326,17 @@ fn main(mut gba: agb::Gba) -> ! {
                     player1.float(rooflevel);
                 }
 
+                //the drop shadow marks where the player is going to land
+                shadow_support_id = -1;
+                if !player1.is_grounded() {
+                    (shadow_ground, shadow_support_id) =
+                        check_landing_below(&entity_array, &player_box, &player_cylinder);
+                }
+
                 player1.update_camera_position();
+                player1
+                    .camera
+                    .pull_in([player1.x, player1.y + CAMERA_FOCUS_HEIGHT, player1.z], &entity_array);
 
                 let input_game_state: effects::InputGameState = effects::InputGameState {
                     support_below_id: bottom_support_id,
@@ -230,6 +344,7 @@ fn main(mut gba: agb::Gba) -> ! {
                     bounding_cylinder: &player_cylinder,
                     action_requested: player1.action,
                     yspeed: player1.yspeed,
+                    ground_pound: player1.is_pounding(),
                 };
                 for i in 0..levelsize + 2 {
                     if let EntityEnum::Empty(_) = entity_array[i] {
@@ -239,31 +354,117 @@ fn main(mut gba: agb::Gba) -> ! {
                         event_loop.push(event);
                     }
                 }
+                dynamic_pool.tick(&input_game_state, &mut event_loop);
 
+                //B dashes unless a switch used it
+                let mut switch_flipped = false;
+                //message of the sign the player is standing at
+                let mut sign_message: Option<u16> = None;
                 for event in event_loop.iter() {
-                    if let OutputEvents::PlayerEvent(event) = event {
-                        player1.x += event.move_x;
-                        player1.y += event.move_y;
-                        player1.z += event.move_z;
+                    if let OutputEvents::Carry(event) = event {
+                        moveutils::carry(
+                            &mut player1,
+                            &entity_array,
+                            &player_cylinder,
+                            &player_box,
+                            event,
+                        );
                     } else if let OutputEvents::GameFinish(_event) = event {
                         audio::play_sound(5, &vblank, &gba.sound);
                         game_state = GameState::CompleteAnimation;
                         player1.finish_animation();
-                        completed_levels[selected_level] = true;
-                        selected_level += 1;
+                        //the demo doesn't count as playing the level
+                        if !demo {
+                            progress[selected_level].completed = true;
+                            progress[selected_level].gems |= collected_gems;
+                            //the next level follows right away if it is open, otherwise the world map comes back
+                            if selected_level + 1 < LEVEL_COUNT
+                                && chapters::level_unlocked(selected_level + 1, &progress)
+                            {
+                                selected_level += 1;
+                            } else {
+                                return_to_map = true;
+                            }
+                        }
                         audio::play_sound(7, &vblank, &gba.sound);
                     } else if let OutputEvents::SwitchAction(_event) = event {
+                        switch_flipped = true;
                         for i in 2..levelsize + 2 {
                             if let EntityEnum::Wireframe(w) = &mut entity_array[i] {
                                 w.toggle();
+                            } else if let EntityEnum::Conveyor(c) = &mut entity_array[i] {
+                                c.reverse();
                             }
                             audio::play_sound(1, &vblank, &gba.sound);
                         }
                     } else if let OutputEvents::BounceEvent(event) = event {
-                        player1.bounce(event.power, input.is_pressed(Button::A));
+                        let jump_held = if demo {
+                            options.mapping.is_pressed(&demo_input, Action::Jump)
+                        } else {
+                            options.mapping.is_pressed(&input, Action::Jump)
+                        };
+                        player1.bounce(event.power, jump_held);
                     } else if let OutputEvents::Sliding(event) = event {
                         player1.sliding(event.acceleration);
+                    } else if let OutputEvents::Force(event) = event {
+                        player1.apply_force(event.force_x, event.force_y, event.force_z);
+                    } else if let OutputEvents::Teleport(event) = event {
+                        if teleport_target < 0 {
+                            teleport_target = event.target;
+                        }
+                    } else if let OutputEvents::EnemyHit(event) = event {
+                        if event.deadly {
+                            game_state = GameState::Failed;
+                        } else if !player1.is_hurt() {
+                            //the enemy keeps touching for a few frames, only the first one knocks back
+                            player1.knockback(event.push_x, event.push_z);
+                        }
+                    } else if let OutputEvents::Spawn(event) = event {
+                        dynamic_pool.spawn_burst(event.dynamic, event.count, event.spread);
+                    } else if let OutputEvents::Message(event) = event {
+                        sign_message = Some(event.index);
+                    } else if let OutputEvents::Collect(event) = event {
+                        collected_gems |= 1 << event.index;
+                        audio::play_sound(6, &vblank, &gba.sound);
+                    }
+                }
+                match sign_message {
+                    Some(index) => hud.show_message(index, levels::message(selected_level, index)),
+                    None => hud.hide_message(),
+                }
+
+                if player1.action && !switch_flipped {
+                    player1.dash();
+                }
+
+                //fade the screen out, move the player while it is black and fade back in
+                if teleport_target >= 0 {
+                    brightness -= TELEPORT_FADE_SPEED;
+                    if brightness <= 0 {
+                        brightness = 0;
+                        for i in 2..levelsize + 2 {
+                            if entity_array[i].get_id() != teleport_target {
+                                continue;
+                            }
+                            if let EntityEnum::Teleporter(t) = &mut entity_array[i] {
+                                (player1.x, player1.y, player1.z) = t.exit_position();
+                                if let Some(angle) = t.exit_angle() {
+                                    player1.face(angle);
+                                }
+                                t.arrive();
+                            }
+                        }
+                        player1.yspeed = Fixed::const_new(0);
+                        player1.snap_camera();
+                        teleport_target = -1;
+                    }
+                    renderer::utils::set_brightness(brightness);
+                } else if brightness < renderer::utils::FADE_STEPS {
+                    brightness += TELEPORT_FADE_SPEED;
+                    if brightness > renderer::utils::FADE_STEPS {
+                        brightness = renderer::utils::FADE_STEPS;
                     }
+                    renderer::utils::set_brightness(brightness);
                 }
 
                 if player1.y < FLOOR_LEVEL {
@@ -278,6 +479,16 @@ fn main(mut gba: agb::Gba) -> ! {
                 game_state = player1.next_animation_frame();
             }
 
+            else if game_state == GameState::Intro {
+                input.update();
+                if options.mapping.is_just_pressed(&input, Action::Pause) || intro_path.is_finished() {
+                    player1.snap_camera();
+                    game_state = GameState::Playing;
+                } else {
+                    intro_path.step(&mut player1.camera);
+                }
+            }
+
             //update player model position on screen
             entity_array[0].set_y_offset(player1.y + entity_array[0].get_height() / 2);
             entity_array[1].set_y_offset(
@@ -291,16 +502,37 @@ fn main(mut gba: agb::Gba) -> ! {
                 entity_array[i].refresh_model_matrix();
             }
 
+            let mut shadow_face: Option<Fixed> = None;
             for i in 0..levelsize + 2 {
                 if let EntityEnum::Empty(_) = entity_array[i] {
                     break;
                 }
+                let first_polygon = polygons.len();
                 entity_array[entity_render_order[i]].render(
                     &player1.camera,
                     &mut polygons,
-                    DRAWDISTANCE,
+                    options.draw_distance(),
                 );
+                if shadow_support_id >= 0 && entity_render_order[i] == shadow_support_id as usize + 2 {
+                    let top_color =
+                        renderer::utils::get_color(entity_array[entity_render_order[i]].get_color(), 0);
+                    shadow_face = renderer::render::face_distance(&polygons[first_polygon..], top_color);
+                }
             }
+            if shadow_support_id >= 0 {
+                renderer::render::render_shadow(
+                    player1.x,
+                    shadow_ground,
+                    player1.z,
+                    entity_array[0].bounding_cylinder().radius,
+                    &player1.camera,
+                    entity_array[shadow_support_id as usize + 2].get_color(),
+                    //drawn right after the face it lies on
+                    shadow_face.map(|distance| distance - Fixed::from_raw(1)),
+                    &mut polygons,
+                );
+            }
+            dynamic_pool.render(&player1.camera, &mut polygons, options.draw_distance());
             for i in 0..polygons.len() {
                 polygon_indices.push(i);
             }
@@ -316,20 +548,58 @@ fn main(mut gba: agb::Gba) -> ! {
             }
 
 
+            let mut switch_states: u32 = 0;
+            let mut switch_count: u8 = 0;
+            for entity in entity_array.iter() {
+                if let EntityEnum::Switch(switch) = entity {
+                    if (switch_count as usize) < hud::MAX_SWITCHES && switch.is_on() {
+                        switch_states |= 1 << switch_count;
+                    }
+                    switch_count += 1;
+                }
+            }
+            hud.set_value(hud_timer, level_frames);
+            hud.set_switches(hud_switches, switch_states, switch_count);
+            hud.set_flag(hud_camera, player1.autorotate_camera);
+
             renderer::hw::fill(page, 128);
             renderer::render::render_polygons(&polygons, &polygon_indices, start as usize, page);
+            if game_state != GameState::Intro {
+                hud.draw(page);
+            }
+            if game_state == GameState::Playing {
+                hud.draw_message(page);
+            }
 
             if game_state == GameState::Paused {
-                renderer::hw::flip(&mut page);
-                //must draw again to update both screens to match
-                renderer::render::render_polygons(
-                    &polygons,
-                    &polygon_indices,
-                    start as usize,
-                    page,
-                );
-                renderer::hw::flip(&mut page);
-                game_state = menu::pause(&mut input, &mut page, &vblank, &gba.sound);
+                let mut choice: u16 = 0;
+                loop {
+                    //the level stays on both pages behind the pause menu, and is drawn again after the map
+                    for _ in 0..2 {
+                        renderer::hw::fill(page, 128);
+                        renderer::render::render_polygons(
+                            &polygons,
+                            &polygon_indices,
+                            start as usize,
+                            page,
+                        );
+                        hud.draw(page);
+                        renderer::hw::flip(&mut page);
+                    }
+                    game_state = menu::pause(
+                        &mut input,
+                        &mut page,
+                        &vblank,
+                        &gba.sound,
+                        &options.mapping,
+                        choice,
+                    );
+                    if game_state != GameState::Map {
+                        break;
+                    }
+                    menu::map::show(&mut input, &mut page, &entity_array, &player1, &options.mapping);
+                    choice = menu::PAUSE_MAP;
+                }
             }
 
             polygons.clear();
@@ -337,6 +607,16 @@ fn main(mut gba: agb::Gba) -> ! {
 
             renderer::hw::flip(&mut page);
         }
-        let _ = save::store_save(&mut gba, &mut completed_levels, SAVE_TYPE);
+        //the level may end in the middle of a teleport fade
+        renderer::utils::set_brightness(renderer::utils::FADE_STEPS);
+        if return_to_map {
+            game_state = GameState::WorldMap;
+        }
+        //the demo plays once and the title comes back, however it ended
+        if demo {
+            game_state = GameState::Title;
+            continue;
+        }
+        let _ = save::store_save(&mut gba, &mut progress, &options, SAVE_TYPE);
     }
 }
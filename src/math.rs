@@ -1,6 +1,7 @@
 //#![no_std]
 
 use crate::Fixed;
+use crate::mathlut::PARTIAL_SINE_LUT;
 
 pub fn vector_mul(v: [Fixed; 3], m: Fixed) -> [Fixed; 3] {
     return [v[0] * m, v[1] * m, v[2] * m];
@@ -108,6 +109,28 @@ pub fn directional_vector_2d(angle: Fixed) -> [Fixed; 2] {
     return [x, z];
 }
 
+//sine of a whole number of degrees, read from the quarter wave in PARTIAL_SINE_LUT
+pub fn sin_degrees(degrees: i32) -> Fixed {
+    let degrees = degrees.rem_euclid(360);
+    let (quarter, sign) = if degrees <= 90 {
+        (degrees, 1)
+    } else if degrees <= 180 {
+        (180 - degrees, 1)
+    } else if degrees <= 270 {
+        (degrees - 180, -1)
+    } else {
+        (360 - degrees, -1)
+    };
+    if quarter == 90 {
+        return Fixed::const_new(sign);
+    }
+    return PARTIAL_SINE_LUT[quarter as usize] * sign;
+}
+
+pub fn cos_degrees(degrees: i32) -> Fixed {
+    return sin_degrees(degrees + 90);
+}
+
 pub fn vector_len_2d(v: [Fixed; 2]) -> Fixed {
     return (v[0] * v[0] + v[1] * v[1]).sqrt();
 }
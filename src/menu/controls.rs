@@ -0,0 +1,108 @@
+//controls screen, opened from the options menu
+//the first row picks a preset with left and right, on the other rows ok waits for the button
+//to give that action, and back returns to the options
+
+use alloc::string::String;
+
+use crate::audio;
+use crate::input::mapping::*;
+use crate::renderer;
+use crate::textengine;
+use crate::textengine::draw::Align;
+
+use super::HEADINGHEIGHT;
+
+const ROW_TOP: u16 = 44;
+const ROW_HEIGHT: u16 = 14;
+const CURSOR_X: u16 = 10;
+const LABEL_X: u16 = 24;
+const VALUE_X: u16 = 224;
+
+pub fn preset_name(mapping: &Mapping) -> &'static str {
+    match mapping.matching_preset() {
+        Some(preset) => preset.name(),
+        None => "custom",
+    }
+}
+
+//the preset after or before the current one, custom mappings start from the first one
+pub fn next_preset(mapping: &Mapping, direction: i32) -> Preset {
+    let count = PRESETS.len() as i32;
+    let index = match mapping.matching_preset() {
+        Some(preset) => PRESETS.iter().position(|&p| p == preset).unwrap_or(0) as i32 + direction,
+        None => 0,
+    };
+    return PRESETS[index.rem_euclid(count) as usize];
+}
+
+pub fn show(
+    input: &mut agb::input::ButtonController,
+    page: &mut u16,
+    vblank: &agb::interrupt::VBlank,
+    sound: &agb::sound::dmg::Sound,
+    mapping: &mut Mapping,
+) {
+    let color = 48;
+    //the preset row and then a row per action
+    let row_count = REMAPPABLE.len() + 1;
+    let mut row: usize = 0;
+    let mut waiting = false;
+
+    loop {
+        input.update();
+
+        //the menu buttons don't change, so they can be read from the mapping while it is edited
+        if waiting {
+            //a button that would leave jump, use or pause without one is refused, and another can be pressed
+            if let Some(button) = just_pressed_bindable(input) {
+                if mapping.bind(REMAPPABLE[row - 1], button) {
+                    waiting = false;
+                    audio::play_sound(6, vblank, sound);
+                } else {
+                    audio::play_sound(4, vblank, sound);
+                }
+            }
+        } else if mapping.is_just_pressed(input, Action::Back) {
+            return;
+        } else if mapping.is_just_pressed(input, Action::Confirm) && row > 0 {
+            waiting = true;
+            audio::play_sound(6, vblank, sound);
+        } else if mapping.is_just_pressed(input, Action::MoveDown) {
+            row = (row + 1) % row_count;
+            audio::play_sound(0, vblank, sound);
+        } else if mapping.is_just_pressed(input, Action::MoveUp) {
+            row = (row + row_count - 1) % row_count;
+            audio::play_sound(0, vblank, sound);
+        } else if row == 0 {
+            let direction = if mapping.is_just_pressed(input, Action::MoveRight) {
+                1
+            } else if mapping.is_just_pressed(input, Action::MoveLeft) {
+                -1
+            } else {
+                0
+            };
+            if direction != 0 {
+                *mapping = Mapping::preset(next_preset(mapping, direction));
+                audio::play_sound(0, vblank, sound);
+            }
+        }
+
+        renderer::hw::fill(*page, 0);
+        textengine::draw::write_heading(HEADINGHEIGHT, "controls", color - 2, *page);
+        for i in 0..row_count {
+            let y = ROW_TOP + i as u16 * ROW_HEIGHT;
+            let (label, value) = if i == 0 {
+                ("preset", String::from(preset_name(mapping)))
+            } else if waiting && i == row {
+                (action_name(REMAPPABLE[i - 1]), String::from("press..."))
+            } else {
+                let action = REMAPPABLE[i - 1];
+                (action_name(action), button_name(mapping.buttons(action)))
+            };
+            textengine::draw::write_text(LABEL_X, y, label, color - 2, *page);
+            textengine::draw::write_text_aligned(VALUE_X, y, &value, Align::Right, color - 2, *page);
+        }
+        textengine::draw::write_text(CURSOR_X, ROW_TOP + row as u16 * ROW_HEIGHT, "*", color - 2, *page);
+        renderer::hw::flip(page);
+    }
+}
@@ -0,0 +1,186 @@
+//overhead map of the level, opened from the pause menu
+//draws the footprint of every entity from above, +z is up and +x is right like in the default camera view
+
+use crate::entities::boundingshapes::BoundingShape;
+use crate::entities::EntityEnum;
+use crate::fixed::Fixed;
+use crate::input::mapping::{Action, Mapping};
+use crate::player::Player;
+use crate::renderer;
+use crate::renderer::draw::draw_line_fixed;
+use crate::renderer::utils::get_color;
+use crate::textengine;
+
+use super::HEADINGHEIGHT;
+
+//screen area the map is fitted into
+const MAP_LEFT: i32 = 16;
+const MAP_RIGHT: i32 = 224;
+const MAP_TOP: i32 = 40;
+const MAP_BOTTOM: i32 = 152;
+//pixels, size of the player and finish markers
+const MARKER_SIZE: i32 = 3;
+const HEADING_LENGTH: i32 = 8;
+
+//palette color of an entity on the map, by what the player should know about it
+fn map_color(entity: &EntityEnum) -> u16 {
+    let color = match entity {
+        EntityEnum::Finish(_) => 1,
+        EntityEnum::Enemy(_) => 0,
+        EntityEnum::Mover(_) | EntityEnum::Conveyor(_) => 6,
+        EntityEnum::Crumbling(_) | EntityEnum::Bounce(_) => 4,
+        EntityEnum::Ice(_) => 8,
+        EntityEnum::Teleporter(_) | EntityEnum::Switch(_) => 9,
+        EntityEnum::Wind(_) => 2,
+        EntityEnum::Decoration(_) | EntityEnum::Pushable(_) | EntityEnum::Sign(_) => 3,
+        _ => 5,
+    };
+    return get_color(color, 0);
+}
+
+//corners of the footprint in x and z, cylinders are drawn as octagons
+fn footprint(entity: &EntityEnum) -> Option<([[Fixed; 2]; 8], usize)> {
+    let mut points = [[Fixed::const_new(0); 2]; 8];
+    match entity.bounding_shape() {
+        Some(BoundingShape::BoundingBox(b)) => {
+            points[..4].copy_from_slice(&b.data);
+            return Some((points, 4));
+        }
+        Some(BoundingShape::BoundingCylinder(c)) => {
+            for (i, point) in points.iter_mut().enumerate() {
+                let angle = Fixed::const_new(i as i32) / 8;
+                *point = [c.x + c.radius * angle.cos(), c.z + c.radius * angle.sin()];
+            }
+            return Some((points, 8));
+        }
+        None => return None,
+    }
+}
+
+struct MapView {
+    center: [Fixed; 2],
+    scale: Fixed,
+}
+
+impl MapView {
+    //fits everything in the level into the map area, keeping the proportions
+    fn fit(entities: &[EntityEnum], player: &Player) -> Self {
+        let mut min = [player.x, player.z];
+        let mut max = [player.x, player.z];
+        for entity in entities.iter().skip(2) {
+            if let EntityEnum::Empty(_) = entity {
+                break;
+            }
+            let Some((points, count)) = footprint(entity) else {
+                continue;
+            };
+            for point in points.iter().take(count) {
+                for axis in 0..2 {
+                    if point[axis] < min[axis] {
+                        min[axis] = point[axis];
+                    }
+                    if point[axis] > max[axis] {
+                        max[axis] = point[axis];
+                    }
+                }
+            }
+        }
+
+        let width = (max[0] - min[0]).max(Fixed::const_new(1));
+        let depth = (max[1] - min[1]).max(Fixed::const_new(1));
+        let scale_x = Fixed::const_new(MAP_RIGHT - MAP_LEFT) / width;
+        let scale_z = Fixed::const_new(MAP_BOTTOM - MAP_TOP) / depth;
+        Self {
+            center: [(min[0] + max[0]) / 2, (min[1] + max[1]) / 2],
+            scale: if scale_x < scale_z { scale_x } else { scale_z },
+        }
+    }
+
+    fn to_screen(&self, point: [Fixed; 2]) -> [Fixed; 2] {
+        return [
+            Fixed::const_new((MAP_LEFT + MAP_RIGHT) / 2) + (point[0] - self.center[0]) * self.scale,
+            Fixed::const_new((MAP_TOP + MAP_BOTTOM) / 2) - (point[1] - self.center[1]) * self.scale,
+        ];
+    }
+}
+
+fn draw_cross(point: [Fixed; 2], color: u16, page: u16) {
+    let size = Fixed::const_new(MARKER_SIZE);
+    draw_line_fixed(point[0] - size, point[1] - size, point[0] + size, point[1] + size, color, page);
+    draw_line_fixed(point[0] - size, point[1] + size, point[0] + size, point[1] - size, color, page);
+}
+
+pub fn draw_map(entities: &[EntityEnum], player: &Player, page: u16) {
+    let view = MapView::fit(entities, player);
+
+    for entity in entities.iter().skip(2) {
+        if let EntityEnum::Empty(_) = entity {
+            break;
+        }
+        let Some((points, count)) = footprint(entity) else {
+            continue;
+        };
+        let color = map_color(entity);
+        for i in 0..count {
+            let a = view.to_screen(points[i]);
+            let b = view.to_screen(points[(i + 1) % count]);
+            draw_line_fixed(a[0], a[1], b[0], b[1], color, page);
+        }
+        if let EntityEnum::Finish(_) = entity {
+            let center = view.to_screen([
+                (points[0][0] + points[2][0]) / 2,
+                (points[0][1] + points[2][1]) / 2,
+            ]);
+            draw_cross(center, color, page);
+        }
+    }
+
+    //the player is a box with a line in the direction they are facing
+    let color = get_color(4, 0);
+    let position = view.to_screen([player.x, player.z]);
+    let size = Fixed::const_new(MARKER_SIZE);
+    let corners = [
+        [position[0] - size, position[1] - size],
+        [position[0] + size, position[1] - size],
+        [position[0] + size, position[1] + size],
+        [position[0] - size, position[1] + size],
+    ];
+    for i in 0..4 {
+        let a = corners[i];
+        let b = corners[(i + 1) % 4];
+        draw_line_fixed(a[0], a[1], b[0], b[1], color, page);
+    }
+    let length = Fixed::const_new(HEADING_LENGTH);
+    draw_line_fixed(
+        position[0],
+        position[1],
+        position[0] + player.angle.cos() * length,
+        position[1] - player.angle.sin() * length,
+        color,
+        page,
+    );
+}
+
+//shows the map until the back or ok button is pressed
+pub fn show(
+    input: &mut agb::input::ButtonController,
+    page: &mut u16,
+    entities: &[EntityEnum],
+    player: &Player,
+    controls: &Mapping,
+) {
+    let color = 48;
+    loop {
+        input.update();
+        if controls.is_just_pressed(input, Action::Back)
+            || controls.is_just_pressed(input, Action::Confirm)
+        {
+            return;
+        }
+
+        renderer::hw::fill(*page, 0);
+        textengine::draw::write_heading(HEADINGHEIGHT, "map", color - 2, *page);
+        draw_map(entities, player, *page);
+        renderer::hw::flip(page);
+    }
+}
@@ -1,137 +1,27 @@
-use agb::InternalAllocator;
 use alloc::format;
 use alloc::string::ToString;
-use alloc::vec::Vec;
+use alloc::vec;
+
+pub mod controls;
+pub mod map;
+pub mod options;
+pub mod title;
+pub mod world;
 
 use crate::audio;
-use crate::levels;
+use crate::input::mapping::{button_name, Action, Mapping};
 use crate::renderer;
 use crate::textengine;
-use crate::utils;
 use crate::utils::GameState;
 
 const HEADINGHEIGHT: u16 = 20;
 
-pub fn levelmenu(
-    selected_level: usize,
-    input: &mut agb::input::ButtonController,
-    page: &mut u16,
-    vblank: &agb::interrupt::VBlank,
-    sound: &agb::sound::dmg::Sound,
-    completed_levels: &Vec<bool, InternalAllocator>,
-) -> (usize, bool) {
-    let levelcount: usize = levels::levelstore::LEVELS.len();
-
-    let mut selected_level: i32 = selected_level as i32;
-
-    let character_count = levelcount.to_string().len();
-
-    let x_center: i32 = 120;
-    let total_width: i32 = (character_count * 12 - 4) as i32;
-    let word_separation: i32 = 8;
-    let padded_width = total_width + ((character_count as i32) * word_separation);
-    let middle_level_left = x_center - (total_width / 2);
-
-    let y: u16 = 80;
-
-    loop {
-        renderer::hw::fill(*page, 0);
-        let color = 48;
-
-        textengine::draw::write_line(52, HEADINGHEIGHT, "select level", color - 2, *page);
-        //textengine::draw::write_line(86, 100, "a-play", color - 2, *page);
-
-        input.update();
-
-        if input.is_just_pressed(agb::input::Button::A) {
-            break;
-        }
-        if input.is_just_pressed(agb::input::Button::RIGHT) {
-            selected_level += 1;
-            audio::play_sound(0, &vblank, &sound);
-        }
-        if input.is_just_pressed(agb::input::Button::LEFT) {
-            selected_level -= 1;
-            audio::play_sound(0, &vblank, &sound);
-        }
-        if input.is_just_pressed(agb::input::Button::B) {
-            return (0, true);
-        }
-
-        utils::clamp(&mut selected_level, 0, (levelcount - 1) as i32);
-        let first_visible_level = selected_level - 10;
-
-        for i in first_visible_level..selected_level + 11 {
-            if i >= 0 && (i as usize) < levelcount {
-                let level = selected_level - i;
-
-                let x: i32 = middle_level_left + (i - selected_level) * padded_width;
-                if x <= 10 || x + padded_width >= 240 {
-                    continue;
-                }
-                let x = x as u16;
-                let mut shade: u16 = color - ((level) * 3).abs() as u16;
-                if shade < 34 {
-                    shade = 34;
-                }
-
-                let distance_from_middle = (level).abs() as u16;
-                let v_offset; // = 0;
-                if distance_from_middle == 0 {
-                    v_offset = 0;
-                } else if distance_from_middle == 1 {
-                    v_offset = 1;
-                } else if distance_from_middle == 2 {
-                    v_offset = 4;
-                } else {
-                    v_offset = 8;
-                }
-                let level_str = (i + 1).to_string();
-                let level_str = format!("{:0>width$}", level_str, width = character_count);
-                textengine::draw::write_line(x, y - v_offset, &level_str, shade, *page);
-
-                let completed = completed_levels[i as usize];
-                if completed {
-                    textengine::draw::write_tile(
-                        (x + total_width as u16 - 2) as u16,
-                        y - v_offset,
-                        37,
-                        10,
-                        *page,
-                    );
-                }
-            }
-        }
-        renderer::hw::flip(page);
-    }
-
-    return (selected_level as usize, false);
-}
-
-pub fn presstart(input: &mut agb::input::ButtonController, page: &mut u16) {
-    renderer::hw::fill(*page, 0);
-    let color = 48;
-
-    textengine::draw::write_line(58, 140, "press start", color - 2, *page);
-
-    renderer::hw::flip(page);
-
-    loop {
-        input.update();
-
-        if input.is_just_pressed(agb::input::Button::A)
-            || input.is_just_pressed(agb::input::Button::START)
-        {
-            break;
-        }
-    }
-}
-
 pub fn mainmenu(
     input: &mut agb::input::ButtonController,
     page: &mut u16,
     vblank: &agb::interrupt::VBlank,
     sound: &agb::sound::dmg::Sound,
+    controls: &Mapping,
 ) -> u16 {
     let color = 48;
     let mut option = 0;
@@ -139,10 +29,11 @@ pub fn mainmenu(
     loop {
         renderer::hw::fill(*page, 0);
 
-        textengine::draw::write_line(70, HEADINGHEIGHT, "main menu", color - 2, *page);
+        textengine::draw::write_heading(HEADINGHEIGHT, "main menu", color - 2, *page);
 
-        textengine::draw::write_line(50, 80, "select level", color - 2, *page);
-        textengine::draw::write_line(50, 100, "keys", color - 2, *page);
+        textengine::draw::write_text(50, 80, "select level", color - 2, *page);
+        textengine::draw::write_text(50, 100, "options", color - 2, *page);
+        textengine::draw::write_text(50, 120, "keys", color - 2, *page);
 
         textengine::draw::write_line(40, 80 + 20 * option, "*", color - 2, *page);
 
@@ -150,85 +41,111 @@ pub fn mainmenu(
 
         input.update();
 
-        if input.is_just_pressed(agb::input::Button::A) {
+        if controls.is_just_pressed(input, Action::Confirm) {
             return option;
         }
-        if input.is_just_pressed(agb::input::Button::DOWN)
-            || input.is_just_pressed(agb::input::Button::UP)
-        {
-            option = 1 - option;
+        if controls.is_just_pressed(input, Action::MoveDown) {
+            option = (option + 1) % 3;
+            audio::play_sound(0, &vblank, &sound);
+        }
+        if controls.is_just_pressed(input, Action::MoveUp) {
+            option = (option + 2) % 3;
             audio::play_sound(0, &vblank, &sound);
         }
     }
 }
 
-pub fn info(input: &mut agb::input::ButtonController, page: &mut u16) {
+pub fn info(input: &mut agb::input::ButtonController, page: &mut u16, controls: &Mapping) {
     let color = 48;
 
     renderer::hw::fill(*page, 0);
 
-    textengine::draw::write_line(94, HEADINGHEIGHT, "keys", color - 2, *page);
-
-    textengine::draw::write_line(10, 60, "dpad-move", color - 2, *page);
-    textengine::draw::write_line(10, 80, "a-jump", color - 2, *page);
-    textengine::draw::write_line(10, 100, "b-toggle switch", color - 2, *page);
-    textengine::draw::write_line(10, 120, "select-camera mode", color - 2, *page);
+    textengine::draw::write_heading(HEADINGHEIGHT, "keys", color - 2, *page);
+
+    let camera_left = button_name(controls.buttons(Action::CameraLeft));
+    let camera_right = button_name(controls.buttons(Action::CameraRight));
+    let mut lines = vec![
+        "✚ move".to_string(),
+        controls.describe(Action::Jump),
+        controls.describe(Action::Use),
+        format!("{}{} turn camera", camera_left, camera_right),
+        format!("{}+{}+✚ tilt, zoom", camera_left, camera_right),
+    ];
+    //precision and whatever it took the button from can be left without one
+    for action in [Action::CameraMode, Action::Pause, Action::Precision] {
+        if !controls.buttons(action).is_empty() {
+            lines.push(controls.describe(action));
+        }
+    }
+    for (i, line) in lines.iter().enumerate() {
+        textengine::draw::write_text(10, 44 + 14 * i as u16, line, color - 2, *page);
+    }
 
     renderer::hw::flip(page);
 
     loop {
         input.update();
 
-        if input.is_just_pressed(agb::input::Button::B) {
+        if controls.is_just_pressed(input, Action::Back) {
             return;
         }
     }
 }
 
+//the row of the map in the pause menu, the menu opens on it again when the map is closed
+pub const PAUSE_MAP: u16 = 2;
+
+//returns GameState::Map when the map is picked, the caller shows it and draws the level again
+//on both pages before opening the pause menu again, as the map covers the whole screen
 pub fn pause(
     input: &mut agb::input::ButtonController,
     page: &mut u16,
     vblank: &agb::interrupt::VBlank,
     sound: &agb::sound::dmg::Sound,
+    controls: &Mapping,
+    choice: u16,
 ) -> GameState {
     let color = 48;
 
-    let mut choice: u16 = 0;
+    let mut choice: u16 = choice;
 
     loop {
         input.update();
 
-        if input.is_just_pressed(agb::input::Button::START | agb::input::Button::A) {
+        if controls.is_just_pressed(input, Action::Confirm) {
             audio::play_sound(6, &vblank, &sound);
             if choice == 0 {
                 return GameState::Playing;
             } else if choice == 1 {
                 return GameState::Failed;
+            } else if choice == PAUSE_MAP {
+                return GameState::Map;
             } else {
                 return GameState::Menu;
             }
         }
-        if input.is_just_pressed(agb::input::Button::B) {
+        if controls.is_just_pressed(input, Action::Back) {
             audio::play_sound(4, &vblank, &sound);
             return GameState::Playing;
         }
 
-        if input.is_just_pressed(agb::input::Button::DOWN) {
-            choice = (choice + 1) % 3;
+        if controls.is_just_pressed(input, Action::MoveDown) {
+            choice = (choice + 1) % 4;
             audio::play_sound(0, &vblank, &sound);
         }
-        if input.is_just_pressed(agb::input::Button::UP) {
-            choice = (choice + 2) % 3;
+        if controls.is_just_pressed(input, Action::MoveUp) {
+            choice = (choice + 3) % 4;
             audio::play_sound(0, &vblank, &sound);
         }
 
-        renderer::hw::fill_area(*page, 42, 50, 190, 50, 118);
+        renderer::hw::fill_area(*page, 42, 50, 190, 50, 138);
 
-        textengine::draw::write_line(86, HEADINGHEIGHT, "paused", color - 2, *page);
+        textengine::draw::write_heading(HEADINGHEIGHT, "paused", color - 2, *page);
 
-        textengine::draw::write_line(70, 60, "continue", color - 2, *page);
-        textengine::draw::write_line(70, 80, "restart", color - 2, *page);
-        textengine::draw::write_line(70, 100, "quit", color - 2, *page);
+        textengine::draw::write_text(70, 60, "continue", color - 2, *page);
+        textengine::draw::write_text(70, 80, "restart", color - 2, *page);
+        textengine::draw::write_text(70, 100, "map", color - 2, *page);
+        textengine::draw::write_text(70, 120, "quit", color - 2, *page);
 
         textengine::draw::write_line(60, 60 + 20 * choice, "*", color - 2, *page);
 
@@ -0,0 +1,146 @@
+//options screen, opened from the main menu
+//up and down pick an option, left and right change it and back returns to the main menu
+//ok on the controls opens the controls screen, the caller stores the options in the save afterwards
+
+use alloc::string::{String, ToString};
+
+use crate::audio;
+use crate::input::mapping::{Action, Mapping};
+use crate::options::*;
+use crate::renderer;
+use crate::textengine;
+use crate::textengine::draw::Align;
+
+use super::HEADINGHEIGHT;
+
+const ROW_TOP: u16 = 44;
+const ROW_HEIGHT: u16 = 14;
+const CURSOR_X: u16 = 10;
+const LABEL_X: u16 = 24;
+//values are right aligned to this
+const VALUE_X: u16 = 224;
+
+const LABELS: [&str; 8] = [
+    "camera follow",
+    "camera speed",
+    "invert camera",
+    "sound",
+    "volume",
+    "draw distance",
+    "timer",
+    "controls",
+];
+
+fn on_off(value: bool) -> String {
+    return if value { "on" } else { "off" }.to_string();
+}
+
+fn value_text(options: &Options, row: usize) -> String {
+    match row {
+        0 => on_off(options.camera_follow),
+        1 => options.camera_speed.to_string(),
+        2 => on_off(options.invert_camera),
+        3 => on_off(options.sound),
+        4 => options.volume.to_string(),
+        5 => options.draw_distance.to_string(),
+        6 => if options.show_timer { "shown" } else { "hidden" }.to_string(),
+        _ => super::controls::preset_name(&options.mapping).to_string(),
+    }
+}
+
+//direction is 1 for right and -1 for left, on and off options flip either way
+fn change(options: &mut Options, row: usize, direction: i8) {
+    match row {
+        0 => options.camera_follow = !options.camera_follow,
+        1 => {
+            options.camera_speed = options
+                .camera_speed
+                .saturating_add_signed(direction)
+                .clamp(MIN_CAMERA_SPEED, MAX_CAMERA_SPEED)
+        }
+        2 => options.invert_camera = !options.invert_camera,
+        3 => options.sound = !options.sound,
+        4 => {
+            options.volume = options
+                .volume
+                .saturating_add_signed(direction)
+                .clamp(MIN_VOLUME, MAX_VOLUME)
+        }
+        5 => {
+            options.draw_distance = options
+                .draw_distance
+                .saturating_add_signed(direction * DRAW_DISTANCE_STEP as i8)
+                .clamp(MIN_DRAW_DISTANCE, MAX_DRAW_DISTANCE)
+        }
+        6 => options.show_timer = !options.show_timer,
+        _ => {
+            let preset = super::controls::next_preset(&options.mapping, direction as i32);
+            options.mapping = Mapping::preset(preset);
+        }
+    }
+}
+
+pub fn show(
+    input: &mut agb::input::ButtonController,
+    page: &mut u16,
+    vblank: &agb::interrupt::VBlank,
+    sound: &agb::sound::dmg::Sound,
+    options: &mut Options,
+) {
+    let color = 48;
+    let mut row: usize = 0;
+
+    loop {
+        input.update();
+        let controls = options.mapping;
+
+        if controls.is_just_pressed(input, Action::Back) {
+            return;
+        }
+        if controls.is_just_pressed(input, Action::Confirm) && row == LABELS.len() - 1 {
+            audio::play_sound(6, vblank, sound);
+            super::controls::show(input, page, vblank, sound, &mut options.mapping);
+            audio::play_sound(4, vblank, sound);
+            continue;
+        }
+        if controls.is_just_pressed(input, Action::MoveDown) {
+            row = (row + 1) % LABELS.len();
+            audio::play_sound(0, vblank, sound);
+        }
+        if controls.is_just_pressed(input, Action::MoveUp) {
+            row = (row + LABELS.len() - 1) % LABELS.len();
+            audio::play_sound(0, vblank, sound);
+        }
+
+        let direction = if controls.is_just_pressed(input, Action::MoveRight) {
+            1
+        } else if controls.is_just_pressed(input, Action::MoveLeft) {
+            -1
+        } else {
+            0
+        };
+        if direction != 0 {
+            change(options, row, direction);
+            //sound changes are heard right away, with the new volume
+            audio::set_volume(options.sound, options.volume);
+            audio::play_sound(0, vblank, sound);
+        }
+
+        renderer::hw::fill(*page, 0);
+        textengine::draw::write_heading(HEADINGHEIGHT, "options", color - 2, *page);
+        for (i, label) in LABELS.iter().enumerate() {
+            let y = ROW_TOP + i as u16 * ROW_HEIGHT;
+            textengine::draw::write_text(LABEL_X, y, label, color - 2, *page);
+            textengine::draw::write_text_aligned(
+                VALUE_X,
+                y,
+                &value_text(options, i),
+                Align::Right,
+                color - 2,
+                *page,
+            );
+        }
+        textengine::draw::write_text(CURSOR_X, ROW_TOP + row as u16 * ROW_HEIGHT, "*", color - 2, *page);
+        renderer::hw::flip(page);
+    }
+}
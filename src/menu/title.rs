@@ -0,0 +1,142 @@
+//title screen, the camera circles the blob standing on a platform next to the finish portal
+//when nothing is pressed for a while the title gives way to the demo of the first level
+
+use agb::input::Button;
+use agb::InternalAllocator;
+use alloc::vec::Vec;
+use serde_json_core::from_slice;
+
+use crate::camera::Camera;
+use crate::entities::body::Body;
+use crate::entities::cube::Cube;
+use crate::entities::EntityEnum;
+use crate::fixed::Fixed;
+use crate::input::mapping::{Action, Mapping};
+use crate::renderer;
+use crate::renderer::polygon::Polygon;
+use crate::textengine;
+use crate::textengine::draw::Align;
+
+//the platform and the portal, written like a level
+const SCENE: &str = r#"[
+    { "type": "rectangle", "data": { "xsize": 6, "ysize": 1, "zsize": 3, "x": 0, "y": 0, "z": 0, "color": 6 } },
+    { "type": "finish", "data": { "size": 3, "x": 1.5, "y": 3, "z": 0, "color": 2 } }
+]"#;
+//top of the platform, where the blob stands
+const GROUND: Fixed = Fixed::from_raw(128);
+const BLOB_X: Fixed = Fixed::from_raw(-384);
+const BLOB_COLOR: u16 = 1;
+
+//point the camera circles around, and how far away and high up it is
+const FOCUS: [Fixed; 3] = [Fixed::const_new(0), Fixed::const_new(1), Fixed::const_new(0)];
+const CAMERA_DISTANCE: Fixed = Fixed::const_new(9);
+const CAMERA_PITCH: i32 = 20;
+//frames for the camera to move one step of 256 around the scene
+const ORBIT_FRAMES: i32 = 2;
+
+//frames without a button pressed before the demo starts
+const IDLE_FRAMES: i32 = 300;
+const RENDER_DISTANCE: Fixed = Fixed::const_new(64);
+const TEXT_COLOR: u16 = 46;
+
+fn load_scene() -> Vec<EntityEnum> {
+    let (mut entities, _): (Vec<EntityEnum>, usize) = from_slice(SCENE.as_bytes()).unwrap();
+
+    //the blob is put together like the player in main
+    let mut body = EntityEnum::Body(Body::default());
+    body.set_color(BLOB_COLOR);
+    body.set_size(Fixed::const_new(1));
+    let mut head = EntityEnum::Cube(Cube::default());
+    head.set_color(BLOB_COLOR);
+    head.set_size(Fixed::from_raw(160));
+    entities.push(body);
+    entities.push(head);
+
+    for entity in entities.iter_mut() {
+        entity.set_x_rotation(Fixed::const_new(0));
+        entity.set_y_rotation(Fixed::const_new(0));
+        entity.set_z_rotation(Fixed::const_new(0));
+        entity.reload_rotation_matrices();
+        entity.recalculate_points();
+        entity.refresh_model_matrix();
+    }
+    return entities;
+}
+
+//returns true when the title timed out and the demo should play, false when start was pressed
+pub fn show(input: &mut agb::input::ButtonController, page: &mut u16, controls: &Mapping) -> bool {
+    let mut entities = load_scene();
+    let portal = entities.len() - 3;
+    let body = entities.len() - 2;
+    let head = entities.len() - 1;
+
+    let mut camera = Camera::default();
+    camera.pitch = CAMERA_PITCH;
+    camera.distance = CAMERA_DISTANCE;
+
+    let mut polygons: Vec<Polygon, InternalAllocator> = Vec::new_in(InternalAllocator);
+    let mut polygon_indices: Vec<usize, InternalAllocator> = Vec::new_in(InternalAllocator);
+    let mut frame: i32 = 0;
+    let mut idle_frames: i32 = 0;
+
+    loop {
+        input.update();
+        frame += 1;
+
+        if controls.is_just_pressed(input, Action::Confirm) {
+            return false;
+        }
+        if input.is_pressed(Button::all()) {
+            idle_frames = 0;
+        } else {
+            idle_frames += 1;
+            if idle_frames >= IDLE_FRAMES {
+                return true;
+            }
+        }
+
+        camera.orbit((frame / ORBIT_FRAMES) as usize % 256);
+        camera.x = camera.local_x + FOCUS[0];
+        camera.y = camera.local_y + FOCUS[1];
+        camera.z = camera.local_z + FOCUS[2];
+
+        //the blob hops on the spot and slowly turns, the portal spins the other way
+        let hop = Fixed::from_raw(frame * 6).sin().abs() / 2;
+        let spin = Fixed::from_raw(frame);
+        let body_height = entities[body].get_height();
+        let head_height = entities[head].get_height();
+        entities[body].set_y_offset(GROUND + hop + body_height / 2);
+        entities[head].set_y_offset(GROUND + hop + body_height + head_height / 2);
+        for i in [body, head] {
+            entities[i].set_x_offset(BLOB_X);
+            entities[i].set_z_offset(Fixed::const_new(0));
+            entities[i].set_y_rotation(-spin);
+            entities[i].refresh_model_matrix();
+        }
+        entities[portal].set_y_rotation(spin);
+        entities[portal].refresh_model_matrix();
+
+        for entity in entities.iter_mut() {
+            entity.render(&camera, &mut polygons, RENDER_DISTANCE);
+        }
+        for i in 0..polygons.len() {
+            polygon_indices.push(i);
+        }
+        polygon_indices.sort_by(|&a, &b| {
+            polygons[b]
+                .distance_from_camera
+                .cmp(&polygons[a].distance_from_camera)
+        });
+
+        renderer::hw::fill(*page, 128);
+        renderer::render::render_polygons(&polygons, &polygon_indices, 0, *page);
+        //blinks so it isn't missed while the scene moves
+        if frame / 16 % 2 == 0 {
+            textengine::draw::write_aligned(120, 140, "press start", Align::Center, TEXT_COLOR, *page);
+        }
+        renderer::hw::flip(page);
+
+        polygons.clear();
+        polygon_indices.clear();
+    }
+}
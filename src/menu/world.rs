@@ -0,0 +1,276 @@
+//world map for picking a level, drawn with the 3d renderer
+//every level is an island, the levels of a chapter are in a row from left to right
+//and the chapters follow each other away from the camera
+//left and right pick a level, up and down a chapter, ok plays the level if it is open
+
+use agb::InternalAllocator;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::audio;
+use crate::camera::Camera;
+use crate::entities::gem;
+use crate::fixed::Fixed;
+use crate::input::mapping::{Action, Mapping};
+use crate::levels::chapters;
+use crate::levels::levelstore::{BONUS_LEVELS, CHAPTERS, LEVEL_CHAPTERS, LEVEL_GEMS};
+use crate::renderer;
+use crate::renderer::polygon::Polygon;
+use crate::save::LevelProgress;
+use crate::textengine;
+use crate::textengine::draw::Align;
+use crate::utils;
+
+use super::HEADINGHEIGHT;
+
+const ISLAND_SPACING: Fixed = Fixed::const_new(4);
+//islands go back and forth by this much, so the row looks like a path
+const ISLAND_SWAY: Fixed = Fixed::from_raw(384);
+const CHAPTER_SPACING: Fixed = Fixed::const_new(12);
+const ISLAND_SIZE: Fixed = Fixed::from_raw(640);
+const ISLAND_HEIGHT: Fixed = Fixed::from_raw(192);
+const STONE_SIZE: Fixed = Fixed::from_raw(128);
+const GEM_SIZE: Fixed = Fixed::from_raw(96);
+const GEM_GAP: Fixed = Fixed::from_raw(128);
+const MARKER_SIZE: Fixed = Fixed::from_raw(128);
+
+//where the camera is from the selected island, it looks down at it from behind
+const CAMERA_OFFSET: [Fixed; 3] = [Fixed::const_new(0), Fixed::const_new(5), Fixed::const_new(-8)];
+const CAMERA_PITCH: Fixed = Fixed::from_raw(-22);
+//part of the way to the selected island the camera moves each frame
+const CAMERA_EASE: i32 = 4;
+
+//palette colors
+const COMPLETED_COLOR: u16 = 6;
+const OPEN_COLOR: u16 = 7;
+const LOCKED_COLOR: u16 = 3;
+const STONE_COLOR: u16 = 7;
+const GEM_COLOR: u16 = 4;
+const MISSING_GEM_COLOR: u16 = 5;
+const MARKER_COLOR: u16 = 1;
+
+const TEXT_COLOR: u16 = 46;
+const TITLE_Y: u16 = 130;
+const STATUS_Y: u16 = 144;
+
+//center of the top of a level's island
+fn island_position(level: usize) -> [Fixed; 3] {
+    let chapter = LEVEL_CHAPTERS[level];
+    let place = chapters::chapter_levels(chapter).position(|l| l == level).unwrap_or(0);
+    let sway = if place % 2 == 0 { -ISLAND_SWAY } else { ISLAND_SWAY };
+    return [
+        ISLAND_SPACING * place as i32,
+        Fixed::const_new(0),
+        CHAPTER_SPACING * chapter as i32 + sway,
+    ];
+}
+
+fn render_box(
+    center: [Fixed; 3],
+    size: [Fixed; 3],
+    color: u16,
+    camera: &Camera,
+    polygons: &mut Vec<Polygon, InternalAllocator>,
+) {
+    let points = utils::rectangle_model_points(size[0], size[1], size[2]);
+    renderer::render::render_rect(
+        &points,
+        center[0],
+        center[1],
+        center[2],
+        Fixed::const_new(0),
+        camera,
+        color,
+        polygons,
+        false,
+    );
+}
+
+fn render_chapter(
+    chapter: usize,
+    selected_level: usize,
+    progress: &[LevelProgress],
+    frame: i32,
+    camera: &Camera,
+    polygons: &mut Vec<Polygon, InternalAllocator>,
+) {
+    let mut previous: Option<[Fixed; 3]> = None;
+    for level in chapters::chapter_levels(chapter) {
+        let top = island_position(level);
+        let unlocked = chapters::level_unlocked(level, progress);
+        let color = if progress[level].completed {
+            COMPLETED_COLOR
+        } else if unlocked {
+            OPEN_COLOR
+        } else {
+            LOCKED_COLOR
+        };
+        let center = [top[0], top[1] - ISLAND_HEIGHT / 2, top[2]];
+        render_box(center, [ISLAND_SIZE, ISLAND_HEIGHT, ISLAND_SIZE], color, camera, polygons);
+
+        //a stepping stone leads to every open island
+        if let Some(previous) = previous {
+            if unlocked {
+                let stone = [(previous[0] + top[0]) / 2, top[1], (previous[2] + top[2]) / 2];
+                render_box(stone, [STONE_SIZE, STONE_SIZE, STONE_SIZE], STONE_COLOR, camera, polygons);
+            }
+        }
+        previous = Some(top);
+
+        //the gems of the level in a row above the island, the collected ones in color
+        let gem_count = LEVEL_GEMS[level] as i32;
+        let spin = Fixed::from_raw(frame * 3);
+        for i in 0..gem_count {
+            let x = top[0] + GEM_GAP * (2 * i - gem_count + 1) / 2;
+            let color = if progress[level].gems & (1 << i) != 0 {
+                GEM_COLOR
+            } else {
+                MISSING_GEM_COLOR
+            };
+            renderer::render::render_triangle_list(
+                &gem::model_points(GEM_SIZE, spin),
+                6,
+                &gem::TRIANGLES,
+                &gem::SHADES,
+                x,
+                top[1] + GEM_SIZE,
+                top[2] - ISLAND_SIZE / 4,
+                camera,
+                color,
+                polygons,
+                false,
+            );
+        }
+
+        if level == selected_level {
+            let bob = Fixed::from_raw(frame * 4).sin() / 8;
+            let marker = [top[0], top[1] + MARKER_SIZE + bob, top[2]];
+            render_box(marker, [MARKER_SIZE, MARKER_SIZE, MARKER_SIZE], MARKER_COLOR, camera, polygons);
+        }
+    }
+}
+
+fn status_text(level: usize, progress: &[LevelProgress]) -> String {
+    if !chapters::level_unlocked(level, progress) {
+        return chapters::unlock_hint(level, progress);
+    }
+    let gems = LEVEL_GEMS[level] as usize;
+    let done = if progress[level].completed { "done" } else { "open" };
+    if gems == 0 {
+        return String::from(done);
+    }
+    return format!("{}, gems {}/{}", done, progress[level].gem_count(), gems);
+}
+
+//the first level of the chapter that is open and not finished yet, or its first level
+fn chapter_start(chapter: usize, progress: &[LevelProgress]) -> usize {
+    let mut levels = chapters::chapter_levels(chapter);
+    let first = levels.next().unwrap_or(0);
+    return chapters::chapter_levels(chapter)
+        .find(|&level| chapters::level_unlocked(level, progress) && !progress[level].completed)
+        .unwrap_or(first);
+}
+
+pub fn show(
+    selected_level: usize,
+    input: &mut agb::input::ButtonController,
+    page: &mut u16,
+    vblank: &agb::interrupt::VBlank,
+    sound: &agb::sound::dmg::Sound,
+    progress: &[LevelProgress],
+    controls: &Mapping,
+) -> (usize, bool) {
+    let mut selected_level = selected_level;
+    let mut camera = Camera::default();
+    camera.set_x_rotation(CAMERA_PITCH);
+    camera.set_y_rotation(Fixed::const_new(0));
+    camera.set_z_rotation(Fixed::const_new(0));
+    let start = island_position(selected_level);
+    camera.x = start[0] + CAMERA_OFFSET[0];
+    camera.y = start[1] + CAMERA_OFFSET[1];
+    camera.z = start[2] + CAMERA_OFFSET[2];
+
+    let mut polygons: Vec<Polygon, InternalAllocator> = Vec::new_in(InternalAllocator);
+    let mut polygon_indices: Vec<usize, InternalAllocator> = Vec::new_in(InternalAllocator);
+    let mut frame: i32 = 0;
+
+    loop {
+        input.update();
+        frame += 1;
+
+        let chapter = LEVEL_CHAPTERS[selected_level];
+        if controls.is_just_pressed(input, Action::Confirm) {
+            if chapters::level_unlocked(selected_level, progress) {
+                return (selected_level, false);
+            }
+            audio::play_sound(4, vblank, sound);
+        }
+        if controls.is_just_pressed(input, Action::Back) {
+            return (0, true);
+        }
+        if controls.is_just_pressed(input, Action::MoveRight) {
+            if let Some(next) = chapters::chapter_levels(chapter).find(|&level| level > selected_level) {
+                selected_level = next;
+                audio::play_sound(0, vblank, sound);
+            }
+        }
+        if controls.is_just_pressed(input, Action::MoveLeft) {
+            if let Some(previous) = chapters::chapter_levels(chapter).filter(|&level| level < selected_level).last() {
+                selected_level = previous;
+                audio::play_sound(0, vblank, sound);
+            }
+        }
+        if controls.is_just_pressed(input, Action::MoveUp) && chapter + 1 < CHAPTERS.len() {
+            selected_level = chapter_start(chapter + 1, progress);
+            audio::play_sound(0, vblank, sound);
+        }
+        if controls.is_just_pressed(input, Action::MoveDown) && chapter > 0 {
+            selected_level = chapter_start(chapter - 1, progress);
+            audio::play_sound(0, vblank, sound);
+        }
+
+        let target = island_position(selected_level);
+        camera.x += (target[0] + CAMERA_OFFSET[0] - camera.x) / CAMERA_EASE;
+        camera.y += (target[1] + CAMERA_OFFSET[1] - camera.y) / CAMERA_EASE;
+        camera.z += (target[2] + CAMERA_OFFSET[2] - camera.z) / CAMERA_EASE;
+
+        //only the chapters next to the selected one can be in view
+        let chapter = LEVEL_CHAPTERS[selected_level];
+        for shown in chapter.saturating_sub(1)..(chapter + 2).min(CHAPTERS.len()) {
+            render_chapter(shown, selected_level, progress, frame, &camera, &mut polygons);
+        }
+        for i in 0..polygons.len() {
+            polygon_indices.push(i);
+        }
+        polygon_indices.sort_by(|&a, &b| {
+            polygons[b]
+                .distance_from_camera
+                .cmp(&polygons[a].distance_from_camera)
+        });
+
+        renderer::hw::fill(*page, 128);
+        renderer::render::render_polygons(&polygons, &polygon_indices, 0, *page);
+
+        let chapter_name = CHAPTERS[chapter].name;
+        textengine::draw::write_heading(HEADINGHEIGHT, chapter_name, TEXT_COLOR, *page);
+        let title = if BONUS_LEVELS[selected_level] {
+            format!("bonus level {}", selected_level + 1)
+        } else {
+            format!("level {}", selected_level + 1)
+        };
+        textengine::draw::write_text_aligned(120, TITLE_Y, &title, Align::Center, TEXT_COLOR, *page);
+        textengine::draw::write_text_aligned(
+            120,
+            STATUS_Y,
+            &status_text(selected_level, progress),
+            Align::Center,
+            TEXT_COLOR,
+            *page,
+        );
+        renderer::hw::flip(page);
+
+        polygons.clear();
+        polygon_indices.clear();
+    }
+}
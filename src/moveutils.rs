@@ -1,16 +1,17 @@
-use crate::{entities::{boundingshapes::BoundingCylinder, utils::horizontal_collision_check, EntityEnum}, fixed::Fixed, math::{directional_vector_2d, vector_len_2d}, player::Player};
+use crate::{effects::Carry, entities::{boundingshapes::{BoundingBox, BoundingCylinder}, utils::{box_collision_check, check_block_above, check_support_below, horizontal_collision, horizontal_collision_check}, EntityEnum}, fixed::Fixed, math::{directional_vector_2d, matmul, vector_len_2d}, player::Player, utils::y_rotation_matrix};
 
 
 
 
 pub fn attempt_move(
     player: &mut Player,
-    entities: &[EntityEnum],
+    entities: &mut [EntityEnum],
     body: &BoundingCylinder,
 ) -> bool {
 
-	let x = player.move_x;
-	let z = player.move_z;
+	let x = player.move_x + player.force_x;
+	let z = player.move_z + player.force_z;
+	player.wall_normal = None;
 
 	if x == Fixed::const_new(0) && z == Fixed::const_new(0) {
 		return true;
@@ -19,11 +20,20 @@ pub fn attempt_move(
 
     let potential_position: BoundingCylinder = BoundingCylinder::new_with_offset(body, x, z);
     //check if we can move in both x and z dirs
-    let (wallangle, collision) = horizontal_collision_check(entities, potential_position);
-    if !collision {
+    let Some((index, wallangle)) = horizontal_collision(entities, potential_position) else {
         player.move_to(x, z);
         return true;
+    };
+
+    //walking into a pushable block moves it along, if there is room for it
+    if push_block(entities, index, x, z) {
+        let potential_position: BoundingCylinder = BoundingCylinder::new_with_offset(body, x, z);
+        if !horizontal_collision_check(entities, potential_position).1 {
+            player.move_to(x, z);
+            return true;
+        }
     }
+    player.wall_normal = Some(wall_normal(wallangle, x, z));
 
 	
     // from the wall angle, get the 4 directions of the wall
@@ -66,6 +76,127 @@ pub fn attempt_move(
     return false;
 }
 
+//of the four directions along the wall edges, the one pointing most against the movement
+fn wall_normal(wall_angle: Fixed, x: Fixed, z: Fixed) -> [Fixed; 2] {
+    let mut normal = directional_vector_2d(wall_angle);
+    for i in 1..4 {
+        let direction = directional_vector_2d(wall_angle + Fixed::from_raw(64) * i);
+        if direction[0] * x + direction[1] * z < normal[0] * x + normal[1] * z {
+            normal = direction;
+        }
+    }
+    return normal;
+}
+
+//keeps the player standing on a moving platform in place on it
+//the horizontal part is stopped by walls like in attempt_move and the vertical part by ceilings,
+//the player isn't treated as falling or landing while carried
+pub fn carry(
+    player: &mut Player,
+    entities: &[EntityEnum],
+    body: &BoundingCylinder,
+    player_box: &BoundingBox,
+    carry: &Carry,
+) {
+    //only landed players stick to the platform, one just above it keeps falling on its own
+    if !player.is_grounded() {
+        return;
+    }
+
+    let mut x = carry.move_x;
+    let mut z = carry.move_z;
+    if carry.turn != Fixed::const_new(0) {
+        let offset = [player.x - carry.pivot_x, Fixed::const_new(0), player.z - carry.pivot_z];
+        let rotated = matmul(y_rotation_matrix(carry.turn), offset);
+        x += rotated[0] - offset[0];
+        z += rotated[2] - offset[2];
+        //the body is drawn with the negated angle
+        player.angle = (player.angle - carry.turn).modulo(Fixed::const_new(1));
+    }
+
+    let height = body.y_top - body.y_bottom;
+    let mut y = player.y + carry.move_y;
+    if carry.move_y > Fixed::const_new(0) {
+        let mut head = BoundingBox::new_with_offset(player_box, player.x - body.x, player.z - body.z);
+        head.y_bottom = player.y;
+        head.y_top = player.y + height;
+        let roof = check_block_above(entities, &head, &cylinder_at(body, player.x, player.y, player.z));
+        if y + height > roof {
+            y = roof - height;
+        }
+    }
+    player.y = y;
+
+    //slide along a wall if only one direction is blocked
+    for (x, z) in [(x, z), (x, Fixed::const_new(0)), (Fixed::const_new(0), z)] {
+        let moved = cylinder_at(body, player.x + x, player.y, player.z + z);
+        if !horizontal_collision_check(entities, moved).1 {
+            player.x += x;
+            player.z += z;
+            return;
+        }
+    }
+}
+
+//the body cylinder moved to stand at x, y, z, lifted a little so the floor doesn't count as a wall
+fn cylinder_at(body: &BoundingCylinder, x: Fixed, y: Fixed, z: Fixed) -> BoundingCylinder {
+    BoundingCylinder {
+        x,
+        z,
+        radius: body.radius,
+        y_bottom: y + Fixed::from_raw(1),
+        y_top: y + body.y_top - body.y_bottom,
+    }
+}
+
+//moves the block at index by x and z, returns false if it isn't a pushable block or something is in the way
+fn push_block(entities: &mut [EntityEnum], index: usize, x: Fixed, z: Fixed) -> bool {
+    let EntityEnum::Pushable(block) = &entities[index] else {
+        return false;
+    };
+    let moved: BoundingBox = BoundingBox::new_with_offset(&block.footprint(), x, z);
+    if box_collision_check(entities, &moved, index) {
+        return false;
+    }
+    if let EntityEnum::Pushable(block) = &mut entities[index] {
+        block.push(x, z);
+    }
+    return true;
+}
+
+//pushable blocks fall until something solid is below them, then weigh down the switches they rest on
+pub fn settle_pushables(entities: &mut [EntityEnum]) {
+    for i in 2..entities.len() {
+        if let EntityEnum::Empty(_) = entities[i] {
+            break;
+        }
+        let EntityEnum::Pushable(block) = &entities[i] else {
+            continue;
+        };
+        let (ground, support_id) =
+            check_support_below(entities, &block.footprint(), &entities[i].bounding_cylinder());
+        if let EntityEnum::Pushable(block) = &mut entities[i] {
+            block.fall(ground, support_id);
+        }
+    }
+
+    for i in 2..entities.len() {
+        if let EntityEnum::Empty(_) = entities[i] {
+            break;
+        }
+        if !matches!(entities[i], EntityEnum::Switch(_)) {
+            continue;
+        }
+        let id = entities[i].get_id();
+        let weighted = entities
+            .iter()
+            .any(|e| matches!(e, EntityEnum::Pushable(block) if block.support_id() == id));
+        if let EntityEnum::Switch(switch) = &mut entities[i] {
+            switch.set_weighted(weighted);
+        }
+    }
+}
+
 fn get_dirs_by_wall_angle(player_angle: Fixed, wall_angle: Fixed) -> ([[Fixed; 2]; 2], [Fixed; 2]) {
     let direction_options: [Fixed; 4] = [
         wall_angle.modulo(Fixed::const_new(1)),
@@ -0,0 +1,97 @@
+//player preferences, changed in the options menu and kept in the save next to the level progress
+
+use crate::fixed::Fixed;
+use crate::input::mapping::{Mapping, REMAPPABLE};
+
+//the first byte of stored options, anything else means there are none yet and the defaults are used
+const MAGIC: u8 = 0xB3;
+//raised when the stored layout changes, older options are then replaced by the defaults
+const VERSION: u8 = 3;
+const MAPPING_OFFSET: usize = 9;
+pub const STORED_SIZE: usize = MAPPING_OFFSET + 2 * REMAPPABLE.len();
+
+pub const MIN_CAMERA_SPEED: u8 = 1;
+pub const MAX_CAMERA_SPEED: u8 = 4;
+pub const MIN_VOLUME: u8 = 1;
+pub const MAX_VOLUME: u8 = 7;
+//in whole units, changed in steps of DRAW_DISTANCE_STEP
+pub const MIN_DRAW_DISTANCE: u8 = 15;
+pub const MAX_DRAW_DISTANCE: u8 = 60;
+pub const DRAW_DISTANCE_STEP: u8 = 5;
+
+#[derive(Copy, Clone, PartialEq)]
+pub struct Options {
+    //whether the camera turns after the player when a level starts, SELECT still toggles it while playing
+    pub camera_follow: bool,
+    //how fast L and R turn the camera
+    pub camera_speed: u8,
+    //swaps L and R, and up and down while tilting the camera
+    pub invert_camera: bool,
+    pub sound: bool,
+    pub volume: u8,
+    pub draw_distance: u8,
+    pub show_timer: bool,
+    pub mapping: Mapping,
+}
+
+impl Options {
+    pub fn default() -> Self {
+        Self {
+            camera_follow: true,
+            camera_speed: 2,
+            invert_camera: false,
+            sound: true,
+            volume: MAX_VOLUME,
+            draw_distance: 35,
+            show_timer: true,
+            mapping: Mapping::default(),
+        }
+    }
+
+    pub fn draw_distance(&self) -> Fixed {
+        return Fixed::const_new(self.draw_distance as i32);
+    }
+
+    pub fn to_bytes(self) -> [u8; STORED_SIZE] {
+        let mut bytes = [0; STORED_SIZE];
+        bytes[..MAPPING_OFFSET].copy_from_slice(&[
+            MAGIC,
+            VERSION,
+            self.camera_follow as u8,
+            self.camera_speed,
+            self.invert_camera as u8,
+            self.sound as u8,
+            self.volume,
+            self.draw_distance,
+            self.show_timer as u8,
+        ]);
+        bytes[MAPPING_OFFSET..].copy_from_slice(&self.mapping.to_bytes());
+        return bytes;
+    }
+
+    //values out of range are replaced by their defaults, so a damaged save can't break the game
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let mut options = Self::default();
+        if bytes.len() < STORED_SIZE || bytes[0] != MAGIC || bytes[1] != VERSION {
+            return options;
+        }
+
+        options.camera_follow = bytes[2] != 0;
+        if (MIN_CAMERA_SPEED..=MAX_CAMERA_SPEED).contains(&bytes[3]) {
+            options.camera_speed = bytes[3];
+        }
+        options.invert_camera = bytes[4] != 0;
+        options.sound = bytes[5] != 0;
+        if (MIN_VOLUME..=MAX_VOLUME).contains(&bytes[6]) {
+            options.volume = bytes[6];
+        }
+        if (MIN_DRAW_DISTANCE..=MAX_DRAW_DISTANCE).contains(&bytes[7])
+            && bytes[7] % DRAW_DISTANCE_STEP == 0
+        {
+            options.draw_distance = bytes[7];
+        }
+        options.show_timer = bytes[8] != 0;
+        options.mapping = Mapping::from_bytes(&bytes[MAPPING_OFFSET..]);
+        return options;
+    }
+}
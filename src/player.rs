@@ -1,22 +1,168 @@
-use lut::CAMERALOCATIONS;
-
 use crate::{
     audio, camera,
     math::vector_len_2d,
     utils::{self, GameState},
 };
+use camera::controller::CameraController;
 use camera::*;
+use serde::Deserialize;
 
 use crate::fixed;
 use fixed::*;
 
+//defaults of the movement profile
 const MOVECAP: Fixed = Fixed::from_raw(64); //64
 const GROUNDACCEL: Fixed = Fixed::from_raw(32);
 const AIRACCEL: Fixed = Fixed::from_raw(8);
 //slows the player down after they let go of Button::A
 const FLOATGRAVITY: Fixed = Fixed::from_raw(128);
 const BASEGRAVITY: Fixed = Fixed::from_raw(32);
-pub const JUMPPOWER: Fixed = Fixed::from_raw(256);
+const JUMPPOWER: Fixed = Fixed::from_raw(256);
+const COYOTEFRAMES: u16 = 5;
+const JUMPBUFFERFRAMES: u16 = 5;
+//max falling speed while an updraft is holding the player up
+const GLIDESPEED: Fixed = Fixed::from_raw(16);
+//max rising speed an updraft stronger than gravity gives the player
+const RISESPEED: Fixed = Fixed::from_raw(32);
+const KNOCKBACKPOWER: Fixed = Fixed::from_raw(128);
+//frames after a knockback during which enemies can't knock the player back again
+const HURTFRAMES: u16 = 45;
+const DOUBLEJUMPPOWER: Fixed = Fixed::from_raw(224);
+const WALLJUMPPOWER: Fixed = Fixed::from_raw(256);
+const DASHSPEED: Fixed = Fixed::from_raw(160);
+const DASHFRAMES: u16 = 10;
+const POUNDSPEED: Fixed = Fixed::from_raw(160);
+//a sixteenth of a turn per frame, so a half turn takes 8 frames
+const TURNRATE: Fixed = Fixed::from_raw(16);
+//turns sharper than this happen at once, so turning back doesn't go around in a curve
+const TURNSNAP: Fixed = Fixed::from_raw(96);
+//how much of the top speed is left in precision mode
+const PRECISIONSPEED: Fixed = Fixed::from_raw(128);
+
+fn default_move_cap() -> Fixed {
+    MOVECAP
+}
+
+fn default_ground_accel() -> Fixed {
+    GROUNDACCEL
+}
+
+fn default_air_accel() -> Fixed {
+    AIRACCEL
+}
+
+fn default_float_gravity() -> Fixed {
+    FLOATGRAVITY
+}
+
+fn default_base_gravity() -> Fixed {
+    BASEGRAVITY
+}
+
+fn default_jump_power() -> Fixed {
+    JUMPPOWER
+}
+
+fn default_coyote_frames() -> u16 {
+    COYOTEFRAMES
+}
+
+fn default_jump_buffer_frames() -> u16 {
+    JUMPBUFFERFRAMES
+}
+
+fn default_turn_rate() -> Fixed {
+    TURNRATE
+}
+
+//how the acceleration changes with the speed while the player speeds up
+//slowing down always uses the plain acceleration
+#[derive(Copy, Clone, Deserialize, Debug, PartialEq)]
+pub enum AccelCurve {
+    //the same acceleration at every speed
+    #[serde(rename = "linear")]
+    Linear,
+    //twice the acceleration from standing still, half of it close to the top speed
+    #[serde(rename = "ease_out")]
+    EaseOut,
+    //half the acceleration from standing still, building up to one and a half times it
+    #[serde(rename = "ease_in")]
+    EaseIn,
+}
+
+impl AccelCurve {
+    //speed is how far the player is to the top speed, from 0 to 1
+    fn scale(&self, speed: Fixed) -> Fixed {
+        match self {
+            AccelCurve::Linear => Fixed::const_new(1),
+            AccelCurve::EaseOut => Fixed::const_new(2) - speed * Fixed::from_raw(384),
+            AccelCurve::EaseIn => Fixed::from_raw(128) + speed,
+        }
+    }
+}
+
+fn default_accel_curve() -> AccelCurve {
+    AccelCurve::Linear
+}
+
+//how the player moves, the global values come from config/movement.json and a level can
+//override them in its settings
+#[derive(Copy, Clone, Deserialize, Debug)]
+pub struct MovementProfile {
+    #[serde(default = "default_move_cap")]
+    pub move_cap: Fixed,
+    #[serde(default = "default_ground_accel")]
+    pub ground_accel: Fixed,
+    #[serde(default = "default_air_accel")]
+    pub air_accel: Fixed,
+    #[serde(default = "default_float_gravity")]
+    pub float_gravity: Fixed,
+    #[serde(default = "default_base_gravity")]
+    pub base_gravity: Fixed,
+    #[serde(default = "default_jump_power")]
+    pub jump_power: Fixed,
+    //frames after walking off a ledge during which a jump still works
+    #[serde(default = "default_coyote_frames")]
+    pub coyote_frames: u16,
+    //frames a jump pressed in the air is remembered, so it happens on landing
+    #[serde(default = "default_jump_buffer_frames")]
+    pub jump_buffer_frames: u16,
+    //how far the player turns toward the pressed direction per frame, 1 turns at once
+    #[serde(default = "default_turn_rate")]
+    pub turn_rate: Fixed,
+    #[serde(default = "default_accel_curve")]
+    pub accel_curve: AccelCurve,
+}
+
+impl Default for MovementProfile {
+    fn default() -> Self {
+        Self {
+            move_cap: MOVECAP,
+            ground_accel: GROUNDACCEL,
+            air_accel: AIRACCEL,
+            float_gravity: FLOATGRAVITY,
+            base_gravity: BASEGRAVITY,
+            jump_power: JUMPPOWER,
+            coyote_frames: COYOTEFRAMES,
+            jump_buffer_frames: JUMPBUFFERFRAMES,
+            turn_rate: TURNRATE,
+            accel_curve: AccelCurve::Linear,
+        }
+    }
+}
+
+//movement options a level can turn on in its settings, all off by default
+#[derive(Copy, Clone, Deserialize, Debug, Default)]
+pub struct Abilities {
+    #[serde(default = "default_bool")]
+    pub double_jump: bool,
+    #[serde(default = "default_bool")]
+    pub wall_jump: bool,
+    #[serde(default = "default_bool")]
+    pub dash: bool,
+    #[serde(default = "default_bool")]
+    pub ground_pound: bool,
+}
 
 #[derive(PartialEq)]
 enum JumpState {
@@ -41,6 +187,7 @@ pub struct Player<'a> {
     pub angle: Fixed,
     camera_angle: usize,
     pub camera: Camera,
+    pub camera_controller: CameraController,
     pub autorotate_camera: bool,
 
     jump_state: JumpState,
@@ -51,9 +198,33 @@ pub struct Player<'a> {
 
     sliding: bool,
 
+    pub movement: MovementProfile,
+    coyote_frames_left: u16,
+    jump_buffer_left: u16,
+
+    pub abilities: Abilities,
+    air_jump_used: bool,
+    dash_frames: u16,
+    dash_used: bool,
+    hurt_frames: u16,
+    pounding: bool,
+    //true on the frame a ground pound hits the ground
+    pound_impact: bool,
+    //points away from the wall the player ran into last frame, set by attempt_move
+    pub wall_normal: Option<[Fixed; 2]>,
+
     pub move_x: Fixed,
     pub move_z: Fixed,
     pub activeaccel: Fixed,
+    //half speed while the precision button is held, set by handle_input
+    pub precision: bool,
+
+    //external forces from wind etc, collected during a frame and applied on the next one
+    pub force_x: Fixed,
+    pub force_y: Fixed,
+    pub force_z: Fixed,
+    next_force: [Fixed; 3],
+
     vblank: Option<&'a agb::interrupt::VBlank>,
     sound: Option<&'a agb::sound::dmg::Sound>,
     finish_animation_frames_left: u16,
@@ -69,6 +240,7 @@ impl<'a> Player<'a> {
             yspeed: Fixed::const_new(0),
             camera_angle: 0,
             camera: Camera::default(),
+            camera_controller: CameraController::default(),
             action: false,
             autorotate_camera: true,
             jumping: false,
@@ -76,8 +248,24 @@ impl<'a> Player<'a> {
             move_x: Fixed::const_new(0),
             move_z: Fixed::const_new(0),
             activeaccel: Fixed::const_new(0),
+            precision: false,
+            force_x: Fixed::const_new(0),
+            force_y: Fixed::const_new(0),
+            force_z: Fixed::const_new(0),
+            next_force: [Fixed::const_new(0); 3],
             in_air: false,
             sliding: false,
+            movement: MovementProfile::default(),
+            coyote_frames_left: 0,
+            jump_buffer_left: 0,
+            abilities: Abilities::default(),
+            air_jump_used: false,
+            dash_frames: 0,
+            hurt_frames: 0,
+            dash_used: false,
+            pounding: false,
+            pound_impact: false,
+            wall_normal: None,
             vblank: None,
             sound: None,
             jump_state: JumpState::Jumping,
@@ -110,91 +298,52 @@ impl<'a> Player<'a> {
         }
     }
 
+    //turns the player toward the direction at offset from the camera and returns the way they face now
+    //the turn is limited by the turn rate, so the player goes through the directions in between
+    fn steer(&mut self, offset: usize) -> (Fixed, Fixed) {
+        let goal = ((self.camera_angle + offset) % 256) as i32;
+        let current = self.angle.to_raw().rem_euclid(256);
+        //the shorter way round, between -128 and 127
+        let diff = (goal - current + 128).rem_euclid(256) - 128;
+        let rate = self.movement.turn_rate.to_raw();
+        let turned = if diff.abs() <= rate || diff.abs() > TURNSNAP.to_raw() {
+            goal
+        } else {
+            current + rate * diff.signum()
+        };
+        self.angle = Fixed::from_raw(turned.rem_euclid(256));
+        return (self.angle.cos(), self.angle.sin());
+    }
+
     pub fn forward(&mut self) -> (Fixed, Fixed) {
-        let mut view_dir: usize = self.camera_angle + 64;
-        if view_dir > 255 {
-            view_dir -= 255;
-        }
-        self.angle = CAMERALOCATIONS[view_dir][2];
-        let x: Fixed = self.angle.cos();
-        let z: Fixed = self.angle.sin();
-        return (x, z);
+        return self.steer(64);
     }
 
     pub fn forward_left(&mut self) -> (Fixed, Fixed) {
-        let mut view_dir: usize = self.camera_angle + 32;
-        if view_dir > 255 {
-            view_dir -= 255;
-        }
-        self.angle = CAMERALOCATIONS[view_dir][2];
-        let x = self.angle.cos();
-        let z = self.angle.sin();
-        return (x, z);
+        return self.steer(32);
     }
 
     pub fn forward_right(&mut self) -> (Fixed, Fixed) {
-        let mut view_dir: usize = self.camera_angle + 96;
-        if view_dir > 255 {
-            view_dir -= 255;
-        }
-        self.angle = CAMERALOCATIONS[view_dir][2];
-        let x = self.angle.cos();
-        let z = self.angle.sin();
-        return (x, z);
+        return self.steer(96);
     }
 
     pub fn back(&mut self) -> (Fixed, Fixed) {
-        let mut view_dir: usize = self.camera_angle + 192;
-        if view_dir > 255 {
-            view_dir -= 255;
-        }
-        self.angle = CAMERALOCATIONS[view_dir][2];
-        let x = self.angle.cos();
-        let z = self.angle.sin();
-        return (x, z);
+        return self.steer(192);
     }
 
     pub fn back_left(&mut self) -> (Fixed, Fixed) {
-        let mut view_dir: usize = self.camera_angle + 224;
-        if view_dir > 255 {
-            view_dir -= 255;
-        }
-        self.angle = CAMERALOCATIONS[view_dir][2];
-        let x = self.angle.cos();
-        let z = self.angle.sin();
-        return (x, z);
+        return self.steer(224);
     }
 
     pub fn back_right(&mut self) -> (Fixed, Fixed) {
-        let mut view_dir: usize = self.camera_angle + 160;
-        if view_dir > 255 {
-            view_dir -= 255;
-        }
-        self.angle = CAMERALOCATIONS[view_dir][2];
-        let x = self.angle.cos();
-        let z = self.angle.sin();
-        return (x, z);
+        return self.steer(160);
     }
 
     pub fn left(&mut self) -> (Fixed, Fixed) {
-        let mut view_dir: usize = self.camera_angle + 0;
-        if view_dir > 255 {
-            view_dir -= 255;
-        }
-        self.angle = CAMERALOCATIONS[view_dir][2];
-        let x = self.angle.cos();
-        let z = self.angle.sin();
-        return (x, z);
+        return self.steer(0);
     }
     pub fn right(&mut self) -> (Fixed, Fixed) {
-        let mut view_dir: usize = self.camera_angle + 128;
-        if view_dir > 255 {
-            view_dir -= 255;
-        }
-        self.angle = CAMERALOCATIONS[view_dir][2];
-        let x = self.angle.cos();
-        let z = self.angle.sin();
-        return (x, z);
+        return self.steer(128);
     }
     pub fn camera_left(&mut self, mut amount: usize) {
         if self.camera_angle < amount {
@@ -202,42 +351,68 @@ impl<'a> Player<'a> {
             self.camera_angle = 256;
         }
         self.camera_angle -= amount;
-        self.camera
-            .set_y_rotation(CAMERALOCATIONS[self.camera_angle][2]);
-        self.camera
-            .set_x_rotation(CAMERALOCATIONS[self.camera_angle][3]);
-        self.camera
-            .set_z_rotation(CAMERALOCATIONS[self.camera_angle][4]);
-
-        self.camera.local_x = CAMERALOCATIONS[self.camera_angle][0];
-        self.camera.local_z = CAMERALOCATIONS[self.camera_angle][1];
+        self.camera.orbit(self.camera_angle);
     }
     pub fn camera_right(&mut self, amount: usize) {
         self.camera_angle += amount;
         if self.camera_angle >= 256 {
             self.camera_angle -= 256;
         }
-        self.camera
-            .set_y_rotation(CAMERALOCATIONS[self.camera_angle][2]);
-        self.camera
-            .set_x_rotation(CAMERALOCATIONS[self.camera_angle][3]);
-        self.camera
-            .set_z_rotation(CAMERALOCATIONS[self.camera_angle][4]);
+        self.camera.orbit(self.camera_angle);
+    }
+    pub fn camera_pitch(&mut self, amount: i32) {
+        self.camera.change_pitch(amount, self.camera_angle);
+    }
+    pub fn camera_zoom(&mut self, amount: Fixed) {
+        self.camera.zoom(amount, self.camera_angle);
+    }
 
-        self.camera.local_x = CAMERALOCATIONS[self.camera_angle][0];
-        self.camera.local_z = CAMERALOCATIONS[self.camera_angle][1];
+    //turns the player to the given angle and moves the camera behind them
+    pub fn face(&mut self, angle: Fixed) {
+        let direction = angle.to_raw().rem_euclid(256);
+        self.angle = Fixed::from_raw(direction);
+        //forward() looks 64 steps ahead of the camera
+        self.camera_angle = (direction - 64).rem_euclid(256) as usize;
+        self.camera_right(0);
     }
 
     pub fn update_camera_position(&mut self) {
-        self.camera.x = self.camera.local_x + self.x;
-        self.camera.y = self.camera.local_y + self.y;
-        self.camera.z = self.camera.local_z + self.z;
+        let grounded = self.is_grounded();
+        self.camera_controller.follow(
+            &mut self.camera,
+            [self.x, self.y, self.z],
+            [self.move_x, self.move_z],
+            grounded,
+            self.camera_angle,
+        );
+    }
+
+    //puts the camera right behind the player without easing there
+    pub fn snap_camera(&mut self) {
+        self.camera_controller
+            .snap(&mut self.camera, [self.x, self.y, self.z], self.camera_angle);
+    }
+
+    //yaw of the camera around the player, in 256 steps per turn
+    pub fn camera_yaw(&self) -> usize {
+        return self.camera_angle;
+    }
+
+    pub fn is_grounded(&self) -> bool {
+        return !self.in_air;
     }
 
     pub fn land(&mut self) {
         self.yspeed = Fixed::const_new(0);
         self.in_air = false;
         self.jump_state = JumpState::OnGround;
+        self.coyote_frames_left = self.movement.coyote_frames;
+        self.air_jump_used = false;
+        if self.dash_frames == 0 {
+            self.dash_used = false;
+        }
+        self.pound_impact = self.pounding;
+        self.pounding = false;
     }
 
     pub fn fall(&mut self, ylimit: Fixed) {
@@ -247,13 +422,21 @@ impl<'a> Player<'a> {
                 self.y = ylimit;
                 self.land();
             }
-            self.yspeed -= BASEGRAVITY;
+            self.yspeed -= self.movement.base_gravity - self.force_y;
+            if self.force_y > Fixed::const_new(0) && self.yspeed < -GLIDESPEED {
+                self.yspeed = -GLIDESPEED;
+            }
             self.in_air = true;
         } else {
             if self.in_air {
                 audio::play_sound(3, self.vblank.unwrap(), self.sound.unwrap());
             }
             self.land();
+            //an updraft stronger than gravity lifts the player off the ground
+            if self.force_y > self.movement.base_gravity {
+                self.yspeed = self.force_y - self.movement.base_gravity;
+                self.in_air = true;
+            }
         }
     }
 
@@ -266,38 +449,107 @@ impl<'a> Player<'a> {
                 self.y = ylimit - Fixed::from_raw(192);
                 self.land();
             }
-            if self.jumping || self.forced_jump {
-                self.yspeed -= BASEGRAVITY;
+            let gravity = if self.jumping || self.forced_jump {
+                self.movement.base_gravity
             } else {
-                self.yspeed -= FLOATGRAVITY;
+                self.movement.float_gravity
+            };
+            let yspeed = self.yspeed;
+            self.yspeed -= gravity - self.force_y;
+            //an updraft lifts the player at a steady speed, a faster jump slows down to it as usual
+            if self.force_y > Fixed::const_new(0) && self.yspeed > RISESPEED {
+                self.yspeed = RISESPEED.max(yspeed - gravity);
             }
         }
         self.jumping = false;
     }
 
+    fn launch(&mut self, power: Fixed) {
+        self.yspeed = power;
+        self.coyote_frames_left = 0;
+        self.jump_buffer_left = 0;
+        self.forced_jump = false;
+        self.activeaccel = self.movement.air_accel;
+        audio::play_sound(2, self.vblank.unwrap(), self.sound.unwrap());
+        self.jump_state = JumpState::Jumping;
+        self.jump_goal_state = JumpGoalState::Cleared;
+    }
+
     pub fn jump(&mut self) {
-        if self.yspeed == Fixed::const_new(0) && !self.in_air {
-            self.yspeed = JUMPPOWER;
-            self.forced_jump = false;
-            self.activeaccel = AIRACCEL;
-            audio::play_sound(2, self.vblank.unwrap(), self.sound.unwrap());
-            self.jump_state = JumpState::Jumping;
-            self.jump_goal_state = JumpGoalState::Cleared;
+        let on_ground = self.yspeed == Fixed::const_new(0) && !self.in_air;
+        //just walked off a ledge
+        let coyote = self.coyote_frames_left > 0 && self.yspeed <= Fixed::const_new(0);
+        if on_ground || coyote {
+            self.launch(self.movement.jump_power);
+        }
+    }
+
+    //a jump pressed in the air pushes off a wall the player is running into, or jumps again
+    fn air_jump(&mut self) {
+        if self.pounding || self.dash_frames > 0 {
+            return;
+        }
+        if self.abilities.wall_jump {
+            if let Some(normal) = self.wall_normal {
+                self.move_x = normal[0] * self.movement.move_cap;
+                self.move_z = normal[1] * self.movement.move_cap;
+                self.launch(WALLJUMPPOWER);
+                return;
+            }
+        }
+        if self.abilities.double_jump && !self.air_jump_used {
+            self.air_jump_used = true;
+            self.launch(DOUBLEJUMPPOWER);
         }
     }
 
     pub fn try_jumping(&mut self) {
-        if self.jump_goal_state == JumpGoalState::Idle {
+        let fresh_press = self.jump_goal_state == JumpGoalState::Idle;
+        if fresh_press {
             self.jump_goal_state = JumpGoalState::Queued;
         }
         if self.jump_goal_state == JumpGoalState::Queued {
             self.jump();
         }
+        if fresh_press && self.jump_goal_state == JumpGoalState::Queued && self.in_air {
+            self.air_jump();
+        }
+        //nothing used the press, remember it in case the player is about to land
+        if fresh_press && self.jump_goal_state == JumpGoalState::Queued {
+            self.jump_buffer_left = self.movement.jump_buffer_frames;
+        }
         if self.jump_state == JumpState::Jumping {
             self.jumping = true;
         }
     }
 
+    //a short burst of speed in the facing direction, once per jump
+    pub fn dash(&mut self) {
+        if !self.abilities.dash || self.dash_used || self.dash_frames > 0 || self.pounding {
+            return;
+        }
+        self.dash_frames = DASHFRAMES;
+        self.dash_used = true;
+        audio::play_sound(2, self.vblank.unwrap(), self.sound.unwrap());
+    }
+
+    //drops straight down, returns false if it isn't possible right now
+    pub fn ground_pound(&mut self) -> bool {
+        if !self.abilities.ground_pound || !self.in_air || self.pounding {
+            return false;
+        }
+        self.pounding = true;
+        self.dash_frames = 0;
+        self.move_x = Fixed::const_new(0);
+        self.move_z = Fixed::const_new(0);
+        self.yspeed = -POUNDSPEED;
+        return true;
+    }
+
+    pub fn is_pounding(&self) -> bool {
+        return self.pounding || self.pound_impact;
+    }
+
     pub fn cancel_jump(&mut self) {
         if self.jump_state == JumpState::Jumping {
             self.jumping = false;
@@ -312,42 +564,80 @@ impl<'a> Player<'a> {
     //set active to true, when player also jumps when contacting the platform
     pub fn bounce(&mut self, power: Fixed, active_bounce: bool) {
         self.yspeed = power;
+        self.coyote_frames_left = 0;
         self.forced_jump = active_bounce;
-        self.activeaccel = AIRACCEL;
+        self.activeaccel = self.movement.air_accel;
         audio::play_sound(2, self.vblank.unwrap(), self.sound.unwrap());
     }
 
+    //x and z are a unit vector pointing away from what hit the player
+    pub fn knockback(&mut self, x: Fixed, z: Fixed) {
+        self.move_x = x * self.movement.move_cap;
+        self.move_z = z * self.movement.move_cap;
+        self.hurt_frames = HURTFRAMES;
+        self.bounce(KNOCKBACKPOWER, false);
+    }
+
+    //true for a moment after a knockback, further hits are ignored until it runs out
+    pub fn is_hurt(&self) -> bool {
+        return self.hurt_frames > 0;
+    }
+
     pub fn move_toward(&mut self, x: Fixed, z: Fixed) {
-        let x_cap = x * MOVECAP;
-        let z_cap = z * MOVECAP;
+        if self.pounding {
+            self.move_x = Fixed::const_new(0);
+            self.move_z = Fixed::const_new(0);
+            return;
+        }
+        if self.dash_frames > 0 {
+            self.move_x = self.angle.cos() * DASHSPEED;
+            self.move_z = self.angle.sin() * DASHSPEED;
+            return;
+        }
+
+        let mut move_cap = self.movement.move_cap;
+        if self.precision {
+            move_cap *= PRECISIONSPEED;
+        }
+        let x_cap = x * move_cap;
+        let z_cap = z * move_cap;
+
+        //the curve only changes the acceleration while speeding up, not while slowing down or turning
+        let speed = vector_len_2d([self.move_x, self.move_z]);
+        let mut accel = self.activeaccel;
+        if vector_len_2d([x_cap, z_cap]) > speed && move_cap > Fixed::const_new(0) {
+            let progress = (speed / move_cap).clamp(Fixed::const_new(0), Fixed::const_new(1));
+            accel *= self.movement.accel_curve.scale(progress);
+        }
 
         if self.move_x > x_cap {
-            self.move_x -= self.activeaccel;
+            self.move_x -= accel;
             if self.move_x < x_cap {
                 self.move_x = x_cap;
             }
         } else if self.move_x < x_cap {
-            self.move_x += self.activeaccel;
+            self.move_x += accel;
             if self.move_x > x_cap {
                 self.move_x = x_cap;
             }
         }
 
         if self.move_z > z_cap {
-            self.move_z -= self.activeaccel;
+            self.move_z -= accel;
             if self.move_z < z_cap {
                 self.move_z = z_cap;
             }
         } else if self.move_z < z_cap {
-            self.move_z += self.activeaccel;
+            self.move_z += accel;
             if self.move_z > z_cap {
                 self.move_z = z_cap;
             }
         }
 
+        //the full top speed, starting precision mode slows the player down like letting go does
         let len = vector_len_2d([self.move_x, self.move_z]);
-        if len > MOVECAP {
-            let scale = MOVECAP / len;
+        if len > self.movement.move_cap {
+            let scale = self.movement.move_cap / len;
             self.move_x *= scale;
             self.move_z *= scale;
         }
@@ -358,10 +648,39 @@ impl<'a> Player<'a> {
         self.sliding = true;
     }
 
+    pub fn apply_force(&mut self, x: Fixed, y: Fixed, z: Fixed) {
+        self.next_force[0] += x;
+        self.next_force[1] += y;
+        self.next_force[2] += z;
+    }
+
     pub fn tick(&mut self) {
         self.action = false;
+        self.force_x = self.next_force[0];
+        self.force_y = self.next_force[1];
+        self.force_z = self.next_force[2];
+        self.next_force = [Fixed::const_new(0); 3];
+        if self.in_air && self.coyote_frames_left > 0 {
+            self.coyote_frames_left -= 1;
+        }
+        if self.jump_buffer_left > 0 {
+            self.jump_buffer_left -= 1;
+            if !self.in_air {
+                self.jump();
+            }
+        }
+        if self.hurt_frames > 0 {
+            self.hurt_frames -= 1;
+        }
+        if self.dash_frames > 0 {
+            self.dash_frames -= 1;
+            //a dash keeps its height in the air
+            if self.in_air {
+                self.yspeed = Fixed::const_new(0);
+            }
+        }
         if !self.in_air && !self.sliding {
-            self.activeaccel = GROUNDACCEL;
+            self.activeaccel = self.movement.ground_accel;
         }
         self.sliding = false;
     }
@@ -2,6 +2,7 @@ const MODE_4_PAGE_1: *mut u16 = 0x600_0000 as *mut u16;
 const MODE_4_PAGE_2: *mut u16 = 0x600_A000 as *mut u16;
 const REG_DISPCNT: *mut u32 = 0x0400_0000 as *mut u32;
 const DCNT_PAGE: u32 = 0x0010;
+const PALETTE: *mut u16 = 0x500_0000 as *mut u16;
 
 pub fn draw_wide_point(x: i32, y: i32, color: u16, page: u16) {
     let index = (y * 240 + x) >> 1;
@@ -84,3 +85,9 @@ pub fn flip(page: &mut u16) {
         *REG_DISPCNT ^= DCNT_PAGE;
     };
 }
+
+pub fn set_palette_entry(index: u32, color: u16) {
+    unsafe {
+        *PALETTE.add(index as usize) = color;
+    }
+}
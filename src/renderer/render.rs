@@ -250,6 +250,102 @@ pub fn render_rect(
     }
 }
 
+//draws any mesh given as a list of triangles that index into the points
+//triangles are counter clockwise when seen from the outside, shade picks the palette shade of each one
+pub fn render_triangle_list<const N: usize>(
+    model_rotated_points: &[[Fixed; 3]; N],
+    point_count: usize,
+    triangles: &[[u8; 3]],
+    shades: &[u8],
+    x: Fixed,
+    y: Fixed,
+    z: Fixed,
+    camera_ptr: &Camera,
+    color: u16,
+    polygons: &mut Vec<Polygon, InternalAllocator>,
+    draw_always: bool,
+) {
+    let mut screen_points: [[Fixed; 2]; N] = [[Fixed::const_new(0), Fixed::const_new(0)]; N];
+    let mut translated_points: [[Fixed; 3]; N] = [[Fixed::const_new(0); 3]; N];
+
+    for i in 0..point_count {
+        (translated_points[i], screen_points[i]) =
+            translate_point(&model_rotated_points[i], camera_ptr, x, y, z);
+    }
+
+    for (i, triangle) in triangles.iter().enumerate() {
+        let [a, b, c] = triangle.map(|p| p as usize);
+        if a >= point_count || b >= point_count || c >= point_count {
+            continue;
+        }
+        if !back_face_culling(&translated_points, a, b, c) {
+            continue;
+        }
+        let shade = if i < shades.len() { shades[i] as i16 } else { 0 };
+        polygons.push(Polygon {
+            distance_from_camera: utils::polygon_avg_z(&translated_points, a, b, c),
+            shape: Shape::Triangle([screen_points[a], screen_points[b], screen_points[c]]),
+            color: utils::get_color(color, shade),
+            draw_always,
+        });
+    }
+}
+
+//flat octagon lying on the ground below an airborne object, in the darkest shade of the ground's color
+//distance is what it sorts by, pass the distance of the face it lies on minus a little
+//so it is drawn right after that face, None sorts it by its own depth
+pub fn render_shadow(
+    x: Fixed,
+    y: Fixed,
+    z: Fixed,
+    radius: Fixed,
+    camera_ptr: &Camera,
+    color: u16,
+    distance: Option<Fixed>,
+    polygons: &mut Vec<Polygon, InternalAllocator>,
+) {
+    let mut screen_points: [[Fixed; 2]; 8] = [[Fixed::const_new(0), Fixed::const_new(0)]; 8];
+    let mut translated_points: [[Fixed; 3]; 8] = [[Fixed::const_new(0); 3]; 8];
+
+    //going clockwise when seen from above, same as the top face of render_rect
+    for i in 0..8 {
+        let angle = -Fixed::const_new(i as i32) / 8;
+        let point = [radius * angle.cos(), Fixed::const_new(0), radius * angle.sin()];
+        (translated_points[i], screen_points[i]) = translate_point(&point, camera_ptr, x, y, z);
+    }
+
+    if !back_face_culling(&translated_points, 0, 1, 2) {
+        return;
+    }
+    let color = utils::get_color(color, 3);
+    for i in 1..7 {
+        let distance_from_camera = match distance {
+            Some(distance) => distance,
+            None => utils::polygon_avg_z(&translated_points, 0, i, i + 1),
+        };
+        polygons.push(Polygon {
+            distance_from_camera,
+            shape: Shape::Triangle([screen_points[0], screen_points[i], screen_points[i + 1]]),
+            color,
+            draw_always: false,
+        });
+    }
+}
+
+//distance of the closest polygon drawn in the given palette color, e.g. the top face of an entity
+pub fn face_distance(polygons: &[Polygon], color: u16) -> Option<Fixed> {
+    let mut closest: Option<Fixed> = None;
+    for polygon in polygons.iter() {
+        if polygon.color != color {
+            continue;
+        }
+        if closest.is_none() || polygon.distance_from_camera < closest.unwrap() {
+            closest = Some(polygon.distance_from_camera);
+        }
+    }
+    return closest;
+}
+
 #[inline(always)]
 pub fn render_wireframe_rect(
     model_rotated_points: &[[Fixed; 3]; 8],
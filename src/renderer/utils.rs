@@ -1,3 +1,4 @@
+use super::hw;
 use super::Fixed;
 
 pub const PROJECTION_MATRIX: [[Fixed; 4]; 4] = [
@@ -27,38 +28,46 @@ pub const PROJECTION_MATRIX: [[Fixed; 4]; 4] = [
     ],
 ];
 
+//number of steps between a black screen and the normal palette
+pub const FADE_STEPS: i32 = 8;
+
 fn init_palette_slice(
-    bitmap4: &mut agb::display::bitmap4::Bitmap4,
     i: u32,
     r: i32,
     g: i32,
     b: i32,
     scale: i32,
+    brightness: i32,
 ) {
-    let red: Fixed = Fixed::new(r);
-    let green: Fixed = Fixed::const_new(g);
-    let blue: Fixed = Fixed::const_new(b);
+    let red: Fixed = Fixed::new(r) * brightness / FADE_STEPS;
+    let green: Fixed = Fixed::const_new(g) * brightness / FADE_STEPS;
+    let blue: Fixed = Fixed::const_new(b) * brightness / FADE_STEPS;
 
     for j in 0..8 {
         let red_shade: u16 = (red - (red / 8 / scale) * j as i32).trunc() as u16;
         let green_shade: u16 = ((green - (green / 8 / scale) * j as i32).trunc() << 5) as u16;
         let blue_shade: u16 = ((blue - (blue / 8 / scale) * j as i32).trunc() << 10) as u16;
-        bitmap4.set_palette_entry(((i+1) * 8 - j) as u32, red_shade | green_shade | blue_shade);
+        hw::set_palette_entry(((i+1) * 8 - j) as u32, red_shade | green_shade | blue_shade);
     }
 }
 
-pub fn init_palette(bitmap4: &mut agb::display::bitmap4::Bitmap4) {
-    // Set  palette entries
-    init_palette_slice(bitmap4, 0, 31, 0, 0, 2);
-    init_palette_slice(bitmap4, 1, 0, 31, 0, 2);
-    init_palette_slice(bitmap4, 2, 0, 0, 31, 2);
-    init_palette_slice(bitmap4, 3, 15, 10, 7, 2);
-    init_palette_slice(bitmap4, 4, 31, 31, 0, 2);
-    init_palette_slice(bitmap4, 5, 31, 31, 31, 1);
-    init_palette_slice(bitmap4, 6, 0, 25, 25, 1);
-    init_palette_slice(bitmap4, 7, 25, 20, 15, 2);
-    init_palette_slice(bitmap4, 8, 28, 31, 31, 1);
-    init_palette_slice(bitmap4, 9, 25, 6, 31, 1);
+pub fn init_palette() {
+    set_brightness(FADE_STEPS);
+}
+
+//darkens every color in the palette, 0 is a black screen and FADE_STEPS the normal colors
+//used to fade the screen without redrawing it
+pub fn set_brightness(brightness: i32) {
+    init_palette_slice(0, 31, 0, 0, 2, brightness);
+    init_palette_slice(1, 0, 31, 0, 2, brightness);
+    init_palette_slice(2, 0, 0, 31, 2, brightness);
+    init_palette_slice(3, 15, 10, 7, 2, brightness);
+    init_palette_slice(4, 31, 31, 0, 2, brightness);
+    init_palette_slice(5, 31, 31, 31, 1, brightness);
+    init_palette_slice(6, 0, 25, 25, 1, brightness);
+    init_palette_slice(7, 25, 20, 15, 2, brightness);
+    init_palette_slice(8, 28, 31, 31, 1, brightness);
+    init_palette_slice(9, 25, 6, 31, 1, brightness);
 }
 
 pub fn get_color(index: u16, shade: i16) -> u16 {
@@ -3,6 +3,42 @@ use agb::save::Error;
 use agb::InternalAllocator;
 use alloc::vec::Vec;
 
+use crate::options::{self, Options};
+
+//the progress is a byte per level from the start of the save, the options come after it
+//at a fixed place so that adding levels doesn't move them
+const OPTIONS_OFFSET: usize = 256;
+//what erased flash reads as, a level that has never been saved
+const ERASED: u8 = 0xFF;
+
+//what the player has done in a level, bit 0 of its byte is set once the level is finished
+//and the bits above it are the gems collected there, saves from before gems only have bit 0
+#[derive(Copy, Clone, Default, PartialEq)]
+pub struct LevelProgress {
+    pub completed: bool,
+    pub gems: u8,
+}
+
+impl LevelProgress {
+    fn to_byte(self) -> u8 {
+        return self.completed as u8 | (self.gems << 1);
+    }
+
+    fn from_byte(byte: u8) -> Self {
+        if byte == ERASED {
+            return Self::default();
+        }
+        Self {
+            completed: byte & 1 != 0,
+            gems: byte >> 1,
+        }
+    }
+
+    pub fn gem_count(&self) -> usize {
+        return self.gems.count_ones() as usize;
+    }
+}
+
 #[allow(dead_code)]
 #[derive(PartialEq)]
 pub enum SaveType {
@@ -27,51 +63,56 @@ pub fn init_save(gba: &mut agb::Gba, save_type: SaveType) {
     }
 }
 
+//progress and options are written together, preparing a write on flash erases
+//the whole sector, which would lose whichever was written first
 pub fn store_save(
     gba: &mut agb::Gba,
-    data: &mut Vec<bool, InternalAllocator>,
+    data: &mut Vec<LevelProgress, InternalAllocator>,
+    options: &Options,
     save_type: SaveType,
 ) -> Result<(), Error> {
     if save_type == SaveType::None {
         return Ok(());
     }
     let mut access = gba.save.access()?;
-    let bytes: Vec<u8> = data.iter().map(|&b| b as u8).collect();
+    let mut bytes: Vec<u8> = data.iter().map(|level| level.to_byte()).collect();
+    bytes.resize(OPTIONS_OFFSET, 0);
+    bytes.extend_from_slice(&options.to_bytes());
 
     access.prepare_write(0..bytes.len())?.write(0, &bytes)?;
     Ok(())
 }
 
+//the defaults if nothing has been stored yet
+pub fn read_options(gba: &mut agb::Gba, save_type: SaveType) -> Result<Options, Error> {
+    if save_type == SaveType::None {
+        return Ok(Options::default());
+    }
+    let mut access = gba.save.access()?;
+    let mut bytes = [0u8; options::STORED_SIZE];
+    access.read(OPTIONS_OFFSET, &mut bytes)?;
+    return Ok(Options::from_bytes(&bytes));
+}
+
 pub fn read_save(
     gba: &mut agb::Gba,
     length: usize,
     save_type: SaveType,
-) -> Result<Vec<bool, InternalAllocator>, Error> {
-    let mut booleans: Vec<bool, InternalAllocator> =
+) -> Result<Vec<LevelProgress, InternalAllocator>, Error> {
+    let mut progress: Vec<LevelProgress, InternalAllocator> =
         Vec::with_capacity_in(length, InternalAllocator);
 
     if save_type == SaveType::None {
-        booleans.resize(length, false);
+        progress.resize(length, LevelProgress::default());
     } else {
         let mut access = gba.save.access()?;
 
-        let mut bytes: Vec<u8, InternalAllocator> =
-            Vec::with_capacity_in(length, InternalAllocator);
         // Read each byte separately
         for i in 0..length {
             let mut val = 0;
             access.read(i, core::slice::from_mut(&mut val))?;
-            if val == 1 {
-                bytes.push(1);
-            } else {
-                bytes.push(0);
-            }
-        }
-
-        // booleans = Vec::with_capacity_in(bytes.len(), InternalAllocator);
-        for &byte in bytes.iter() {
-            booleans.push(byte != 0);
+            progress.push(LevelProgress::from_byte(val));
         }
     }
-    return Ok(booleans);
+    return Ok(progress);
 }
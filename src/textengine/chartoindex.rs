@@ -1,14 +1,30 @@
-
 extern crate alloc;
 use alloc::vec::Vec;
 
-pub fn convert_to_tiles(s: &str) -> Vec<u8> {
-	let indices: Vec<u8> = s.chars().map(|c| match c.to_lowercase().next().unwrap() {
+pub const BLANK_TILE: u8 = 36;
+pub const CHECKMARK_TILE: u8 = 37;
+//tiles from 40 onwards, in this order
+//the arrows and circled letters are the d-pad directions and the buttons, for use in help texts
+const SYMBOLS: &str = "!\"#$%&'()+,./:;<=>?@[\\]^_`{|}~←↑→↓ⒶⒷⓁⓇ✚";
+const FIRST_SYMBOL_TILE: u8 = 40;
+
+//the font only has capitals, so upper and lower case letters look the same
+pub fn char_to_tile(c: char) -> u8 {
+	let c = c.to_ascii_lowercase();
+	match c {
 		'0'..='9' => (c as u8) - b'0',          // '0' -> 0, '1' -> 1, ..., '9' -> 9
 		'a'..='z' => (c as u8) - b'a' + 10,     // 'a' -> 10, 'b' -> 11, ..., 'z' -> 35
 		'-' => 38,
 		'*' => 39,
-		_ => 36,                                // Any other character -> 36
-	}).collect();
+		'✓' => CHECKMARK_TILE,
+		_ => match SYMBOLS.chars().position(|symbol| symbol == c) {
+			Some(i) => FIRST_SYMBOL_TILE + i as u8,
+			None => BLANK_TILE,                 // Any other character -> 36
+		},
+	}
+}
+
+pub fn convert_to_tiles(s: &str) -> Vec<u8> {
+	let indices: Vec<u8> = s.chars().map(char_to_tile).collect();
 	return indices;
 }
@@ -1,6 +1,8 @@
 use crate::renderer;
 
 use super::chartoindex::convert_to_tiles;
+use super::font;
+use super::fontstore;
 use super::letters::LETTERTILES;
 
 pub fn write_line(
@@ -36,3 +38,248 @@ pub fn write_tile(
 	}
 }
 
+
+pub const SCREEN_WIDTH: u16 = 240;
+pub const SCREEN_HEIGHT: u16 = 160;
+pub const TILE_SIZE: u16 = 8;
+//distance from the start of one character to the next
+pub const ADVANCE: u16 = 12;
+pub const LINE_HEIGHT: u16 = 12;
+//headings are drawn at twice the size when they fit on the screen
+pub const HEADING_SCALE: u16 = 2;
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum Align {
+	Left,
+	Center,
+	Right,
+}
+
+//width in pixels of text drawn at the given scale, without the gap after the last character
+pub fn measure(text: &str, scale: u16) -> u16 {
+	let count = text.chars().count() as u16;
+	if count == 0 {
+		return 0;
+	}
+	return ((count - 1) * ADVANCE + TILE_SIZE) * scale;
+}
+
+//x of the left edge for text that should start, be centered on or end at x
+fn aligned_x(x: u16, text: &str, scale: u16, align: Align) -> u16 {
+	let width = measure(text, scale);
+	match align {
+		Align::Left => x,
+		Align::Center => x.saturating_sub(width / 2),
+		Align::Right => x.saturating_sub(width),
+	}
+}
+
+//a tile with every pixel drawn as a scale by scale square, pixels off the screen are left out
+pub fn write_tile_scaled(
+	x: u16,
+	y: u16,
+	tile: usize,
+	scale: u16,
+	color: u16,
+	page: u16,
+) {
+	for i in 0..TILE_SIZE {
+		let mut mask = 0x80;
+		for j in 0..TILE_SIZE {
+			if LETTERTILES[tile][i as usize] & mask != 0 {
+				for dy in 0..scale {
+					for dx in 0..scale {
+						let px = x + j * scale + dx;
+						let py = y + i * scale + dy;
+						if px < SCREEN_WIDTH && py < SCREEN_HEIGHT {
+							renderer::hw::draw_point(px as i32, py as i32, color, page);
+						}
+					}
+				}
+			}
+			mask >>= 1;
+		}
+	}
+}
+
+pub fn write_line_scaled(
+	x: u16,
+	y: u16,
+	text: &str,
+	scale: u16,
+	color: u16,
+	page: u16,
+) {
+	let indices: alloc::vec::Vec<u8> = convert_to_tiles(text);
+	for i in 0..indices.len() {
+		write_tile_scaled(x + i as u16 * ADVANCE * scale, y, indices[i] as usize, scale, color, page);
+	}
+}
+
+//x is where the text starts, is centered or ends depending on align
+pub fn write_aligned(
+	x: u16,
+	y: u16,
+	text: &str,
+	align: Align,
+	color: u16,
+	page: u16,
+) {
+	write_line_scaled(aligned_x(x, text, 1, align), y, text, 1, color, page);
+}
+
+//centered on the screen, at double size if it fits
+pub fn write_heading(
+	y: u16,
+	text: &str,
+	color: u16,
+	page: u16,
+) {
+	let scale = if measure(text, HEADING_SCALE) <= SCREEN_WIDTH { HEADING_SCALE } else { 1 };
+	let x = aligned_x(SCREEN_WIDTH / 2, text, scale, Align::Center);
+	write_line_scaled(x, y, text, scale, color, page);
+}
+
+//width in pixels of text in the proportional font
+pub fn text_width(text: &str) -> u16 {
+	let mut width = 0;
+	let mut chars = text.chars().peekable();
+	while let Some(c) = chars.next() {
+		width += match chars.peek() {
+			Some(&next) => font::advance(c, next),
+			None => font::char_width(c),
+		};
+	}
+	return width;
+}
+
+//draws text in the proportional font, pixels off the screen are left out
+//returns the x the next character would start at
+pub fn write_text(
+	x: u16,
+	y: u16,
+	text: &str,
+	color: u16,
+	page: u16,
+) -> u16 {
+	let mut x = x;
+	let mut chars = text.chars().peekable();
+	while let Some(c) = chars.next() {
+		if let Some(glyph) = font::glyph(c) {
+			for (i, row) in glyph.rows.iter().enumerate() {
+				let py = y + i as u16;
+				for j in 0..glyph.width as u16 {
+					let px = x + j;
+					if row & (0x80 >> j) != 0 && px < SCREEN_WIDTH && py < SCREEN_HEIGHT {
+						renderer::hw::draw_point(px as i32, py as i32, color, page);
+					}
+				}
+			}
+		}
+		x += match chars.peek() {
+			Some(&next) => font::advance(c, next),
+			None => font::char_width(c) + fontstore::SPACING,
+		};
+	}
+	return x;
+}
+
+//x is where the text starts, is centered or ends depending on align
+pub fn write_text_aligned(
+	x: u16,
+	y: u16,
+	text: &str,
+	align: Align,
+	color: u16,
+	page: u16,
+) {
+	let width = text_width(text);
+	let x = match align {
+		Align::Left => x,
+		Align::Center => x.saturating_sub(width / 2),
+		Align::Right => x.saturating_sub(width),
+	};
+	write_text(x, y, text, color, page);
+}
+
+//splits text into lines no wider than width in the proportional font, breaking at spaces
+//words too long for a line of their own are cut
+pub fn wrap(text: &str, width: u16) -> alloc::vec::Vec<&str> {
+	let mut lines = alloc::vec::Vec::new();
+	for paragraph in text.split('\n') {
+		let mut rest = paragraph.trim_start();
+		if rest.is_empty() {
+			lines.push(rest);
+			continue;
+		}
+		while !rest.is_empty() {
+			if text_width(rest) <= width {
+				lines.push(rest);
+				break;
+			}
+			//byte index of the first character that doesn't fit, at least one character goes on every line
+			let mut limit = rest.chars().next().map_or(rest.len(), |c| c.len_utf8());
+			for (i, c) in rest.char_indices().skip(1) {
+				if text_width(&rest[..i + c.len_utf8()]) > width {
+					break;
+				}
+				limit = i + c.len_utf8();
+			}
+			let end = if rest[limit..].starts_with(' ') {
+				limit
+			} else {
+				match rest[..limit].rfind(' ') {
+					Some(space) if space > 0 => space,
+					_ => limit,
+				}
+			};
+			lines.push(rest[..end].trim_end());
+			rest = rest[end..].trim_start();
+		}
+	}
+	return lines;
+}
+
+//draws text word wrapped inside the box, lines below the bottom of the box are left out
+//returns the height used
+#[allow(dead_code)]
+pub fn write_paragraph(
+	x: u16,
+	y: u16,
+	width: u16,
+	height: u16,
+	text: &str,
+	align: Align,
+	color: u16,
+	page: u16,
+) -> u16 {
+	return write_lines(x, y, width, height, &wrap(text, width), align, color, page);
+}
+
+//draws lines that are already wrapped, for text drawn every frame that shouldn't be wrapped every time
+//returns the height used
+pub fn write_lines(
+	x: u16,
+	y: u16,
+	width: u16,
+	height: u16,
+	lines: &[&str],
+	align: Align,
+	color: u16,
+	page: u16,
+) -> u16 {
+	let anchor = match align {
+		Align::Left => x,
+		Align::Center => x + width / 2,
+		Align::Right => x + width,
+	};
+	let mut line_y = y;
+	for line in lines {
+		if line_y + TILE_SIZE > y + height {
+			break;
+		}
+		write_text_aligned(anchor, line_y, line, align, color, page);
+		line_y += LINE_HEIGHT;
+	}
+	return line_y - y;
+}
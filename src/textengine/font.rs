@@ -0,0 +1,39 @@
+//proportional font, every glyph is only as wide as its pixels
+//the glyphs and kerning pairs come from fonts/proportional.png and fonts/proportional.json,
+//scripts/generate_font.py turns them into fontstore.rs when the game is built
+
+use super::fontstore::{glyph_index, GLYPHS, GLYPH_HEIGHT, KERNING, SPACE_WIDTH, SPACING};
+
+pub struct Glyph {
+	pub width: u8,
+	//the leftmost pixel is the highest bit
+	pub rows: [u8; GLYPH_HEIGHT],
+}
+
+//like the tile font it only has capitals, so upper case letters use the lower case glyphs
+pub fn glyph(c: char) -> Option<&'static Glyph> {
+	return glyph_index(c.to_ascii_lowercase()).map(|i| &GLYPHS[i]);
+}
+
+//pixels to move the second character by when it follows the first, usually towards it
+pub fn kerning(first: char, second: char) -> i16 {
+	let pair = (first.to_ascii_lowercase(), second.to_ascii_lowercase());
+	match KERNING.binary_search_by(|k| (k.0, k.1).cmp(&pair)) {
+		Ok(i) => KERNING[i].2 as i16,
+		Err(_) => 0,
+	}
+}
+
+//width of the character itself, characters without a glyph take up as much room as a space
+pub fn char_width(c: char) -> u16 {
+	match glyph(c) {
+		Some(g) => g.width as u16,
+		None => SPACE_WIDTH,
+	}
+}
+
+//distance from the start of first to the start of second
+pub fn advance(first: char, second: char) -> u16 {
+	let distance = (char_width(first) + SPACING) as i16 + kerning(first, second);
+	return distance.max(0) as u16;
+}
@@ -0,0 +1,191 @@
+// DO NOT EDIT: This file was automatically generated by generate_font.py.
+// Any modifications will be overwritten during the next build.
+
+use super::font::Glyph;
+
+pub const GLYPH_HEIGHT: usize = 8;
+pub const SPACE_WIDTH: u16 = 4;
+pub const SPACING: u16 = 2;
+
+pub const GLYPHS: [Glyph; 78] = [
+	Glyph { width: 8, rows: [0b01111110, 0b10000011, 0b10000001, 0b10000001, 0b10000001, 0b10000001, 0b10000011, 0b01111110] }, // 0
+	Glyph { width: 6, rows: [0b00110000, 0b01110000, 0b00110000, 0b00110000, 0b00110000, 0b00110000, 0b00110000, 0b11111100] }, // 1
+	Glyph { width: 8, rows: [0b01111110, 0b00000011, 0b00000011, 0b01111110, 0b11000000, 0b11000000, 0b11000000, 0b11111110] }, // 2
+	Glyph { width: 7, rows: [0b11111100, 0b00000110, 0b00000110, 0b11111100, 0b00000110, 0b00000110, 0b00000110, 0b11111100] }, // 3
+	Glyph { width: 8, rows: [0b10000001, 0b10000001, 0b10000001, 0b11111111, 0b00000001, 0b00000001, 0b00000001, 0b00000001] }, // 4
+	Glyph { width: 8, rows: [0b11111111, 0b10000000, 0b10000000, 0b01111110, 0b00000011, 0b00000011, 0b00000011, 0b01111110] }, // 5
+	Glyph { width: 8, rows: [0b01111110, 0b10000000, 0b10000000, 0b11111110, 0b10000011, 0b10000001, 0b10000001, 0b01111110] }, // 6
+	Glyph { width: 7, rows: [0b11111100, 0b00000110, 0b00000110, 0b00001100, 0b00011000, 0b00011000, 0b00011000, 0b00011000] }, // 7
+	Glyph { width: 8, rows: [0b01111110, 0b10000001, 0b10000001, 0b01111110, 0b10000001, 0b10000001, 0b10000001, 0b01111110] }, // 8
+	Glyph { width: 8, rows: [0b01111110, 0b10000001, 0b10000001, 0b01111111, 0b00000011, 0b00000011, 0b00000011, 0b01111110] }, // 9
+	Glyph { width: 8, rows: [0b01111110, 0b10000001, 0b10000001, 0b10000001, 0b11111111, 0b10000001, 0b10000001, 0b10000001] }, // a
+	Glyph { width: 8, rows: [0b11111110, 0b10000001, 0b10000001, 0b11111110, 0b10000001, 0b10000001, 0b10000001, 0b11111110] }, // b
+	Glyph { width: 8, rows: [0b01111111, 0b10000000, 0b10000000, 0b10000000, 0b10000000, 0b10000000, 0b10000000, 0b01111111] }, // c
+	Glyph { width: 8, rows: [0b11111110, 0b10000001, 0b10000001, 0b10000001, 0b10000001, 0b10000001, 0b10000001, 0b11111110] }, // d
+	Glyph { width: 8, rows: [0b11111111, 0b10000000, 0b10000000, 0b11111111, 0b10000000, 0b10000000, 0b10000000, 0b11111111] }, // e
+	Glyph { width: 8, rows: [0b11111111, 0b10000000, 0b10000000, 0b11111111, 0b10000000, 0b10000000, 0b10000000, 0b10000000] }, // f
+	Glyph { width: 8, rows: [0b01111110, 0b10000000, 0b10000000, 0b10000000, 0b10001111, 0b10000001, 0b10000001, 0b01111110] }, // g
+	Glyph { width: 8, rows: [0b10000001, 0b10000001, 0b10000001, 0b11111111, 0b10000001, 0b10000001, 0b10000001, 0b10000001] }, // h
+	Glyph { width: 2, rows: [0b11000000, 0b11000000, 0b11000000, 0b11000000, 0b11000000, 0b11000000, 0b11000000, 0b11000000] }, // i
+	Glyph { width: 8, rows: [0b00000001, 0b00000001, 0b00000001, 0b00000001, 0b00000001, 0b10000001, 0b10000001, 0b01111110] }, // j
+	Glyph { width: 7, rows: [0b10000110, 0b10001000, 0b10010000, 0b11100000, 0b11100000, 0b10010000, 0b10001000, 0b10000110] }, // k
+	Glyph { width: 7, rows: [0b10000000, 0b10000000, 0b10000000, 0b10000000, 0b10000000, 0b10000000, 0b10000000, 0b11111110] }, // l
+	Glyph { width: 8, rows: [0b10000001, 0b11000011, 0b10100101, 0b10011001, 0b10011001, 0b10000001, 0b10000001, 0b10000001] }, // m
+	Glyph { width: 8, rows: [0b10000001, 0b11000001, 0b10100001, 0b10010001, 0b10001001, 0b10000101, 0b10000011, 0b10000001] }, // n
+	Glyph { width: 8, rows: [0b01111110, 0b10000001, 0b10000001, 0b10000001, 0b10000001, 0b10000001, 0b10000001, 0b01111110] }, // o
+	Glyph { width: 8, rows: [0b11111110, 0b10000001, 0b10000001, 0b10000001, 0b11111110, 0b10000000, 0b10000000, 0b10000000] }, // p
+	Glyph { width: 8, rows: [0b01111110, 0b10000001, 0b10000001, 0b10000001, 0b10000001, 0b10001001, 0b10000110, 0b01111101] }, // q
+	Glyph { width: 8, rows: [0b11111110, 0b10000001, 0b10000001, 0b10000001, 0b11111110, 0b10000100, 0b10000010, 0b10000001] }, // r
+	Glyph { width: 8, rows: [0b01111110, 0b10000000, 0b10000000, 0b01111110, 0b00000001, 0b00000001, 0b00000001, 0b11111110] }, // s
+	Glyph { width: 8, rows: [0b11111111, 0b00011000, 0b00011000, 0b00011000, 0b00011000, 0b00011000, 0b00011000, 0b00011000] }, // t
+	Glyph { width: 8, rows: [0b10000001, 0b10000001, 0b10000001, 0b10000001, 0b10000001, 0b10000001, 0b10000001, 0b01111110] }, // u
+	Glyph { width: 8, rows: [0b10000001, 0b10000001, 0b10000001, 0b01000010, 0b01000010, 0b00100100, 0b00100100, 0b00011000] }, // v
+	Glyph { width: 8, rows: [0b10000001, 0b10000001, 0b10000001, 0b10000001, 0b10000001, 0b01011010, 0b01011010, 0b00111100] }, // w
+	Glyph { width: 8, rows: [0b10000001, 0b01000010, 0b00100100, 0b00011000, 0b00011000, 0b00100100, 0b01000010, 0b10000001] }, // x
+	Glyph { width: 8, rows: [0b10000001, 0b01000010, 0b00100100, 0b00011000, 0b00011000, 0b00011000, 0b00011000, 0b00011000] }, // y
+	Glyph { width: 8, rows: [0b11111111, 0b00000010, 0b00000100, 0b00001000, 0b00010000, 0b00100000, 0b01000000, 0b11111111] }, // z
+	Glyph { width: 3, rows: [0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b10100000, 0b11000000, 0b10000000] }, // ✓
+	Glyph { width: 3, rows: [0b00000000, 0b00000000, 0b00000000, 0b11100000, 0b11100000, 0b00000000, 0b00000000, 0b00000000] }, // -
+	Glyph { width: 8, rows: [0b00000000, 0b00100100, 0b00111100, 0b00111100, 0b11111111, 0b00111100, 0b00111100, 0b00100100] }, // *
+	Glyph { width: 2, rows: [0b11000000, 0b11000000, 0b11000000, 0b11000000, 0b11000000, 0b00000000, 0b11000000, 0b11000000] }, // !
+	Glyph { width: 6, rows: [0b11001100, 0b11001100, 0b10001000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000] }, // "
+	Glyph { width: 8, rows: [0b00100100, 0b00100100, 0b11111111, 0b00100100, 0b00100100, 0b11111111, 0b00100100, 0b00100100] }, // #
+	Glyph { width: 8, rows: [0b00010000, 0b01111110, 0b10010000, 0b01111110, 0b00010001, 0b00010001, 0b01111110, 0b00010000] }, // $
+	Glyph { width: 7, rows: [0b01000010, 0b10100100, 0b01001000, 0b00010000, 0b00100000, 0b01001010, 0b10000100, 0b00000000] }, // %
+	Glyph { width: 7, rows: [0b01110000, 0b10001000, 0b01010000, 0b00100000, 0b01010010, 0b10001100, 0b01110010, 0b00000000] }, // &
+	Glyph { width: 3, rows: [0b01100000, 0b01100000, 0b10000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000] }, // '
+	Glyph { width: 4, rows: [0b00110000, 0b01000000, 0b10000000, 0b10000000, 0b10000000, 0b10000000, 0b01000000, 0b00110000] }, // (
+	Glyph { width: 4, rows: [0b11000000, 0b00100000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00100000, 0b11000000] }, // )
+	Glyph { width: 6, rows: [0b00000000, 0b00110000, 0b00110000, 0b11111100, 0b11111100, 0b00110000, 0b00110000, 0b00000000] }, // +
+	Glyph { width: 3, rows: [0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b01100000, 0b01100000, 0b10000000] }, // ,
+	Glyph { width: 2, rows: [0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b11000000, 0b11000000] }, // .
+	Glyph { width: 8, rows: [0b00000001, 0b00000010, 0b00000100, 0b00001000, 0b00010000, 0b00100000, 0b01000000, 0b10000000] }, // /
+	Glyph { width: 2, rows: [0b00000000, 0b11000000, 0b11000000, 0b00000000, 0b00000000, 0b11000000, 0b11000000, 0b00000000] }, // :
+	Glyph { width: 3, rows: [0b00000000, 0b01100000, 0b01100000, 0b00000000, 0b00000000, 0b01100000, 0b01100000, 0b10000000] }, // ;
+	Glyph { width: 5, rows: [0b00011000, 0b00100000, 0b01000000, 0b10000000, 0b01000000, 0b00100000, 0b00011000, 0b00000000] }, // <
+	Glyph { width: 6, rows: [0b00000000, 0b00000000, 0b11111100, 0b00000000, 0b00000000, 0b11111100, 0b00000000, 0b00000000] }, // =
+	Glyph { width: 5, rows: [0b11000000, 0b00100000, 0b00010000, 0b00001000, 0b00010000, 0b00100000, 0b11000000, 0b00000000] }, // >
+	Glyph { width: 8, rows: [0b01111110, 0b10000001, 0b00000001, 0b00001110, 0b00010000, 0b00000000, 0b00011000, 0b00011000] }, // ?
+	Glyph { width: 8, rows: [0b01111110, 0b10000001, 0b10011110, 0b10100101, 0b10011110, 0b10000000, 0b01111110, 0b00000000] }, // @
+	Glyph { width: 4, rows: [0b11110000, 0b10000000, 0b10000000, 0b10000000, 0b10000000, 0b10000000, 0b10000000, 0b11110000] }, // [
+	Glyph { width: 8, rows: [0b10000000, 0b01000000, 0b00100000, 0b00010000, 0b00001000, 0b00000100, 0b00000010, 0b00000001] }, // \
+	Glyph { width: 4, rows: [0b11110000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b11110000] }, // ]
+	Glyph { width: 6, rows: [0b00110000, 0b01001000, 0b10000100, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000] }, // ^
+	Glyph { width: 8, rows: [0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b11111111] }, // _
+	Glyph { width: 3, rows: [0b11000000, 0b00100000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000] }, // `
+	Glyph { width: 6, rows: [0b00011100, 0b00100000, 0b00100000, 0b11000000, 0b00100000, 0b00100000, 0b00011100, 0b00000000] }, // {
+	Glyph { width: 2, rows: [0b11000000, 0b11000000, 0b11000000, 0b11000000, 0b11000000, 0b11000000, 0b11000000, 0b11000000] }, // |
+	Glyph { width: 6, rows: [0b11100000, 0b00010000, 0b00010000, 0b00001100, 0b00010000, 0b00010000, 0b11100000, 0b00000000] }, // }
+	Glyph { width: 8, rows: [0b00000000, 0b00000000, 0b01100001, 0b10010010, 0b10000110, 0b00000000, 0b00000000, 0b00000000] }, // ~
+	Glyph { width: 7, rows: [0b00000000, 0b00100000, 0b01000000, 0b11111110, 0b11111110, 0b01000000, 0b00100000, 0b00000000] }, // ←
+	Glyph { width: 8, rows: [0b00011000, 0b00111100, 0b01011010, 0b10011001, 0b00011000, 0b00011000, 0b00011000, 0b00011000] }, // ↑
+	Glyph { width: 7, rows: [0b00000000, 0b00001000, 0b00000100, 0b11111110, 0b11111110, 0b00000100, 0b00001000, 0b00000000] }, // →
+	Glyph { width: 8, rows: [0b00011000, 0b00011000, 0b00011000, 0b00011000, 0b10011001, 0b01011010, 0b00111100, 0b00011000] }, // ↓
+	Glyph { width: 8, rows: [0b01111110, 0b11100111, 0b11011011, 0b11011011, 0b11000011, 0b11011011, 0b11111111, 0b01111110] }, // Ⓐ
+	Glyph { width: 8, rows: [0b01111110, 0b11000111, 0b11011011, 0b11000111, 0b11011011, 0b11000111, 0b11111111, 0b01111110] }, // Ⓑ
+	Glyph { width: 8, rows: [0b01111110, 0b11011111, 0b11011111, 0b11011111, 0b11011111, 0b11000011, 0b11111111, 0b01111110] }, // Ⓛ
+	Glyph { width: 8, rows: [0b01111110, 0b11000111, 0b11011011, 0b11000111, 0b11010111, 0b11011011, 0b11111111, 0b01111110] }, // Ⓡ
+	Glyph { width: 8, rows: [0b00111100, 0b00100100, 0b11100111, 0b10000001, 0b10000001, 0b11100111, 0b00100100, 0b00111100] }, // ✚
+];
+
+pub fn glyph_index(c: char) -> Option<usize> {
+	match c {
+		'0' => Some(0),
+		'1' => Some(1),
+		'2' => Some(2),
+		'3' => Some(3),
+		'4' => Some(4),
+		'5' => Some(5),
+		'6' => Some(6),
+		'7' => Some(7),
+		'8' => Some(8),
+		'9' => Some(9),
+		'a' => Some(10),
+		'b' => Some(11),
+		'c' => Some(12),
+		'd' => Some(13),
+		'e' => Some(14),
+		'f' => Some(15),
+		'g' => Some(16),
+		'h' => Some(17),
+		'i' => Some(18),
+		'j' => Some(19),
+		'k' => Some(20),
+		'l' => Some(21),
+		'm' => Some(22),
+		'n' => Some(23),
+		'o' => Some(24),
+		'p' => Some(25),
+		'q' => Some(26),
+		'r' => Some(27),
+		's' => Some(28),
+		't' => Some(29),
+		'u' => Some(30),
+		'v' => Some(31),
+		'w' => Some(32),
+		'x' => Some(33),
+		'y' => Some(34),
+		'z' => Some(35),
+		'✓' => Some(36),
+		'-' => Some(37),
+		'*' => Some(38),
+		'!' => Some(39),
+		'"' => Some(40),
+		'#' => Some(41),
+		'$' => Some(42),
+		'%' => Some(43),
+		'&' => Some(44),
+		'\'' => Some(45),
+		'(' => Some(46),
+		')' => Some(47),
+		'+' => Some(48),
+		',' => Some(49),
+		'.' => Some(50),
+		'/' => Some(51),
+		':' => Some(52),
+		';' => Some(53),
+		'<' => Some(54),
+		'=' => Some(55),
+		'>' => Some(56),
+		'?' => Some(57),
+		'@' => Some(58),
+		'[' => Some(59),
+		'\\' => Some(60),
+		']' => Some(61),
+		'^' => Some(62),
+		'_' => Some(63),
+		'`' => Some(64),
+		'{' => Some(65),
+		'|' => Some(66),
+		'}' => Some(67),
+		'~' => Some(68),
+		'←' => Some(69),
+		'↑' => Some(70),
+		'→' => Some(71),
+		'↓' => Some(72),
+		'Ⓐ' => Some(73),
+		'Ⓑ' => Some(74),
+		'Ⓛ' => Some(75),
+		'Ⓡ' => Some(76),
+		'✚' => Some(77),
+		_ => None,
+	}
+}
+
+pub const KERNING: [(char, char, i8); 15] = [
+	('a', 't', -1),
+	('f', ',', -2),
+	('f', '.', -2),
+	('l', 't', -2),
+	('l', 'v', -1),
+	('l', 'w', -1),
+	('l', 'y', -2),
+	('p', '.', -1),
+	('t', ',', -2),
+	('t', '.', -2),
+	('t', 'a', -1),
+	('v', ',', -1),
+	('v', '.', -1),
+	('y', ',', -2),
+	('y', '.', -2),
+];
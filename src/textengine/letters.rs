@@ -1,4 +1,4 @@
-pub const LETTERTILES: [[u16; 8]; 40] = [
+pub const LETTERTILES: [[u16; 8]; 79] = [
 	[0b01111110, 0b10000011, 0b10000001, 0b10000001, 0b10000001, 0b10000001, 0b10000011, 0b01111110], // 0
 	[0b00011000, 0b00111000, 0b00011000, 0b00011000, 0b00011000, 0b00011000, 0b00011000, 0b01111110], // 1
 	[0b01111110, 0b00000011, 0b00000011, 0b01111110, 0b11000000, 0b11000000, 0b11000000, 0b11111110], // 2
@@ -39,4 +39,43 @@ pub const LETTERTILES: [[u16; 8]; 40] = [
 	[0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000101, 0b00000110, 0b00000100], // small v
 	[0b00000000, 0b00000000, 0b00000000, 0b00011100, 0b00011100, 0b00000000, 0b00000000, 0b00000000], // small -
 	[0b00000000, 0b00100100, 0b00111100, 0b00111100, 0b11111111, 0b00111100, 0b00111100, 0b00100100], // bigger *
+	[0b00011000, 0b00011000, 0b00011000, 0b00011000, 0b00011000, 0b00000000, 0b00011000, 0b00011000], // !
+	[0b01100110, 0b01100110, 0b01000100, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000], // "
+	[0b00100100, 0b00100100, 0b11111111, 0b00100100, 0b00100100, 0b11111111, 0b00100100, 0b00100100], // #
+	[0b00010000, 0b01111110, 0b10010000, 0b01111110, 0b00010001, 0b00010001, 0b01111110, 0b00010000], // $
+	[0b01000010, 0b10100100, 0b01001000, 0b00010000, 0b00100000, 0b01001010, 0b10000100, 0b00000000], // %
+	[0b01110000, 0b10001000, 0b01010000, 0b00100000, 0b01010010, 0b10001100, 0b01110010, 0b00000000], // &
+	[0b00011000, 0b00011000, 0b00100000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000], // '
+	[0b00001100, 0b00010000, 0b00100000, 0b00100000, 0b00100000, 0b00100000, 0b00010000, 0b00001100], // (
+	[0b00110000, 0b00001000, 0b00000100, 0b00000100, 0b00000100, 0b00000100, 0b00001000, 0b00110000], // )
+	[0b00000000, 0b00011000, 0b00011000, 0b01111110, 0b01111110, 0b00011000, 0b00011000, 0b00000000], // +
+	[0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00011000, 0b00011000, 0b00100000], // ,
+	[0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00011000, 0b00011000], // .
+	[0b00000001, 0b00000010, 0b00000100, 0b00001000, 0b00010000, 0b00100000, 0b01000000, 0b10000000], // /
+	[0b00000000, 0b00011000, 0b00011000, 0b00000000, 0b00000000, 0b00011000, 0b00011000, 0b00000000], // :
+	[0b00000000, 0b00011000, 0b00011000, 0b00000000, 0b00000000, 0b00011000, 0b00011000, 0b00100000], // ;
+	[0b00000110, 0b00001000, 0b00010000, 0b00100000, 0b00010000, 0b00001000, 0b00000110, 0b00000000], // <
+	[0b00000000, 0b00000000, 0b01111110, 0b00000000, 0b00000000, 0b01111110, 0b00000000, 0b00000000], // =
+	[0b01100000, 0b00010000, 0b00001000, 0b00000100, 0b00001000, 0b00010000, 0b01100000, 0b00000000], // >
+	[0b01111110, 0b10000001, 0b00000001, 0b00001110, 0b00010000, 0b00000000, 0b00011000, 0b00011000], // ?
+	[0b01111110, 0b10000001, 0b10011110, 0b10100101, 0b10011110, 0b10000000, 0b01111110, 0b00000000], // @
+	[0b00111100, 0b00100000, 0b00100000, 0b00100000, 0b00100000, 0b00100000, 0b00100000, 0b00111100], // [
+	[0b10000000, 0b01000000, 0b00100000, 0b00010000, 0b00001000, 0b00000100, 0b00000010, 0b00000001], // \
+	[0b00111100, 0b00000100, 0b00000100, 0b00000100, 0b00000100, 0b00000100, 0b00000100, 0b00111100], // ]
+	[0b00011000, 0b00100100, 0b01000010, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000], // ^
+	[0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b11111111], // _
+	[0b00110000, 0b00001000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000], // `
+	[0b00001110, 0b00010000, 0b00010000, 0b01100000, 0b00010000, 0b00010000, 0b00001110, 0b00000000], // {
+	[0b00011000, 0b00011000, 0b00011000, 0b00011000, 0b00011000, 0b00011000, 0b00011000, 0b00011000], // |
+	[0b01110000, 0b00001000, 0b00001000, 0b00000110, 0b00001000, 0b00001000, 0b01110000, 0b00000000], // }
+	[0b00000000, 0b00000000, 0b01100001, 0b10010010, 0b10000110, 0b00000000, 0b00000000, 0b00000000], // ~
+	[0b00000000, 0b00010000, 0b00100000, 0b01111111, 0b01111111, 0b00100000, 0b00010000, 0b00000000], // arrow left
+	[0b00011000, 0b00111100, 0b01011010, 0b10011001, 0b00011000, 0b00011000, 0b00011000, 0b00011000], // arrow up
+	[0b00000000, 0b00001000, 0b00000100, 0b11111110, 0b11111110, 0b00000100, 0b00001000, 0b00000000], // arrow right
+	[0b00011000, 0b00011000, 0b00011000, 0b00011000, 0b10011001, 0b01011010, 0b00111100, 0b00011000], // arrow down
+	[0b01111110, 0b11100111, 0b11011011, 0b11011011, 0b11000011, 0b11011011, 0b11111111, 0b01111110], // a button
+	[0b01111110, 0b11000111, 0b11011011, 0b11000111, 0b11011011, 0b11000111, 0b11111111, 0b01111110], // b button
+	[0b01111110, 0b11011111, 0b11011111, 0b11011111, 0b11011111, 0b11000011, 0b11111111, 0b01111110], // l button
+	[0b01111110, 0b11000111, 0b11011011, 0b11000111, 0b11010111, 0b11011011, 0b11111111, 0b01111110], // r button
+	[0b00111100, 0b00100100, 0b11100111, 0b10000001, 0b10000001, 0b11100111, 0b00100100, 0b00111100], // dpad
 ];
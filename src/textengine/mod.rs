@@ -1,3 +1,5 @@
 pub mod chartoindex;
 pub mod draw;
+pub mod font;
+pub mod fontstore;
 pub mod letters;
\ No newline at end of file
@@ -1,5 +1,9 @@
 
 use crate::Fixed;
+use core::fmt;
+use core::marker::PhantomData;
+use serde::de::{self, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer};
 
 #[allow(dead_code)]
 #[derive(PartialEq)]
@@ -7,11 +11,19 @@ pub enum GameState {
     //TODO: use menu option, and make player load the next level after finishing
     //canceling from pause menu should return player to main menu, so MENU would be set
     Menu,
+    //picking a level, also where a finished level goes when the next one isn't open
+    WorldMap,
+    //the title screen, and the demo that plays when it is left alone
+    Title,
     Playing,
     Finished,
     Failed,
     Paused,
+    //the map of the level, opened from the pause menu
+    Map,
     CompleteAnimation,
+    //camera flyover before the player gets control
+    Intro,
 }
 
 #[allow(dead_code)]
@@ -126,3 +138,60 @@ pub fn fixed_array_binary_search(target: Fixed, array: &[Fixed], len: i32) -> i3
     return high;
 }
 
+
+//a list from level data stored in a fixed size array, so entities holding it can stay Copy
+//items past len are left as their default values
+#[derive(Copy, Clone, Debug)]
+pub struct FixedList<T, const N: usize> {
+    pub items: [T; N],
+    pub len: usize,
+}
+
+impl<T: Default + Copy, const N: usize> Default for FixedList<T, N> {
+    fn default() -> Self {
+        FixedList {
+            items: [T::default(); N],
+            len: 0,
+        }
+    }
+}
+
+impl<T, const N: usize> FixedList<T, N> {
+    pub fn as_slice(&self) -> &[T] {
+        &self.items[..self.len]
+    }
+}
+
+impl<'de, T: Deserialize<'de> + Default + Copy, const N: usize> Deserialize<'de> for FixedList<T, N> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ListVisitor<T, const N: usize>(PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de> + Default + Copy, const N: usize> Visitor<'de> for ListVisitor<T, N> {
+            type Value = FixedList<T, N>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "a list of at most {} items", N)
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut list: FixedList<T, N> = FixedList::default();
+                while let Some(item) = seq.next_element()? {
+                    if list.len >= N {
+                        return Err(de::Error::invalid_length(list.len + 1, &self));
+                    }
+                    list.items[list.len] = item;
+                    list.len += 1;
+                }
+                Ok(list)
+            }
+        }
+
+        deserializer.deserialize_seq(ListVisitor(PhantomData))
+    }
+}